@@ -0,0 +1,180 @@
+use crate::{languages, Language};
+
+// The array is sorted by the alias ID
+//
+// Aliases must not be IDs of any language
+pub(crate) static ID_ALIASES: [(&str, &str); 14] = [
+    ("c#", "csharp"),
+    ("c++", "cpp"),
+    ("c-sharp", "csharp"),
+    ("golang", "go"),
+    ("js", "javascript"),
+    ("obj-c", "objective-c"),
+    ("objc", "objective-c"),
+    ("postgres", "postgresql"),
+    ("pwsh", "powershell"),
+    ("rst", "restructuredtext"),
+    ("sh", "shell"),
+    ("t-sql", "tsql"),
+    ("tf", "terraform"),
+    ("ts", "typescript"),
+];
+
+// The array is sorted by the alias name
+//
+// Aliases are lowercase informal names, which are neither names
+// nor IDs of any language, nor ID aliases
+pub(crate) static NAME_ALIASES: [(&str, &str); 25] = [
+    ("bash", "shell"),
+    ("cplusplus", "cpp"),
+    ("docker", "dockerfile"),
+    ("ecmascript", "javascript"),
+    ("elisp", "emacs-lisp"),
+    ("html5", "html"),
+    ("kt", "kotlin"),
+    ("latex", "tex"),
+    ("makefile", "make"),
+    ("md", "markdown"),
+    ("node", "javascript"),
+    ("node.js", "javascript"),
+    ("nodejs", "javascript"),
+    ("objective c", "objective-c"),
+    ("ps1", "powershell"),
+    ("py", "python"),
+    ("python3", "python"),
+    ("rb", "ruby"),
+    ("rs", "rust"),
+    ("shell script", "shell"),
+    ("shell-script", "shell"),
+    ("vim", "vim-script"),
+    ("viml", "vim-script"),
+    ("yml", "yaml"),
+    ("zsh", "shell"),
+];
+
+/// Identifies a language from its name, ID, or an alias, ignoring case,
+/// e.g. `Rust`, `c++`, `golang`, `node`, and `shell-script`.
+/// Returns `None` if the language was not identified.
+///
+/// Aliases include [ID aliases] and common informal names, which is
+/// useful for resolving names typed into search boxes.
+///
+/// [ID aliases]: fn.canonical_id.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_name_or_alias, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(from_name_or_alias("Rust"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_name_or_alias("objective-c"), Some(Language("Objective-C", "objective-c")));
+/// assert_eq!(from_name_or_alias("golang"), Some(Language("Go", "go")));
+/// assert_eq!(from_name_or_alias("C++"), Some(Language("C++", "cpp")));
+/// assert_eq!(from_name_or_alias("node"), Some(Language("JavaScript", "javascript")));
+/// assert_eq!(from_name_or_alias("Shell-Script"), Some(Language("Shell", "shell")));
+///
+/// assert_eq!(from_name_or_alias("unknown"), None);
+/// # }
+/// ```
+pub fn from_name_or_alias(name: &str) -> Option<Language<'static>> {
+    let name = name.trim();
+    if let Some(lang) = languages::from_name(name) {
+        return Some(lang);
+    }
+
+    let name = name.to_lowercase();
+    let id = NAME_ALIASES
+        .binary_search_by_key(&name.as_str(), |&(alias, _)| alias)
+        .map_or(name.as_str(), |i| NAME_ALIASES[i].1);
+    languages::from_id(canonical_id(id)?)
+}
+
+/// Returns the canonical ID of a language ID or alias ID, e.g.
+/// both `cpp` and `c++` return `cpp`.
+/// Returns `None` if the ID is unknown.
+///
+/// The ID is matched exactly, i.e. the case is not ignored.
+///
+/// This is useful for redirecting URLs containing aliases to the
+/// canonical URL.
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::canonical_id;
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(canonical_id("cpp"), Some("cpp"));
+/// assert_eq!(canonical_id("c++"), Some("cpp"));
+/// assert_eq!(canonical_id("c-sharp"), Some("csharp"));
+/// assert_eq!(canonical_id("rust"), Some("rust"));
+/// assert_eq!(canonical_id("unknown"), None);
+///
+/// // Redirecting aliases
+/// let id = "c++";
+/// match canonical_id(id) {
+///     Some(canonical) if canonical != id => { /* redirect to `canonical` */ }
+///     Some(_) => { /* already canonical */ }
+///     None => { /* not found */ }
+/// }
+/// # }
+/// ```
+pub fn canonical_id(id: &str) -> Option<&'static str> {
+    let id = ID_ALIASES
+        .binary_search_by_key(&id, |&(alias, _)| alias)
+        .map_or(id, |i| ID_ALIASES[i].1);
+    languages::from_id(id).map(|lang| lang.1)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in ID_ALIASES.iter().zip(ID_ALIASES.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Aliases out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_name_order() {
+    for (a, b) in NAME_ALIASES.iter().zip(NAME_ALIASES.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Name aliases out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_aliases() {
+    for &(alias, id) in ID_ALIASES.iter() {
+        assert!(
+            languages::from_id(alias).is_none(),
+            "Alias {:?} is the ID of a language",
+            alias
+        );
+        assert_eq!(canonical_id(alias), Some(id), "Unknown ID for {:?}", alias);
+    }
+
+    for &(alias, id) in NAME_ALIASES.iter() {
+        assert_eq!(alias, alias.to_lowercase(), "{:?} is not lowercase", alias);
+        assert!(
+            languages::from_name(alias).is_none() && canonical_id(alias).is_none(),
+            "Alias {:?} is the name or ID of a language",
+            alias
+        );
+        assert_eq!(
+            from_name_or_alias(alias).map(|lang| lang.id()),
+            Some(id),
+            "Unknown ID for {:?}",
+            alias
+        );
+    }
+}
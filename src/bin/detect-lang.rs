@@ -0,0 +1,173 @@
+//! Identifies the languages of files from the command line.
+//!
+//! *This binary requires the `cli` feature.*
+
+use std::env;
+use std::fs::File;
+use std::io::{self, BufRead, Read, Write};
+use std::path::Path;
+use std::process;
+
+use detect_lang::{Language, MAX_CONTENT_LEN};
+
+const USAGE: &str = "\
+Identifies the languages of files
+
+Usage: detect-lang [OPTIONS] [PATH]...
+
+Paths are read from stdin, one per line, if none are given or if a
+path is `-`, e.g. `find . -type f | detect-lang`.
+
+Options:
+      --json     Print a JSON object per line, with `path`, `name`, and `id`
+  -h, --help     Print help
+  -V, --version  Print version
+
+Exits with 1 if any language was not identified, and 2 on errors.";
+
+fn main() {
+    let mut json = false;
+    let mut paths = Vec::new();
+    let mut args = env::args_os().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.to_str() {
+            Some("--json") => json = true,
+            Some("-h") | Some("--help") => {
+                println!("{}", USAGE);
+                return;
+            }
+            Some("-V") | Some("--version") => {
+                println!("detect-lang {}", env!("CARGO_PKG_VERSION"));
+                return;
+            }
+            Some("--") => {
+                paths.extend(args.by_ref());
+            }
+            Some(opt) if opt.starts_with('-') && opt != "-" => {
+                eprintln!("error: unknown option {:?}\n\n{}", opt, USAGE);
+                process::exit(2);
+            }
+            _ => paths.push(arg),
+        }
+    }
+
+    let stdout = io::stdout();
+    let mut out = stdout.lock();
+    let mut all_identified = true;
+    let mut result = Ok(());
+
+    if paths.is_empty() {
+        paths.push("-".into());
+    }
+    for path in paths {
+        if path == "-" {
+            for line in io::stdin().lock().lines() {
+                let line = match line {
+                    Ok(line) => line,
+                    Err(err) => {
+                        result = Err(err);
+                        break;
+                    }
+                };
+                if line.is_empty() {
+                    continue;
+                }
+                match run(&mut out, Path::new(&line), json) {
+                    Ok(identified) => all_identified &= identified,
+                    Err(err) => {
+                        result = Err(err);
+                        break;
+                    }
+                }
+            }
+        } else {
+            match run(&mut out, Path::new(&path), json) {
+                Ok(identified) => all_identified &= identified,
+                Err(err) => result = Err(err),
+            }
+        }
+        if result.is_err() {
+            break;
+        }
+    }
+
+    match result {
+        // Exit quietly when piped into e.g. `head`
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => {}
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(2);
+        }
+        Ok(()) if !all_identified => process::exit(1),
+        Ok(()) => {}
+    }
+}
+
+/// Identifies and prints the language of `path`, returning whether the
+/// language was identified.
+fn run<W: Write>(out: &mut W, path: &Path, json: bool) -> io::Result<bool> {
+    // Paths which cannot be read, e.g. directories and missing files,
+    // are identified by the path alone
+    let content = read_content(path).ok();
+    let lang = detect_lang::detect(path, content.as_deref()).map(|d| d.language());
+    write_language(out, path, lang, json)?;
+    Ok(lang.is_some())
+}
+
+fn read_content(path: &Path) -> io::Result<Vec<u8>> {
+    // The metadata of notebooks is commonly stored at the end
+    let notebook = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("ipynb"));
+    let limit = if cfg!(feature = "jupyter") && notebook {
+        u64::MAX
+    } else {
+        MAX_CONTENT_LEN as u64
+    };
+
+    let mut content = Vec::new();
+    File::open(path)?.take(limit).read_to_end(&mut content)?;
+    Ok(content)
+}
+
+fn write_language<W: Write>(
+    out: &mut W,
+    path: &Path,
+    lang: Option<Language<'_>>,
+    json: bool,
+) -> io::Result<()> {
+    let path = path.to_string_lossy();
+    if json {
+        let value = serde_json::json!({
+            "path": path,
+            "name": lang.map(|lang| lang.name()),
+            "id": lang.map(|lang| lang.id()),
+        });
+        writeln!(out, "{}", value)
+    } else if let Some(lang) = lang {
+        writeln!(out, "{}: {}", path, lang.name())
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn check_write_language() {
+    let write = |path: &str, lang, json| {
+        let mut out = Vec::new();
+        write_language(&mut out, Path::new(path), lang, json).unwrap();
+        String::from_utf8(out).unwrap()
+    };
+
+    let rust = Some(Language("Rust", "rust"));
+    assert_eq!(write("src/main.rs", rust, false), "src/main.rs: Rust\n");
+    assert_eq!(write("foo", None, false), "");
+    assert_eq!(
+        write("src/main.rs", rust, true),
+        "{\"id\":\"rust\",\"name\":\"Rust\",\"path\":\"src/main.rs\"}\n"
+    );
+    assert_eq!(
+        write("foo", None, true),
+        "{\"id\":null,\"name\":null,\"path\":\"foo\"}\n"
+    );
+}
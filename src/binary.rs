@@ -0,0 +1,164 @@
+use core::str;
+
+use crate::{Language, MAX_CONTENT_LEN};
+
+/// The maximum ratio of non-text bytes, in content which is not valid UTF-8,
+/// before the content is considered binary.
+const MAX_NON_TEXT_RATIO: f32 = 0.3;
+
+// The array is sorted by the language ID
+pub(crate) static BINARY_LANGUAGES: [&str; 19] = [
+    "7z", "bzip2", "elf", "excel", "feather", "gzip", "mach-o", "ods", "parquet", "pdf", "pe",
+    "png", "rar", "sqlite", "tar", "tar-gzip", "xz", "zip", "zstd",
+];
+
+// Extensions of binary files and build artifacts, which are not
+// languages. The array is sorted by the extension
+pub(crate) static BINARY_EXTENSIONS: [&str; 39] = [
+    "a", "avif", "bin", "bmp", "class", "dll", "dylib", "eot", "exe", "flac", "gif", "ico", "jar",
+    "jpeg", "jpg", "lib", "mov", "mp3", "mp4", "o", "obj", "ogg", "otf", "pdb", "pdf", "png",
+    "pyc", "pyd", "pyo", "rlib", "so", "tif", "tiff", "ttf", "wasm", "wav", "webp", "woff",
+    "woff2",
+];
+
+pub(crate) fn is_binary_language(id: &str) -> bool {
+    BINARY_LANGUAGES.binary_search(&id).is_ok()
+}
+
+/// Returns `true` if the beginning of `content` appears to be binary,
+/// e.g. an image or an executable, such that it can be skipped before
+/// attempting to identify the language. At most [`MAX_CONTENT_LEN`]
+/// bytes are checked.
+///
+/// Content is binary if it contains a null byte, or if it is not valid
+/// UTF-8 and many of the bytes are neither printable ASCII nor common
+/// whitespace, e.g. unlike Latin-1 text. Content with a UTF-16 byte
+/// order mark is text, and empty content is not binary.
+///
+/// [`MAX_CONTENT_LEN`]: constant.MAX_CONTENT_LEN.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::is_binary;
+/// assert!(is_binary(b"\x7fELF\x02\x01\x01\x00\x00\x00"));
+/// assert!(is_binary(b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR"));
+///
+/// assert!(!is_binary(b"fn main() {}\n"));
+/// assert!(!is_binary("// Hello, world! \u{1F30D}\n".as_bytes()));
+/// assert!(!is_binary(b"caf\xe9 cr\xe8me\n"));
+/// assert!(!is_binary(b""));
+/// ```
+pub fn is_binary(content: &[u8]) -> bool {
+    let content = &content[..content.len().min(MAX_CONTENT_LEN)];
+    if content.starts_with(&[0xFF, 0xFE]) || content.starts_with(&[0xFE, 0xFF]) {
+        return false;
+    }
+    if content.contains(&0) {
+        return true;
+    }
+    match str::from_utf8(content) {
+        Ok(_) => false,
+        // The content may be truncated in the middle of a character
+        Err(err) if err.error_len().is_none() => false,
+        Err(_) => {
+            let non_text = content.iter().filter(|&&b| !is_text_byte(b)).count();
+            non_text as f32 / content.len() as f32 > MAX_NON_TEXT_RATIO
+        }
+    }
+}
+
+#[inline]
+fn is_text_byte(b: u8) -> bool {
+    matches!(b, b'\t' | b'\n' | b'\r' | 0x0C | 0x1B | b' '..=b'~' | 0xA0..=0xFF)
+}
+
+/// Returns `true` if `ext` is the extension of binary files, e.g. images,
+/// executables, and compiled artifacts like `.class` and `.pyc`, or of a
+/// [binary language], e.g. `.zip`. The case is ignored.
+///
+/// Files with these extensions are not identified as a language, and can
+/// be skipped without reading their content.
+///
+/// [binary language]: struct.Language.html#method.is_binary
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_extension, is_binary_extension};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert!(is_binary_extension("png"));
+/// assert!(is_binary_extension("EXE"));
+/// assert!(is_binary_extension("pyc"));
+/// assert!(is_binary_extension("wasm"));
+/// assert!(is_binary_extension("zip"));
+///
+/// assert!(!is_binary_extension("py"));
+/// assert!(!is_binary_extension("rs"));
+///
+/// // Compiled Python is not Python
+/// assert_eq!(from_extension("pyc"), None);
+/// # }
+/// ```
+pub fn is_binary_extension(ext: &str) -> bool {
+    BINARY_EXTENSIONS
+        .binary_search(&ext.to_ascii_lowercase().as_str())
+        .is_ok()
+        || crate::from_extension(ext)
+            .filter(Language::is_binary)
+            .is_some()
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in BINARY_LANGUAGES.iter().zip(BINARY_LANGUAGES.iter().skip(1)) {
+        assert!(
+            a < b,
+            "Binary languages out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_binary_extensions() {
+    for (a, b) in BINARY_EXTENSIONS
+        .iter()
+        .zip(BINARY_EXTENSIONS.iter().skip(1))
+    {
+        assert!(
+            a < b,
+            "Binary extensions out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+    for &ext in BINARY_EXTENSIONS.iter() {
+        assert_eq!(ext, ext.to_ascii_lowercase());
+        assert_eq!(crate::from_extension(ext), None, "{:?} is a language", ext);
+        assert!(is_binary_extension(ext));
+    }
+}
+
+#[test]
+fn check_is_binary() {
+    use alloc::vec;
+
+    let utf16 = [0xFF, 0xFE, b'h', 0, b'i', 0];
+    assert!(!is_binary(&utf16));
+    assert!(is_binary(&utf16[2..]));
+
+    // Truncated in the middle of a character
+    let content = "foo \u{1F30D}".as_bytes();
+    assert!(!is_binary(&content[..content.len() - 1]));
+
+    assert!(is_binary(&[0x80, 0x81, 0x82, 0x83, b'a']));
+    assert!(!is_binary(b"\x1b[1mbold\x1b[0m\n"));
+
+    // Only the beginning of the content is checked
+    let mut content = vec![b'a'; MAX_CONTENT_LEN];
+    content.push(0);
+    assert!(!is_binary(&content));
+}
@@ -0,0 +1,119 @@
+use std::io;
+use std::path::Path;
+
+use crate::{Language, PercentageOptions};
+
+/// Recursively walks the directory `root`, summing the size in bytes
+/// of the files of each language, similar to the language statistics
+/// of GitHub. The result is sorted by size in descending order,
+/// followed by language.
+///
+/// The directory is walked as by [`scan_dir`], i.e. `.git` directories
+/// are skipped, and with the `ignore` feature, so are ignored files.
+/// Files where the language was not identified, [binary] files, and
+/// [system files] are skipped. Use [`breakdown_by_bytes_with`] to also
+/// skip e.g. [vendored] files.
+///
+/// [`scan_dir`]: fn.scan_dir.html
+/// [binary]: struct.Language.html#method.is_binary
+/// [system files]: struct.Language.html#method.is_system_file
+/// [`breakdown_by_bytes_with`]: fn.breakdown_by_bytes_with.html
+/// [vendored]: fn.is_vendored.html
+///
+/// # Errors
+///
+/// Returns an error if `root`, any directory within it, or the
+/// metadata of any file cannot be read.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> std::io::Result<()> {
+/// use detect_lang::breakdown_by_bytes;
+///
+/// let breakdown = breakdown_by_bytes(".")?;
+/// let total: u64 = breakdown.iter().map(|&(_, bytes)| bytes).sum();
+/// for (lang, bytes) in breakdown {
+///     println!("{}: {:.1}%", lang.name(), bytes as f64 / total as f64 * 100.0);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn breakdown_by_bytes<P: AsRef<Path>>(root: P) -> io::Result<Vec<(Language<'static>, u64)>> {
+    breakdown_by_bytes_with(root, &PercentageOptions::new())
+}
+
+/// Same as [`breakdown_by_bytes`], except files are also skipped as
+/// given by `options`, e.g. [vendored] and [generated] files.
+///
+/// [`breakdown_by_bytes`]: fn.breakdown_by_bytes.html
+/// [vendored]: fn.is_vendored.html
+/// [generated]: fn.is_generated.html
+///
+/// # Errors
+///
+/// Returns an error if `root`, any directory within it, or the
+/// metadata of any file cannot be read.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> std::io::Result<()> {
+/// use detect_lang::{breakdown_by_bytes_with, PercentageOptions};
+///
+/// let options = PercentageOptions::new().exclude_vendored(true);
+/// for (lang, bytes) in breakdown_by_bytes_with(".", &options)? {
+///     println!("{}: {} bytes", lang.name(), bytes);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn breakdown_by_bytes_with<P: AsRef<Path>>(
+    root: P,
+    options: &PercentageOptions,
+) -> io::Result<Vec<(Language<'static>, u64)>> {
+    Ok(crate::scan_dir(root)?.bytes_with(options))
+}
+
+#[test]
+fn breakdown_temp_dir() {
+    use crate::test_util::TempDir;
+    use std::fs;
+
+    let root = TempDir::new("breakdown");
+    fs::create_dir_all(root.join("src/nested")).unwrap();
+    fs::create_dir_all(root.join(".git")).unwrap();
+    fs::create_dir_all(root.join("node_modules/foo")).unwrap();
+    fs::write(root.join("README.md"), [b'a'; 10]).unwrap();
+    fs::write(root.join("src/main.rs"), [b'a'; 30]).unwrap();
+    fs::write(root.join("src/nested/lib.rs"), [b'a'; 5]).unwrap();
+    fs::write(root.join("src/nested/foo.py"), [b'a'; 20]).unwrap();
+    fs::write(root.join("src/nested/foo.unknown"), [b'a'; 100]).unwrap();
+    fs::write(root.join("desktop.ini"), [b'a'; 100]).unwrap();
+    fs::write(root.join(".git/config"), [b'a'; 100]).unwrap();
+    fs::write(root.join("node_modules/foo/index.js"), [b'a'; 15]).unwrap();
+
+    let breakdown = breakdown_by_bytes(&root).unwrap();
+    assert_eq!(
+        breakdown,
+        [
+            (Language("Rust", "rust"), 35),
+            (Language("Python", "python"), 20),
+            (Language("JavaScript", "javascript"), 15),
+            (Language("Markdown", "markdown"), 10),
+        ]
+    );
+
+    let options = PercentageOptions::new().exclude_vendored(true);
+    assert_eq!(
+        breakdown_by_bytes_with(&root, &options).unwrap(),
+        [
+            (Language("Rust", "rust"), 35),
+            (Language("Python", "python"), 20),
+            (Language("Markdown", "markdown"), 10),
+        ]
+    );
+
+    assert!(breakdown_by_bytes(root.join("missing")).is_err());
+}
@@ -0,0 +1,189 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// A build system, identified from its characteristic files, e.g.
+/// `CMakeLists.txt` for CMake.
+///
+/// *[See also `from_build_file` and `build_systems`][from_build_file].*
+///
+/// [from_build_file]: fn.from_build_file.html
+#[non_exhaustive]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum BuildSystem {
+    /// [Autotools](https://www.gnu.org/software/automake/), e.g. `configure.ac`.
+    Autotools,
+    /// [Bazel](https://bazel.build/), e.g. `BUILD.bazel` and `WORKSPACE`.
+    Bazel,
+    /// [Cargo](https://doc.rust-lang.org/cargo/), e.g. `Cargo.toml`.
+    Cargo,
+    /// [CMake](https://cmake.org/), e.g. `CMakeLists.txt`.
+    CMake,
+    /// [Gradle](https://gradle.org/), e.g. `build.gradle` and `build.gradle.kts`.
+    Gradle,
+    /// [Make](https://www.gnu.org/software/make/), e.g. `Makefile`.
+    Make,
+    /// [Maven](https://maven.apache.org/), e.g. `pom.xml`.
+    Maven,
+    /// [Meson](https://mesonbuild.com/), e.g. `meson.build`.
+    Meson,
+    /// [Ninja](https://ninja-build.org/), e.g. `build.ninja`.
+    Ninja,
+    /// [sbt](https://www.scala-sbt.org/), e.g. `build.sbt`.
+    Sbt,
+    /// [SCons](https://scons.org/), e.g. `SConstruct`.
+    SCons,
+}
+
+impl BuildSystem {
+    /// Returns the name of the build system, e.g. `CMake`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::BuildSystem;
+    /// assert_eq!(BuildSystem::CMake.name(), "CMake");
+    /// assert_eq!(BuildSystem::Sbt.name(), "sbt");
+    /// ```
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Autotools => "Autotools",
+            Self::Bazel => "Bazel",
+            Self::Cargo => "Cargo",
+            Self::CMake => "CMake",
+            Self::Gradle => "Gradle",
+            Self::Make => "Make",
+            Self::Maven => "Maven",
+            Self::Meson => "Meson",
+            Self::Ninja => "Ninja",
+            Self::Sbt => "sbt",
+            Self::SCons => "SCons",
+        }
+    }
+}
+
+use BuildSystem::*;
+
+// The array is sorted by the filename
+pub(crate) static BUILD_FILES: [(&str, BuildSystem); 20] = [
+    ("BUILD", Bazel),
+    ("BUILD.bazel", Bazel),
+    ("CMakeLists.txt", CMake),
+    ("Cargo.toml", Cargo),
+    ("GNUmakefile", Make),
+    ("MODULE.bazel", Bazel),
+    ("Makefile", Make),
+    ("SConstruct", SCons),
+    ("WORKSPACE", Bazel),
+    ("WORKSPACE.bazel", Bazel),
+    ("build.gradle", Gradle),
+    ("build.gradle.kts", Gradle),
+    ("build.ninja", Ninja),
+    ("build.sbt", Sbt),
+    ("configure.ac", Autotools),
+    ("makefile", Make),
+    ("meson.build", Meson),
+    ("pom.xml", Maven),
+    ("settings.gradle", Gradle),
+    ("settings.gradle.kts", Gradle),
+];
+
+/// Identifies a build system from the filename of one of its
+/// characteristic files, e.g. `CMakeLists.txt` for CMake.
+/// Returns `None` if the build system was not identified.
+///
+/// The filename is case-sensitive.
+///
+/// *[See also `build_systems`][build_systems].*
+///
+/// [build_systems]: fn.build_systems.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_build_file, BuildSystem};
+/// assert_eq!(from_build_file("CMakeLists.txt"), Some(BuildSystem::CMake));
+/// assert_eq!(from_build_file("BUILD.bazel"), Some(BuildSystem::Bazel));
+/// assert_eq!(from_build_file("build.gradle.kts"), Some(BuildSystem::Gradle));
+/// assert_eq!(from_build_file("Makefile"), Some(BuildSystem::Make));
+///
+/// assert_eq!(from_build_file("main.rs"), None);
+/// ```
+pub fn from_build_file(filename: &str) -> Option<BuildSystem> {
+    BUILD_FILES
+        .binary_search_by_key(&filename, |&(filename, _)| filename)
+        .ok()
+        .map(|i| BUILD_FILES[i].1)
+}
+
+/// Identifies the build systems used in the directory `dir`, from the
+/// [characteristic files] directly within it. The result is sorted, and
+/// is empty if no build systems were identified.
+///
+/// Subdirectories are not checked.
+///
+/// *[See also `project_languages`][project_languages].*
+///
+/// [characteristic files]: fn.from_build_file.html
+/// [project_languages]: fn.project_languages.html
+///
+/// # Errors
+///
+/// Returns an error if `dir` cannot be read.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> std::io::Result<()> {
+/// use detect_lang::build_systems;
+///
+/// for build_system in build_systems(".")? {
+///     println!("{}", build_system.name());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn build_systems<P: AsRef<Path>>(dir: P) -> io::Result<Vec<BuildSystem>> {
+    let mut build_systems = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            continue;
+        }
+        if let Some(build_system) = entry.file_name().to_str().and_then(from_build_file) {
+            build_systems.push(build_system);
+        }
+    }
+    build_systems.sort_unstable();
+    build_systems.dedup();
+    Ok(build_systems)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in BUILD_FILES.iter().zip(BUILD_FILES.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Build files out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_build_systems_dir() {
+    use crate::test_util::TempDir;
+
+    let root = TempDir::new("build");
+    fs::create_dir_all(root.join("sub")).unwrap();
+    fs::create_dir_all(root.join("BUILD")).unwrap();
+    fs::write(root.join("CMakeLists.txt"), "").unwrap();
+    fs::write(root.join("Makefile"), "").unwrap();
+    fs::write(root.join("GNUmakefile"), "").unwrap();
+    fs::write(root.join("sub/meson.build"), "").unwrap();
+
+    assert_eq!(build_systems(&root).unwrap(), [CMake, Make]);
+    assert_eq!(build_systems(root.join("sub")).unwrap(), [Meson]);
+    assert!(build_systems(root.join("missing")).is_err());
+}
@@ -0,0 +1,703 @@
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::ffi::OsStr;
+use std::path::Path;
+
+use crate::{
+    candidates, compound, content, filenames, heuristics, languages, shebang, to_ascii_lowercase,
+    ContentDetector, DetectContext, Detection, DetectionSource, Detector, Language,
+    LanguageValidationError, MAX_STACK_EXTENSION_LEN,
+};
+
+/// Identifies languages with per-instance customizations, e.g. overridden
+/// extensions, preferred resolutions of ambiguous extensions, and disabled
+/// languages. Use [`LanguageDetector::builder`] to create a customized detector,
+/// and [`register_extension`] to add custom languages.
+///
+/// The free functions, e.g. [`from_path`] and [`detect_explained`], behave
+/// the same as the methods of a [default] detector.
+///
+/// [`LanguageDetector::builder`]: #method.builder
+/// [`register_extension`]: #method.register_extension
+/// [default]: #method.new
+/// [`from_path`]: fn.from_path.html
+/// [`detect_explained`]: fn.detect_explained.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{LanguageDetector, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// let detector = LanguageDetector::builder()
+///     .override_extension("m", "matlab")
+///     .prefer("prolog")
+///     .disable("glsl")
+///     .build();
+///
+/// assert_eq!(detector.from_path("foo.m"), Some(Language("MATLAB", "matlab")));
+/// assert_eq!(detector.from_path("foo.pl"), Some(Language("Prolog", "prolog")));
+/// assert_eq!(detector.from_path("foo.glsl"), None);
+///
+/// // Everything else is the same as the default
+/// assert_eq!(detector.from_path("foo.rs"), Some(Language("Rust", "rust")));
+/// # }
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct LanguageDetector {
+    /// Mappings of lowercase extensions, either to built-in
+    /// or custom languages.
+    extensions: BTreeMap<String, (Cow<'static, str>, Cow<'static, str>)>,
+    /// Mappings of lowercase filenames to custom languages.
+    filenames: BTreeMap<String, (Cow<'static, str>, Cow<'static, str>)>,
+    preferred: Vec<String>,
+    disabled: BTreeSet<String>,
+    refine_xml: bool,
+}
+
+/// The detector used by the free functions.
+pub(crate) static DEFAULT_DETECTOR: LanguageDetector = LanguageDetector::new();
+
+impl LanguageDetector {
+    /// Creates a detector without any customizations, which behaves
+    /// the same as the free functions.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            extensions: BTreeMap::new(),
+            filenames: BTreeMap::new(),
+            preferred: Vec::new(),
+            disabled: BTreeSet::new(),
+            refine_xml: false,
+        }
+    }
+
+    /// Creates a builder for a customized detector.
+    #[inline]
+    pub fn builder() -> LanguageDetectorBuilder {
+        LanguageDetectorBuilder::default()
+    }
+
+    /// Maps `extension` to a custom `language`, e.g. for proprietary or
+    /// in-house formats, replacing any previous override of the extension.
+    /// The casing of the extension does not affect the result.
+    ///
+    /// Custom mappings take precedence over everything else, as
+    /// [overridden extensions] do.
+    ///
+    /// Returns an error and leaves the detector unchanged, if the
+    /// language is [invalid].
+    ///
+    /// [overridden extensions]: struct.LanguageDetectorBuilder.html#method.override_extension
+    /// [invalid]: struct.Language.html#method.validate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{LanguageDetector, Language, LanguageValidationError};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// let mut detector = LanguageDetector::new();
+    /// detector.register_extension("kicad_sch", Language("KiCad", "kicad")).unwrap();
+    ///
+    /// assert_eq!(detector.from_path("foo.kicad_sch"), Some(Language("KiCad", "kicad")));
+    /// assert_eq!(detector.from_extension("KICAD_SCH"), Some(Language("KiCad", "kicad")));
+    ///
+    /// // Languages are validated
+    /// assert_eq!(
+    ///     detector.register_extension("pl", Language("Prolog", "")),
+    ///     Err(LanguageValidationError::EmptyId),
+    /// );
+    /// assert_eq!(detector.from_extension("pl"), Some(Language("Perl", "perl")));
+    /// # }
+    /// ```
+    pub fn register_extension<S: AsRef<str>>(
+        &mut self,
+        extension: S,
+        language: Language<'_>,
+    ) -> Result<(), LanguageValidationError> {
+        language.validate()?;
+        let ext = extension.as_ref().to_ascii_lowercase();
+        let Language(name, id) = language;
+        self.extensions.insert(
+            ext,
+            (Cow::Owned(name.to_owned()), Cow::Owned(id.to_owned())),
+        );
+        Ok(())
+    }
+
+    /// Maps `filename` to a custom `language`, e.g. `BUILD` for Starlark,
+    /// replacing any previous mapping of the filename.
+    /// The casing of the filename does not affect the result.
+    ///
+    /// Custom filenames take precedence over everything else,
+    /// including [custom extensions].
+    ///
+    /// Returns an error and leaves the detector unchanged, if the
+    /// language is [invalid].
+    ///
+    /// [custom extensions]: #method.register_extension
+    /// [invalid]: struct.Language.html#method.validate
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{LanguageDetector, Language};
+    /// let mut detector = LanguageDetector::new();
+    /// detector.register_filename("BUILD", Language("Starlark", "starlark")).unwrap();
+    ///
+    /// assert_eq!(detector.from_path("foo/BUILD"), Some(Language("Starlark", "starlark")));
+    /// assert_eq!(detector.from_path("foo/build"), Some(Language("Starlark", "starlark")));
+    /// assert_eq!(detector.from_path("BUILD.md"), Some(Language("Markdown", "markdown")));
+    /// ```
+    pub fn register_filename<S: AsRef<str>>(
+        &mut self,
+        filename: S,
+        language: Language<'_>,
+    ) -> Result<(), LanguageValidationError> {
+        language.validate()?;
+        let filename = filename.as_ref().to_lowercase();
+        let Language(name, id) = language;
+        self.filenames.insert(
+            filename,
+            (Cow::Owned(name.to_owned()), Cow::Owned(id.to_owned())),
+        );
+        Ok(())
+    }
+
+    /// Identifies a language from a path.
+    /// Returns `None` if the language was not identified.
+    ///
+    /// *[See also `detect_lang::from_path`][from_path].*
+    ///
+    /// [from_path]: fn.from_path.html
+    #[inline]
+    pub fn from_path<P: AsRef<Path>>(&self, path: P) -> Option<Language<'_>> {
+        self.detect_explained(path, None).map(|(lang, _)| lang)
+    }
+
+    /// Identifies a language from a file extension.
+    /// The casing of the extension does not affect the result.
+    /// Returns `None` if the language was not identified.
+    ///
+    /// *[See also `detect_lang::from_extension`][from_extension].*
+    ///
+    /// [from_extension]: fn.from_extension.html
+    pub fn from_extension<S: AsRef<str>>(&self, extension: S) -> Option<Language<'_>> {
+        let (mut buf, mut heap) = ([0; MAX_STACK_EXTENSION_LEN], String::new());
+        let ext = to_ascii_lowercase(extension.as_ref(), &mut buf, &mut heap);
+        self.custom(ext).or_else(|| self.builtin_extension(ext))
+    }
+
+    /// Identifies a language from a path and the content of the file.
+    /// Returns `None` if the language was not identified.
+    ///
+    /// *[See also `detect_lang::from_path_with_content`][from_path_with_content].*
+    ///
+    /// [from_path_with_content]: fn.from_path_with_content.html
+    #[inline]
+    pub fn from_path_with_content<P: AsRef<Path>>(
+        &self,
+        path: P,
+        content: &[u8],
+    ) -> Option<Language<'_>> {
+        self.detect_explained(path, Some(content))
+            .map(|(lang, _)| lang)
+    }
+
+    /// Identifies a language from a path and optionally the content of the file,
+    /// along with how the language was identified and a rough confidence.
+    /// Returns `None` if the language was not identified.
+    ///
+    /// *[See also `detect_lang::detect`][detect].*
+    ///
+    /// [detect]: fn.detect.html
+    pub fn detect<P: AsRef<Path>>(&self, path: P, content: Option<&[u8]>) -> Option<Detection<'_>> {
+        let path = path.as_ref();
+        let (lang, source) = self.detect_explained(path, content)?;
+        let ambiguous = path
+            .extension()
+            .and_then(OsStr::to_str)
+            .is_some_and(crate::is_ambiguous);
+        Some(Detection::new(lang, source, ambiguous))
+    }
+
+    /// Identifies the language of each path, the same as [`from_path`].
+    /// The result is in the same order as `paths`.
+    ///
+    /// *[See also `detect_lang::detect_paths`][detect_paths].*
+    ///
+    /// [`from_path`]: #method.from_path
+    /// [detect_paths]: fn.detect_paths.html
+    pub fn detect_paths<I>(&self, paths: I) -> Vec<Option<Language<'_>>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<Path>,
+    {
+        // The custom and built-in language of each extension, as given
+        // before lowercasing, such that each distinct extension is only
+        // lowercased and resolved once
+        let mut extensions = HashMap::<String, (Option<Language<'_>>, Option<Language<'_>>)>::new();
+
+        let paths = paths.into_iter();
+        let mut langs = Vec::with_capacity(paths.size_hint().0);
+        for path in paths {
+            let path = path.as_ref();
+            let filename = path.file_name().and_then(OsStr::to_str);
+            let (custom, builtin) = match path.extension().and_then(OsStr::to_str) {
+                Some(ext) => match extensions.get(ext) {
+                    Some(&langs) => langs,
+                    None => {
+                        let (mut buf, mut heap) = ([0; MAX_STACK_EXTENSION_LEN], String::new());
+                        let lower = to_ascii_lowercase(ext, &mut buf, &mut heap);
+                        let langs = (self.custom(lower), self.builtin_extension(lower));
+                        extensions.insert(ext.to_owned(), langs);
+                        langs
+                    }
+                },
+                None => (None, None),
+            };
+
+            // The same order as `detect_explained` without content
+            let lang = filename
+                .filter(|_| !self.filenames.is_empty())
+                .and_then(|filename| self.filenames.get(&filename.to_lowercase()))
+                .map(|(name, id)| Language(name.as_ref(), id.as_ref()))
+                .or(custom)
+                .or_else(|| {
+                    let filename = filename?;
+                    self.allowed(filenames::from_filename(filename))
+                        .or_else(|| self.allowed(compound::from_filename(filename)))
+                })
+                .or(builtin)
+                .or_else(|| self.allowed(filenames::from_qualified_filename(filename?)));
+            langs.push(lang);
+        }
+        langs
+    }
+
+    /// Identifies a language from a path and optionally the content of the file,
+    /// along with how the language was identified.
+    /// Returns `None` if the language was not identified.
+    ///
+    /// Overridden extensions are checked first, and are reported as
+    /// [`DetectionSource::UserOverride`]. Disabled languages are never
+    /// returned, instead the next step of the detection is tried.
+    ///
+    /// *[See also `detect_lang::detect_explained`][detect_explained].*
+    ///
+    /// [detect_explained]: fn.detect_explained.html
+    /// [`DetectionSource::UserOverride`]: enum.DetectionSource.html#variant.UserOverride
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{DetectionSource, LanguageDetector, Language};
+    /// let detector = LanguageDetector::builder().override_extension("h", "cpp").build();
+    ///
+    /// assert_eq!(
+    ///     detector.detect_explained("foo.h", None),
+    ///     Some((Language("C++", "cpp"), DetectionSource::UserOverride)),
+    /// );
+    /// assert_eq!(
+    ///     detector.detect_explained("foo.rs", None),
+    ///     Some((Language("Rust", "rust"), DetectionSource::BuiltinExtension)),
+    /// );
+    /// ```
+    pub fn detect_explained<P: AsRef<Path>>(
+        &self,
+        path: P,
+        content: Option<&[u8]>,
+    ) -> Option<(Language<'_>, DetectionSource)> {
+        let detected = self.detect_unrefined(path.as_ref(), content)?;
+        if let (true, Some(content), "xml") = (self.refine_xml, content, detected.0.id()) {
+            if let Some(lang) = self.allowed(crate::from_xml_root(content)) {
+                return Some((lang, detected.1));
+            }
+        }
+        Some(detected)
+    }
+
+    fn detect_unrefined(
+        &self,
+        path: &Path,
+        content: Option<&[u8]>,
+    ) -> Option<(Language<'_>, DetectionSource)> {
+        let filename = path.file_name().and_then(OsStr::to_str);
+        let (mut buf, mut heap) = ([0; MAX_STACK_EXTENSION_LEN], String::new());
+        let ext = path
+            .extension()
+            .and_then(OsStr::to_str)
+            .map(|ext| to_ascii_lowercase(ext, &mut buf, &mut heap));
+
+        if let (Some(filename), false) = (filename, self.filenames.is_empty()) {
+            if let Some((name, id)) = self.filenames.get(&filename.to_lowercase()) {
+                return Some((Language(name, id), DetectionSource::UserOverride));
+            }
+        }
+
+        if let Some(ext) = ext {
+            if let Some(lang) = self.custom(ext) {
+                return Some((lang, DetectionSource::UserOverride));
+            }
+        }
+
+        if let Some(filename) = filename {
+            if let Some(lang) = self.allowed(filenames::from_filename(filename)) {
+                return Some((lang, DetectionSource::Filename));
+            }
+            if let Some(lang) = self.allowed(compound::from_filename(filename)) {
+                return Some((lang, DetectionSource::CompoundExtension));
+            }
+        }
+
+        if let Some(ext) = ext {
+            if let Some(content) = content {
+                #[cfg(feature = "jupyter")]
+                if ext == "ipynb" {
+                    if let Some(lang) = self.allowed(crate::from_notebook(content)) {
+                        return Some((lang, DetectionSource::Heuristic));
+                    }
+                }
+                if let Some(lang) = self.allowed(heuristics::disambiguate(ext, content)) {
+                    return Some((lang, DetectionSource::Heuristic));
+                }
+            }
+            if let Some(lang) = self.builtin_extension(ext) {
+                return Some((lang, DetectionSource::BuiltinExtension));
+            }
+        }
+
+        if let Some(filename) = filename {
+            if let Some(lang) = self.allowed(filenames::from_qualified_filename(filename)) {
+                return Some((lang, DetectionSource::Filename));
+            }
+        }
+
+        let content = content?;
+        let ctx = DetectContext::new(path).with_content(content);
+
+        if let Some(lang) = self.allowed(ctx.first_line().and_then(shebang::from_shebang)) {
+            return Some((lang, DetectionSource::Shebang));
+        }
+
+        if let Some(lang) = self.allowed(content::text(content).and_then(crate::from_modeline)) {
+            return Some((lang, DetectionSource::Modeline));
+        }
+
+        if let Some(lang) = self.allowed(ContentDetector.detect(&ctx)) {
+            return Some((lang, DetectionSource::Content));
+        }
+
+        #[cfg(feature = "classifier")]
+        if let Some(lang) = self.allowed(crate::classify(content)) {
+            return Some((lang, DetectionSource::Classifier));
+        }
+
+        None
+    }
+
+    fn custom(&self, ext: &str) -> Option<Language<'_>> {
+        self.extensions
+            .get(ext)
+            .map(|(name, id)| Language(name.as_ref(), id.as_ref()))
+    }
+
+    /// Resolves a lowercase extension using the built-in languages, while
+    /// applying the preferred and disabled languages.
+    fn builtin_extension(&self, ext: &str) -> Option<Language<'static>> {
+        let default = crate::from_lowercase_extension(ext);
+        let langs = match candidates::find(ext) {
+            Some(langs) => langs,
+            None => return self.allowed(default),
+        };
+
+        let preferred = self
+            .preferred
+            .iter()
+            .find_map(|id| langs.iter().find(|lang| lang.id() == id))
+            .copied();
+
+        // The default is checked before the other candidates, to
+        // preserve pointer stability
+        self.allowed(preferred)
+            .or_else(|| self.allowed(default))
+            .or_else(|| langs.iter().copied().find(|lang| !self.is_disabled(lang)))
+    }
+
+    #[inline]
+    fn is_disabled(&self, lang: &Language<'_>) -> bool {
+        self.disabled.contains(lang.id())
+    }
+
+    #[inline]
+    fn allowed(&self, lang: Option<Language<'static>>) -> Option<Language<'static>> {
+        lang.filter(|lang| !self.disabled.contains(lang.id()))
+    }
+}
+
+/// A builder for a customized [`LanguageDetector`].
+///
+/// Languages are given by [ID], ignoring ASCII case.
+///
+/// [`LanguageDetector`]: struct.LanguageDetector.html
+/// [ID]: struct.Language.html#method.id
+#[derive(Clone, Default, Debug)]
+pub struct LanguageDetectorBuilder {
+    detector: LanguageDetector,
+}
+
+impl LanguageDetectorBuilder {
+    /// Maps `extension` to the built-in language with the ID `id`,
+    /// replacing any previous override of the extension.
+    /// The casing of the extension does not affect the result.
+    ///
+    /// Overrides take precedence over everything else, including
+    /// well-known filenames and disabled languages. Unknown IDs are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{LanguageDetector, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// let detector = LanguageDetector::builder()
+    ///     .override_extension("M", "matlab")
+    ///     .override_extension("rs", "unknown")
+    ///     .build();
+    ///
+    /// assert_eq!(detector.from_extension("m"), Some(Language("MATLAB", "matlab")));
+    /// assert_eq!(detector.from_extension("rs"), Some(Language("Rust", "rust")));
+    /// # }
+    /// ```
+    pub fn override_extension<S, I>(mut self, extension: S, id: I) -> Self
+    where
+        S: AsRef<str>,
+        I: AsRef<str>,
+    {
+        if let Some(lang) = languages::from_id(&id.as_ref().to_ascii_lowercase()) {
+            let ext = extension.as_ref().to_ascii_lowercase();
+            let Language(name, id) = lang;
+            self.detector
+                .extensions
+                .insert(ext, (Cow::Borrowed(name), Cow::Borrowed(id)));
+        }
+        self
+    }
+
+    /// Prefers the language with the ID `id`, for extensions mapped to
+    /// multiple languages, e.g. `pl` for Perl and Prolog. Languages
+    /// preferred first take precedence.
+    ///
+    /// The content of the file still takes precedence, if it
+    /// disambiguates the extension.
+    ///
+    /// *[See also `ambiguous_extensions`][ambiguous_extensions].*
+    ///
+    /// [ambiguous_extensions]: fn.ambiguous_extensions.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{LanguageDetector, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// let detector = LanguageDetector::builder().prefer("coq").prefer("v").build();
+    ///
+    /// assert_eq!(detector.from_path("foo.v"), Some(Language("Coq", "coq")));
+    ///
+    /// let content = b"module foo(input a);\nendmodule\n";
+    /// assert_eq!(
+    ///     detector.from_path_with_content("foo.v", content),
+    ///     Some(Language("Verilog", "verilog")),
+    /// );
+    /// # }
+    /// ```
+    pub fn prefer<S: AsRef<str>>(mut self, id: S) -> Self {
+        let id = id.as_ref().to_ascii_lowercase();
+        if !self.detector.preferred.contains(&id) {
+            self.detector.preferred.push(id);
+        }
+        self
+    }
+
+    /// Disables the language with the ID `id`, such that it is never
+    /// identified, unless given by an [overridden extension].
+    ///
+    /// If a disabled language is the default of an extension mapped to
+    /// multiple languages, then the next candidate is used instead.
+    ///
+    /// [overridden extension]: #method.override_extension
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{LanguageDetector, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// let detector = LanguageDetector::builder().disable("glsl").disable("perl").build();
+    ///
+    /// assert_eq!(detector.from_path("foo.glsl"), None);
+    /// assert_eq!(detector.from_path("foo.pl"), Some(Language("Prolog", "prolog")));
+    /// # }
+    /// ```
+    pub fn disable<S: AsRef<str>>(mut self, id: S) -> Self {
+        self.detector
+            .disabled
+            .insert(id.as_ref().to_ascii_lowercase());
+        self
+    }
+
+    /// Refines files identified as XML into the dialect given by the
+    /// [root element] of the content, e.g. SVG and Maven POM, instead of
+    /// reporting them as XML. Disabled by default.
+    ///
+    /// The [`DetectionSource`] of a refined file is the source which
+    /// identified it as XML, e.g. the extension.
+    ///
+    /// [root element]: fn.from_xml_root.html
+    /// [`DetectionSource`]: enum.DetectionSource.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{DetectionSource, LanguageDetector, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// let detector = LanguageDetector::builder().refine_xml(true).build();
+    ///
+    /// let content = b"<project xmlns=\"http://maven.apache.org/POM/4.0.0\"/>\n";
+    /// assert_eq!(
+    ///     detector.from_path_with_content("build.xml", content),
+    ///     Some(Language("Maven POM", "maven-pom")),
+    /// );
+    /// assert_eq!(
+    ///     detector.detect_explained("build.xml", Some(content)),
+    ///     Some((Language("Maven POM", "maven-pom"), DetectionSource::BuiltinExtension)),
+    /// );
+    /// assert_eq!(
+    ///     LanguageDetector::new().from_path_with_content("build.xml", content),
+    ///     Some(Language("XML", "xml")),
+    /// );
+    ///
+    /// // Without content, or without a known root element, the file is XML
+    /// assert_eq!(detector.from_path("build.xml"), Some(Language("XML", "xml")));
+    /// assert_eq!(detector.from_path_with_content("foo.xml", b"<foo/>"), Some(Language("XML", "xml")));
+    /// # }
+    /// ```
+    pub fn refine_xml(mut self, refine: bool) -> Self {
+        self.detector.refine_xml = refine;
+        self
+    }
+
+    /// Builds the detector.
+    #[inline]
+    pub fn build(self) -> LanguageDetector {
+        self.detector
+    }
+}
+
+#[test]
+fn check_default() {
+    let detector = LanguageDetector::new();
+    for &(ext, lang) in crate::LANGUAGES.iter() {
+        assert_eq!(detector.from_extension(ext), Some(lang));
+    }
+    for &(filename, lang) in crate::FILENAMES.iter() {
+        assert_eq!(detector.from_path(filename), Some(lang));
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_disabled() {
+    let detector = LanguageDetector::builder()
+        .disable("shell")
+        .disable("c")
+        .build();
+
+    assert_eq!(detector.from_path("foo.sh"), None);
+    assert_eq!(detector.from_path(".bashrc"), None);
+    assert_eq!(detector.from_path("foo.h"), Some(Language("C++", "cpp")));
+
+    // The next step is tried instead
+    let content = b"#!/bin/sh\n# vim: ft=python\n";
+    assert_eq!(
+        detector.detect_explained("foo", Some(content)),
+        Some((Language("Python", "python"), DetectionSource::Modeline))
+    );
+}
+
+#[test]
+fn check_stack_lowercase() {
+    for &(ext, _) in crate::LANGUAGES.iter() {
+        assert!(
+            ext.len() <= MAX_STACK_EXTENSION_LEN,
+            "{:?} does not fit on the stack",
+            ext
+        );
+    }
+
+    let (mut buf, mut heap) = ([0; MAX_STACK_EXTENSION_LEN], String::new());
+    assert_eq!(to_ascii_lowercase("RŚ", &mut buf, &mut heap), "rŚ");
+    assert!(heap.is_empty());
+
+    let long = "X".repeat(MAX_STACK_EXTENSION_LEN + 1);
+    let (mut buf, mut heap) = ([0; MAX_STACK_EXTENSION_LEN], String::new());
+    assert_eq!(
+        to_ascii_lowercase(&long, &mut buf, &mut heap),
+        long.to_ascii_lowercase()
+    );
+
+    let mut detector = LanguageDetector::new();
+    detector
+        .register_extension(&long, Language("Foo", "foo"))
+        .unwrap();
+    assert_eq!(detector.from_extension(&long), Some(Language("Foo", "foo")));
+    assert_eq!(
+        detector.from_extension("RS"),
+        Some(Language("Rust", "rust"))
+    );
+}
+
+#[test]
+fn check_detect_paths() {
+    let paths = [
+        "src/main.rs",
+        "src/lib.RS",
+        "README.md",
+        "Makefile",
+        "Makefile.am",
+        "Dockerfile.prod",
+        "foo.h",
+        "foo.blade.php",
+        "foo.d.ts",
+        "foo.unknown",
+        "foo",
+        ".git/config",
+        "",
+    ];
+
+    let mut detector = LanguageDetector::builder()
+        .override_extension("h", "cpp")
+        .disable("markdown")
+        .build();
+    detector
+        .register_filename("BUILD", Language("Starlark", "starlark"))
+        .unwrap();
+    detector
+        .register_extension("ts", Language("Foo", "foo"))
+        .unwrap();
+
+    for detector in &[LanguageDetector::new(), detector] {
+        let mut all = paths.to_vec();
+        all.extend(["a/BUILD", "foo.ts", "FOO.TS", "foo.md"]);
+        all.extend(&paths);
+
+        let expected = all
+            .iter()
+            .map(|path| detector.from_path(path))
+            .collect::<Vec<_>>();
+        assert_eq!(detector.detect_paths(&all), expected);
+    }
+}
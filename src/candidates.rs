@@ -0,0 +1,319 @@
+use core::slice;
+#[cfg(feature = "std")]
+use std::ffi::OsStr;
+#[cfg(feature = "std")]
+use std::path::Path;
+
+use crate::languages::LANGUAGES;
+use crate::Language;
+#[cfg(feature = "std")]
+use crate::{compound, filenames};
+
+// The array is sorted by the extension name
+//
+// The first candidate must be the language in `LANGUAGES`
+pub(crate) static CANDIDATES: &[(&str, &[Language])] = &[
+    #[cfg(feature = "full")]
+    (
+        "cls",
+        &[
+            Language("TeX", "tex"),
+            Language("VBA", "vba"),
+            Language("Apex", "apex"),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "d",
+        &[
+            Language("D", "d"),
+            Language("DTrace", "dtrace"),
+            Language("Make", "make"),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "gml",
+        // Geography Markup Language is XML
+        &[
+            Language("GameMaker Language", "gml"),
+            Language("XML", "xml"),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "h",
+        &[
+            Language("C", "c"),
+            Language("C++", "cpp"),
+            Language("Objective-C", "objective-c"),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "inc",
+        &[
+            Language("PHP", "php"),
+            Language("Pascal", "pascal"),
+            Language("C++", "cpp"),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "m",
+        &[
+            Language("Objective-C", "objective-c"),
+            Language("MATLAB", "matlab"),
+            Language("Mercury", "mercury"),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "pl",
+        &[Language("Perl", "perl"), Language("Prolog", "prolog")],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "pp",
+        &[Language("Puppet", "puppet"), Language("Pascal", "pascal")],
+    ),
+    #[cfg(feature = "full")]
+    ("r", &[Language("R", "r"), Language("Rebol", "rebol")]),
+    #[cfg(feature = "full")]
+    (
+        "sql",
+        &[
+            Language("SQL", "sql"),
+            Language("PL/SQL", "plsql"),
+            Language("T-SQL", "tsql"),
+            Language("PostgreSQL", "postgresql"),
+            Language("MySQL", "mysql"),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    ("t", &[Language("Perl", "perl"), Language("Raku", "raku")]),
+    #[cfg(feature = "full")]
+    (
+        "ts",
+        // Qt Linguist translations are XML
+        &[Language("TypeScript", "typescript"), Language("XML", "xml")],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "v",
+        &[
+            Language("Verilog", "verilog"),
+            Language("V", "v"),
+            Language("Coq", "coq"),
+        ],
+    ),
+];
+
+/// Returns all languages of a lowercase extension, if the extension is ambiguous.
+pub(crate) fn find(ext: &str) -> Option<&'static [Language<'static>]> {
+    CANDIDATES
+        .binary_search_by_key(&ext, |&(ext, _)| ext)
+        .ok()
+        .map(|i| CANDIDATES[i].1)
+}
+
+/// Returns all languages of a file extension, e.g. Objective-C, MATLAB,
+/// and Mercury for `m`. The casing of the extension does not affect the
+/// result. Returns an empty slice if the language was not identified.
+///
+/// The first language is the one returned by [`from_extension`], and
+/// extensions mapped to a single language return only that language.
+///
+/// *[See also `from_path_all`][from_path_all].*
+///
+/// [`from_extension`]: fn.from_extension.html
+/// [from_path_all]: fn.from_path_all.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_extension_all, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(
+///     from_extension_all("pl"),
+///     [Language("Perl", "perl"), Language("Prolog", "prolog")],
+/// );
+/// assert_eq!(from_extension_all("rs"), [Language("Rust", "rust")]);
+/// assert_eq!(from_extension_all("unknown"), []);
+/// # }
+/// ```
+pub fn from_extension_all<S: AsRef<str>>(extension: S) -> &'static [Language<'static>] {
+    let ext = extension.as_ref().to_ascii_lowercase();
+    if let Some(langs) = find(&ext) {
+        return langs;
+    }
+    match LANGUAGES.binary_search_by_key(&ext.as_str(), |&(ext, _)| ext) {
+        Ok(i) => slice::from_ref(&LANGUAGES[i].1),
+        Err(_) => &[],
+    }
+}
+
+#[cfg(feature = "std")]
+/// Returns all languages of a path, in the same order as
+/// [`from_path`] checks the path. Returns an empty slice if the
+/// language was not identified.
+///
+/// Well-known filenames and compound extensions return a single
+/// language, while the extension returns all languages as by
+/// [`from_extension_all`].
+///
+/// [`from_path`]: fn.from_path.html
+/// [`from_extension_all`]: fn.from_extension_all.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_path_all, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(
+///     from_path_all("foo/bar.pl"),
+///     [Language("Perl", "perl"), Language("Prolog", "prolog")],
+/// );
+/// assert_eq!(from_path_all("Makefile"), [Language("Make", "make")]);
+/// assert_eq!(from_path_all("foo"), []);
+/// # }
+/// ```
+pub fn from_path_all<P: AsRef<Path>>(path: P) -> &'static [Language<'static>] {
+    let path = path.as_ref();
+    let filename = path.file_name().and_then(OsStr::to_str);
+
+    if let Some(filename) = filename {
+        if let Some(lang) = filenames::find(filename).or_else(|| compound::find(filename)) {
+            return slice::from_ref(lang);
+        }
+    }
+
+    if let Some(ext) = path.extension().and_then(OsStr::to_str) {
+        let langs = from_extension_all(ext);
+        if !langs.is_empty() {
+            return langs;
+        }
+    }
+
+    match filename.and_then(filenames::find_qualified) {
+        Some(lang) => slice::from_ref(lang),
+        None => &[],
+    }
+}
+
+/// Returns `true` if the file extension is mapped to multiple languages,
+/// e.g. `h` for C, C++, and Objective-C, in which case the content of
+/// the file may be needed to identify the language.
+/// The casing of the extension does not affect the result.
+///
+/// *[See also `candidates`][candidates].*
+///
+/// [candidates]: fn.candidates.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::is_ambiguous;
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert!(is_ambiguous("h"));
+/// assert!(is_ambiguous("PL"));
+/// assert!(!is_ambiguous("rs"));
+/// assert!(!is_ambiguous("unknown"));
+/// # }
+/// ```
+pub fn is_ambiguous<S: AsRef<str>>(extension: S) -> bool {
+    find(&extension.as_ref().to_ascii_lowercase()).is_some()
+}
+
+/// Returns an iterator over all languages of a file extension,
+/// the same as [`from_extension_all`].
+/// The casing of the extension does not affect the result.
+///
+/// [`from_extension_all`]: fn.from_extension_all.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{candidates, from_path_with_content, is_ambiguous, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// let ext = "v";
+/// if is_ambiguous(ext) {
+///     // Fall back to content inspection
+///     let content = b"Theorem foo : True.\nProof.\n  trivial.\nQed.\n";
+///     assert_eq!(from_path_with_content("foo.v", content), Some(Language("Coq", "coq")));
+/// }
+///
+/// assert_eq!(candidates("v").count(), 3);
+/// assert_eq!(candidates("rs").collect::<Vec<_>>(), [Language("Rust", "rust")]);
+/// assert_eq!(candidates("unknown").next(), None);
+/// # }
+/// ```
+pub fn candidates<S: AsRef<str>>(extension: S) -> impl Iterator<Item = Language<'static>> {
+    from_extension_all(extension).iter().copied()
+}
+
+/// Returns all extensions that are mapped to multiple languages,
+/// along with all of the languages. The first language is the one
+/// returned by [`from_extension`].
+///
+/// [`from_extension`]: fn.from_extension.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{ambiguous_extensions, from_extension, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// let (_, langs) = ambiguous_extensions().find(|&(ext, _)| ext == "m").unwrap();
+/// assert!(langs.contains(&Language("Objective-C", "objective-c")));
+/// assert!(langs.contains(&Language("MATLAB", "matlab")));
+///
+/// for (ext, langs) in ambiguous_extensions() {
+///     assert!(langs.len() > 1);
+///     assert_eq!(from_extension(ext), Some(langs[0]));
+/// }
+/// # }
+/// ```
+pub fn ambiguous_extensions() -> impl Iterator<Item = (&'static str, &'static [Language<'static>])>
+{
+    CANDIDATES.iter().copied()
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in CANDIDATES.iter().zip(CANDIDATES.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Candidates out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_candidates() {
+    for &(ext, langs) in CANDIDATES.iter() {
+        assert!(langs.len() > 1, "Too few candidates for {:?}", ext);
+        assert_eq!(
+            crate::from_lowercase_extension(ext),
+            Some(langs[0]),
+            "{:?}",
+            ext
+        );
+    }
+}
+
+#[test]
+fn check_all() {
+    for &(ext, lang) in LANGUAGES.iter() {
+        let langs = from_extension_all(ext);
+        assert_eq!(langs.first(), Some(&lang), "{:?}", ext);
+        #[cfg(feature = "std")]
+        assert_eq!(from_path_all(format!("foo.{}", ext)), langs);
+    }
+}
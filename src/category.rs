@@ -0,0 +1,256 @@
+use crate::Language;
+
+/// The kind of a language, similar to the types used by [GitHub Linguist].
+///
+/// [GitHub Linguist]: https://github.com/github/linguist
+#[non_exhaustive]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum Category {
+    /// Programming languages, e.g. Rust, Python, and Shell.
+    Programming,
+    /// Markup and stylesheet languages, e.g. HTML, CSS, and TeX.
+    Markup,
+    /// Data and configuration formats, e.g. JSON, YAML, and SQL.
+    Data,
+    /// Prose and documentation formats, e.g. Markdown and reStructuredText.
+    Prose,
+    /// Archive and compression formats, e.g. ZIP and Gzip.
+    Archive,
+    /// Files that are not languages, e.g. [system files].
+    ///
+    /// [system files]: struct.Language.html#method.is_system_file
+    Other,
+}
+
+/// The coarse role of files of a language within a repository,
+/// as commonly reported by code statistics tools.
+///
+/// *[See also `Language::role`][role].*
+///
+/// [role]: struct.Language.html#method.role
+#[non_exhaustive]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+pub enum Role {
+    /// Source code, e.g. Rust, HTML, and CSS.
+    Source,
+    /// Documentation, e.g. Markdown and TeX.
+    Documentation,
+    /// Configuration, e.g. TOML, INI, and YAML.
+    Configuration,
+    /// Data, e.g. JSON and SQL.
+    Data,
+    /// Assets, e.g. SVG images.
+    Asset,
+    /// Anything else, e.g. system files.
+    Other,
+}
+
+impl Category {
+    #[cfg(feature = "std")]
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Programming => "programming",
+            Self::Markup => "markup",
+            Self::Data => "data",
+            Self::Prose => "prose",
+            Self::Archive => "archive",
+            Self::Other => "other",
+        }
+    }
+}
+
+use Category::*;
+
+// The array is sorted by the language ID
+pub(crate) static CATEGORIES: [(&str, Category); 136] = [
+    ("7z", Archive),
+    ("android-layout", Markup),
+    ("apex", Programming),
+    ("asciidoc", Prose),
+    ("astro", Markup),
+    ("batch", Programming),
+    ("bibtex", Markup),
+    ("bicep", Programming),
+    ("blade", Markup),
+    ("bzip2", Archive),
+    ("c", Programming),
+    ("cassandra-ql", Programming),
+    ("clojure", Programming),
+    ("cmake", Programming),
+    ("common-lisp", Programming),
+    ("coq", Programming),
+    ("cpp", Programming),
+    ("csharp", Programming),
+    ("cson", Data),
+    ("css", Markup),
+    ("csv", Data),
+    ("cue", Programming),
+    ("d", Programming),
+    ("dart", Programming),
+    ("dhall", Programming),
+    ("dockerfile", Programming),
+    ("dtrace", Programming),
+    ("elf", Other),
+    ("emacs-lisp", Programming),
+    ("excel", Data),
+    ("feather", Data),
+    ("gdscript", Programming),
+    ("gemfile-lock", Data),
+    ("git-config", Data),
+    ("glsl", Programming),
+    ("gml", Programming),
+    ("go", Programming),
+    ("go-checksums", Data),
+    ("godot-resource", Data),
+    ("graphql", Data),
+    ("groovy", Programming),
+    ("gzip", Archive),
+    ("haml", Markup),
+    ("handlebars", Markup),
+    ("hcl", Programming),
+    ("hiveql", Programming),
+    ("hjson", Data),
+    ("hlsl", Programming),
+    ("html", Markup),
+    ("html-erb", Markup),
+    ("ini", Data),
+    ("java", Programming),
+    ("javascript", Programming),
+    ("jest-snapshot", Data),
+    ("jinja", Markup),
+    ("json", Data),
+    ("json-lines", Data),
+    ("json5", Data),
+    ("jsonc", Data),
+    ("julia", Programming),
+    ("jupyter-notebook", Markup),
+    ("kotlin", Programming),
+    ("less", Markup),
+    ("lua", Programming),
+    ("mach-o", Other),
+    ("make", Programming),
+    ("markdown", Prose),
+    ("matlab", Programming),
+    ("maven-pom", Data),
+    ("mercury", Programming),
+    ("mysql", Programming),
+    ("nix", Programming),
+    ("nushell", Programming),
+    ("objective-c", Programming),
+    ("ods", Data),
+    ("org", Prose),
+    ("parquet", Data),
+    ("pascal", Programming),
+    ("pdf", Prose),
+    ("pe", Other),
+    ("perl", Programming),
+    ("php", Programming),
+    ("plsql", Programming),
+    ("png", Data),
+    ("pod", Prose),
+    ("postgresql", Programming),
+    ("powershell", Programming),
+    ("prolog", Programming),
+    ("psv", Data),
+    ("puppet", Programming),
+    ("python", Programming),
+    ("r", Programming),
+    ("racket", Programming),
+    ("raku", Programming),
+    ("rar", Archive),
+    ("rebol", Programming),
+    ("restructuredtext", Prose),
+    ("ruby", Programming),
+    ("rust", Programming),
+    ("sass", Markup),
+    ("scala", Programming),
+    ("scss", Markup),
+    ("shaderlab", Programming),
+    ("shell", Programming),
+    ("sparql", Data),
+    ("sql", Data),
+    ("sqlite", Data),
+    ("svelte", Markup),
+    ("svg", Data),
+    ("swift", Programming),
+    ("system-file", Other),
+    ("tar", Archive),
+    ("tar-gzip", Archive),
+    ("terraform", Programming),
+    ("tex", Markup),
+    ("text", Prose),
+    ("textile", Prose),
+    ("toml", Data),
+    ("tsql", Programming),
+    ("tsv", Data),
+    ("tsx", Programming),
+    ("typescript", Programming),
+    ("typescript-declaration", Programming),
+    ("v", Programming),
+    ("vba", Programming),
+    ("verilog", Programming),
+    ("vim-script", Programming),
+    ("vue", Markup),
+    ("xhtml", Markup),
+    ("xml", Data),
+    ("xml-property-list", Data),
+    ("xslt", Programming),
+    ("xz", Archive),
+    ("yaml", Data),
+    ("zip", Archive),
+    ("zstd", Archive),
+];
+
+pub(crate) fn category(id: &str) -> Option<Category> {
+    CATEGORIES
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .ok()
+        .map(|i| CATEGORIES[i].1)
+}
+
+pub(crate) fn role(lang: &Language<'_>) -> Option<Role> {
+    let role = match (category(lang.id())?, lang.id()) {
+        (Data, "png") | (Data, "svg") => Role::Asset,
+        (_, "cson")
+        | (_, "git-config")
+        | (_, "hcl")
+        | (_, "hjson")
+        | (_, "ini")
+        | (_, "json5")
+        | (_, "jsonc")
+        | (_, "maven-pom")
+        | (_, "terraform")
+        | (_, "toml")
+        | (_, "xml-property-list")
+        | (_, "yaml") => Role::Configuration,
+        (Markup, "bibtex") | (Markup, "tex") => Role::Documentation,
+        (Programming, _) | (Markup, _) => Role::Source,
+        (Data, _) => Role::Data,
+        (Prose, _) => Role::Documentation,
+        (Archive, _) | (Other, _) => Role::Other,
+    };
+    Some(role)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in CATEGORIES.iter().zip(CATEGORIES.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Categories out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_categories() {
+    for lang in crate::languages::all() {
+        assert!(
+            category(lang.id()).is_some(),
+            "Missing category for {:?}",
+            lang
+        );
+    }
+}
@@ -0,0 +1,657 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::{content, Language};
+
+// Small, idiomatic samples of each language, used to train the classifier.
+// Samples should mostly consist of keywords, common library names, and
+// punctuation, rather than arbitrary identifiers.
+pub(crate) static SAMPLES: &[(Language, &str)] = &[
+    (
+        Language("C", "c"),
+        r#"#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+typedef struct node {
+    int value;
+    struct node *next;
+} node_t;
+
+static int count(const node_t *head) {
+    int n = 0;
+    for (; head != NULL; head = head->next) {
+        n++;
+    }
+    return n;
+}
+
+int main(int argc, char **argv) {
+    char *buf = malloc(sizeof(char) * 64);
+    if (buf == NULL) {
+        fprintf(stderr, "out of memory\n");
+        return EXIT_FAILURE;
+    }
+    memset(buf, 0, 64);
+    printf("%s %d\n", argv[0], argc);
+    free(buf);
+    return 0;
+}
+"#,
+    ),
+    (
+        Language("C++", "cpp"),
+        r#"#include <iostream>
+#include <vector>
+#include <string>
+#include <memory>
+
+namespace foo {
+
+template <typename T>
+class Stack {
+public:
+    void push(const T& value) { items_.push_back(value); }
+    T pop() {
+        T value = items_.back();
+        items_.pop_back();
+        return value;
+    }
+    bool empty() const noexcept { return items_.empty(); }
+
+private:
+    std::vector<T> items_;
+};
+
+} // namespace foo
+
+int main() {
+    auto stack = std::make_unique<foo::Stack<std::string>>();
+    stack->push("Hello World");
+    for (const auto& s : std::vector<std::string>{"a", "b"}) {
+        std::cout << s << std::endl;
+    }
+    std::cout << stack->pop() << std::endl;
+    return 0;
+}
+"#,
+    ),
+    #[cfg(feature = "full")]
+    (
+        Language("C#", "csharp"),
+        r#"using System;
+using System.Collections.Generic;
+using System.Linq;
+
+namespace Foo
+{
+    public class Program
+    {
+        public string Name { get; set; }
+
+        public static void Main(string[] args)
+        {
+            var items = new List<int> { 1, 2, 3 };
+            foreach (var item in items.Where(x => x > 1))
+            {
+                Console.WriteLine($"Item {item}");
+            }
+        }
+
+        private async Task<bool> RunAsync()
+        {
+            await Task.Delay(100);
+            return true;
+        }
+    }
+}
+"#,
+    ),
+    (
+        Language("CSS", "css"),
+        r#"body {
+    margin: 0;
+    padding: 0;
+    font-family: sans-serif;
+    background-color: #fff;
+}
+
+.container > .item:hover {
+    color: #333;
+    border: 1px solid #ccc;
+    border-radius: 4px;
+}
+
+@media (max-width: 600px) {
+    #header {
+        display: none;
+        width: 100%;
+    }
+}
+"#,
+    ),
+    #[cfg(feature = "full")]
+    (
+        Language("Go", "go"),
+        r#"package main
+
+import (
+    "fmt"
+    "os"
+    "strings"
+)
+
+type Server struct {
+    Name string
+    Port int
+}
+
+func (s *Server) String() string {
+    return fmt.Sprintf("%s:%d", s.Name, s.Port)
+}
+
+func main() {
+    s := &Server{Name: "localhost", Port: 8080}
+    if err := run(s); err != nil {
+        fmt.Fprintln(os.Stderr, err)
+        os.Exit(1)
+    }
+    for i, arg := range os.Args {
+        fmt.Println(i, strings.ToUpper(arg))
+    }
+    defer fmt.Println("done")
+    go func() {}()
+}
+"#,
+    ),
+    (
+        Language("HTML", "html"),
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="utf-8">
+    <title>Hello World</title>
+    <link rel="stylesheet" href="style.css">
+</head>
+<body>
+    <div class="container">
+        <h1>Hello World</h1>
+        <p>Lorem <a href="/">ipsum</a></p>
+        <ul>
+            <li>Foo</li>
+            <li>Bar</li>
+        </ul>
+        <img src="foo.png" alt="">
+    </div>
+    <script src="main.js"></script>
+</body>
+</html>
+"#,
+    ),
+    #[cfg(feature = "full")]
+    (
+        Language("Java", "java"),
+        r#"package com.example;
+
+import java.util.ArrayList;
+import java.util.List;
+
+public class Main {
+    private final List<String> items = new ArrayList<>();
+
+    @Override
+    public String toString() {
+        return "Main";
+    }
+
+    public static void main(String[] args) throws Exception {
+        Main main = new Main();
+        for (String arg : args) {
+            main.items.add(arg);
+        }
+        System.out.println(main.items.size());
+    }
+}
+"#,
+    ),
+    (
+        Language("JavaScript", "javascript"),
+        r#"const fs = require('fs');
+
+function readConfig(path) {
+    return new Promise((resolve, reject) => {
+        fs.readFile(path, 'utf8', (err, data) => {
+            if (err) {
+                return reject(err);
+            }
+            resolve(JSON.parse(data));
+        });
+    });
+}
+
+document.addEventListener('DOMContentLoaded', () => {
+    const el = document.querySelector('#app');
+    let items = [1, 2, 3].map((x) => x * 2);
+    console.log(items, el);
+});
+
+module.exports = { readConfig };
+"#,
+    ),
+    (
+        Language("JSON", "json"),
+        r#"{
+    "name": "foo",
+    "version": "1.0.0",
+    "private": true,
+    "dependencies": {
+        "bar": "^2.0.0"
+    },
+    "items": [
+        { "id": 1, "enabled": false },
+        { "id": 2, "enabled": null }
+    ]
+}
+"#,
+    ),
+    (
+        Language("Markdown", "markdown"),
+        r#"# Hello World
+
+Lorem ipsum dolor sit amet, **consectetur** adipiscing elit.
+
+## Installation
+
+- Foo
+- Bar
+- [Baz](https://example.com)
+
+1. First
+2. Second
+
+> Note that this is a quote.
+
+```
+cargo build
+```
+"#,
+    ),
+    #[cfg(feature = "full")]
+    (
+        Language("PHP", "php"),
+        r#"<?php
+
+namespace App;
+
+use App\Models\User;
+
+class UserController extends Controller
+{
+    public function index($request)
+    {
+        $users = User::all();
+        foreach ($users as $user) {
+            echo $user->name;
+        }
+        return view('users', ['users' => $users]);
+    }
+}
+"#,
+    ),
+    (
+        Language("Python", "python"),
+        r#"import os
+import sys
+from collections import defaultdict
+
+
+class Foo(object):
+    def __init__(self, name):
+        self.name = name
+
+    def __repr__(self):
+        return "Foo(%r)" % self.name
+
+
+def main():
+    counts = defaultdict(int)
+    for arg in sys.argv[1:]:
+        if arg is not None and not arg.startswith("-"):
+            counts[arg] += 1
+    with open(os.devnull, "w") as f:
+        print(counts, file=f)
+    try:
+        pass
+    except Exception as e:
+        raise e
+    return [x for x in range(10) if x % 2 == 0]
+
+
+if __name__ == "__main__":
+    main()
+"#,
+    ),
+    #[cfg(feature = "full")]
+    (
+        Language("Ruby", "ruby"),
+        r#"require 'json'
+
+module Foo
+  class Bar
+    attr_reader :name
+
+    def initialize(name)
+      @name = name
+    end
+
+    def to_s
+      "Bar(#{@name})"
+    end
+  end
+end
+
+[1, 2, 3].each do |x|
+  puts x unless x.nil?
+end
+
+def hello(name = nil)
+  name ||= 'World'
+  puts "Hello #{name}"
+end
+"#,
+    ),
+    (
+        Language("Rust", "rust"),
+        r#"use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Clone, Debug)]
+pub struct Foo<'a> {
+    name: &'a str,
+    items: Vec<u32>,
+}
+
+impl<'a> Foo<'a> {
+    pub fn new(name: &'a str) -> Self {
+        Self { name, items: Vec::new() }
+    }
+}
+
+impl fmt::Display for Foo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+fn main() {
+    let mut map: HashMap<String, usize> = HashMap::new();
+    let foo = Foo::new("foo");
+    match map.get(&foo.name.to_string()) {
+        Some(n) => println!("{}", n),
+        None => {}
+    }
+    if let Some(x) = foo.items.iter().next() {
+        map.insert(x.to_string(), 1);
+    }
+}
+"#,
+    ),
+    (
+        Language("Shell", "shell"),
+        r#"#!/bin/sh
+set -e
+
+DIR="$(cd "$(dirname "$0")" && pwd)"
+
+if [ -z "$1" ]; then
+    echo "usage: $0 <name>" >&2
+    exit 1
+fi
+
+for f in "$DIR"/*.txt; do
+    if [ -f "$f" ]; then
+        cat "$f" | grep -v '^#' > /dev/null
+    fi
+done
+
+case "$1" in
+    start) echo "starting" ;;
+    *) echo "unknown" ;;
+esac
+
+export PATH="$DIR/bin:$PATH"
+"#,
+    ),
+    #[cfg(feature = "full")]
+    (
+        Language("SQL", "sql"),
+        r#"CREATE TABLE users (
+    id INTEGER PRIMARY KEY,
+    name VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO users (name) VALUES ('foo');
+
+SELECT u.name, COUNT(*) AS total
+FROM users u
+LEFT JOIN orders o ON o.user_id = u.id
+WHERE u.name IS NOT NULL
+GROUP BY u.name
+ORDER BY total DESC;
+
+UPDATE users SET name = 'bar' WHERE id = 1;
+DELETE FROM users WHERE id = 2;
+"#,
+    ),
+    (
+        Language("TOML", "toml"),
+        r#"[package]
+name = "foo"
+version = "0.1.0"
+edition = "2018"
+
+[dependencies]
+serde = { version = "1", features = ["derive"] }
+
+[[bin]]
+name = "bar"
+path = "src/main.rs"
+"#,
+    ),
+    (
+        Language("YAML", "yaml"),
+        r#"name: CI
+on:
+  push:
+    branches:
+      - master
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v2
+      - name: Build
+        run: cargo build
+        env:
+          FOO: true
+"#,
+    ),
+];
+
+/// The minimum number of tokens, which must be known to the classifier.
+const MIN_TOKENS: usize = 4;
+
+struct Model {
+    languages: Vec<(Language<'static>, HashMap<&'static str, u32>, u32)>,
+    vocabulary: usize,
+}
+
+impl Model {
+    fn train() -> Self {
+        let mut vocabulary = HashMap::new();
+        let languages = SAMPLES
+            .iter()
+            .map(|&(lang, sample)| {
+                let mut counts = HashMap::new();
+                let mut total = 0;
+                for token in tokens(sample) {
+                    *counts.entry(token).or_insert(0) += 1;
+                    vocabulary.insert(token, ());
+                    total += 1;
+                }
+                (lang, counts, total)
+            })
+            .collect();
+        Self {
+            languages,
+            vocabulary: vocabulary.len(),
+        }
+    }
+
+    fn get() -> &'static Self {
+        static MODEL: OnceLock<Model> = OnceLock::new();
+        MODEL.get_or_init(Self::train)
+    }
+
+    fn is_known(&self, token: &str) -> bool {
+        self.languages
+            .iter()
+            .any(|(_, counts, _)| counts.contains_key(token))
+    }
+}
+
+/// Splits `s` into identifiers, e.g. `HashMap`, and runs of punctuation,
+/// e.g. `::` and `=>`. Numbers and whitespace are skipped.
+fn tokens(s: &str) -> impl Iterator<Item = &str> {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut rest = s;
+    std::iter::from_fn(move || loop {
+        rest = rest.trim_start();
+        let c = rest.chars().next()?;
+        let len = if is_word(c) {
+            rest.find(|c| !is_word(c)).unwrap_or(rest.len())
+        } else {
+            rest.chars()
+                .take_while(|&c| !is_word(c) && !c.is_whitespace())
+                .take(3)
+                .map(char::len_utf8)
+                .sum()
+        };
+        let (token, tail) = rest.split_at(len);
+        rest = tail;
+        if !c.is_ascii_digit() {
+            return Some(token);
+        }
+    })
+}
+
+/// Identifies a language from the content of a file, using a small
+/// naive Bayes classifier trained on built-in samples.
+/// Returns `None` if the content is too short or not valid UTF-8.
+///
+/// Unlike [`from_content`], the classifier always guesses a language
+/// given enough content, and is only intended as a last resort, e.g.
+/// for pasted snippets. Only the following languages are classified:
+///
+/// C, C#, C++, CSS, Go, HTML, Java, JavaScript, JSON, Markdown, PHP,
+/// Python, Ruby, Rust, Shell, SQL, TOML, and YAML.
+///
+/// At most the first [`MAX_CONTENT_LEN`] bytes are inspected.
+///
+/// This requires the `classifier` feature.
+///
+/// [`from_content`]: fn.from_content.html
+/// [`MAX_CONTENT_LEN`]: constant.MAX_CONTENT_LEN.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{classify, Language};
+/// let snippet = "let mut items = Vec::new();\nitems.push(1);\n";
+/// assert_eq!(classify(snippet.as_bytes()), Some(Language("Rust", "rust")));
+///
+/// let snippet = "for x in range(10):\n    print(x)\n";
+/// assert_eq!(classify(snippet.as_bytes()), Some(Language("Python", "python")));
+///
+/// assert_eq!(classify(b"foo"), None);
+/// ```
+pub fn classify(content: &[u8]) -> Option<Language<'static>> {
+    let content = content::text(content)?;
+    let model = Model::get();
+
+    let tokens: Vec<&str> = tokens(content).collect();
+    if tokens.iter().filter(|t| model.is_known(t)).count() < MIN_TOKENS {
+        return None;
+    }
+
+    let score = |counts: &HashMap<&str, u32>, total: u32| -> f64 {
+        let denominator = f64::from(total) + model.vocabulary as f64;
+        tokens
+            .iter()
+            .map(|token| {
+                let count = counts.get(token).copied().unwrap_or(0);
+                ((f64::from(count) + 1.0) / denominator).ln()
+            })
+            .sum()
+    };
+
+    model
+        .languages
+        .iter()
+        .map(|(lang, counts, total)| (*lang, score(counts, *total)))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(lang, _)| lang)
+}
+
+#[test]
+fn check_tokens() {
+    assert_eq!(
+        tokens("use std::fmt;\nx => 10 + y_1").collect::<Vec<_>>(),
+        ["use", "std", "::", "fmt", ";", "x", "=>", "+", "y_1"]
+    );
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_classify() {
+    let cases: &[(&str, &str)] = &[
+        ("#include <stdio.h>\nint x = 0;\nprintf(\"%d\", x);\n", "c"),
+        ("std::vector<int> v;\nstd::cout << v.size();\n", "cpp"),
+        (
+            "var x = new List<string>();\nConsole.WriteLine(x);\n",
+            "csharp",
+        ),
+        (".foo {\n  color: red;\n  margin: 0;\n}\n", "css"),
+        ("x := 1\nif err != nil {\n  return err\n}\n", "go"),
+        ("<div class=\"foo\">\n  <p>Hello</p>\n</div>\n", "html"),
+        (
+            "public static void foo(String s) {\n  System.out.println(s);\n}\n",
+            "java",
+        ),
+        (
+            "const x = () => {\n  console.log(document);\n};\n",
+            "javascript",
+        ),
+        ("$user = User::find(1);\necho $user->name;\n", "php"),
+        (
+            "def foo(self):\n    return [x for x in self.items]\n",
+            "python",
+        ),
+        ("items.each do |x|\n  puts x\nend\n", "ruby"),
+        ("let mut items = Vec::new();\nitems.push(1);\n", "rust"),
+        ("if [ -f \"$1\" ]; then\n  echo \"$1\"\nfi\n", "shell"),
+        ("SELECT name FROM users WHERE id = 1;\n", "sql"),
+    ];
+    for &(content, id) in cases {
+        assert_eq!(
+            classify(content.as_bytes()).map(|lang| lang.id()),
+            Some(id),
+            "{:?}",
+            content
+        );
+    }
+
+    assert_eq!(classify(b""), None);
+    assert_eq!(classify(b"\xFF\xFE"), None);
+}
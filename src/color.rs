@@ -0,0 +1,151 @@
+// The array is sorted by the language ID
+//
+// Colors are lowercase `#rrggbb` hex colors, as used by GitHub Linguist
+pub(crate) static COLORS: [(&str, &str); 103] = [
+    ("apex", "#1797c0"),
+    ("asciidoc", "#73a0c5"),
+    ("astro", "#ff5a03"),
+    ("batch", "#c1f12e"),
+    ("bibtex", "#778899"),
+    ("bicep", "#519aba"),
+    ("blade", "#f7523f"),
+    ("c", "#555555"),
+    ("clojure", "#db5855"),
+    ("cmake", "#da3434"),
+    ("common-lisp", "#3fb68b"),
+    ("coq", "#d0b68c"),
+    ("cpp", "#f34b7d"),
+    ("csharp", "#178600"),
+    ("cson", "#244776"),
+    ("css", "#563d7c"),
+    ("csv", "#237346"),
+    ("cue", "#5886e1"),
+    ("d", "#ba595e"),
+    ("dart", "#00b4ab"),
+    ("dhall", "#dfafff"),
+    ("dockerfile", "#384d54"),
+    ("emacs-lisp", "#c065db"),
+    ("gdscript", "#355570"),
+    ("gemfile-lock", "#701516"),
+    ("git-config", "#f44d27"),
+    ("glsl", "#5686a5"),
+    ("gml", "#71b417"),
+    ("go", "#00add8"),
+    ("go-checksums", "#00add8"),
+    ("godot-resource", "#355570"),
+    ("graphql", "#e10098"),
+    ("groovy", "#4298b8"),
+    ("haml", "#ece2a9"),
+    ("handlebars", "#f7931e"),
+    ("hcl", "#844fba"),
+    ("hiveql", "#dce200"),
+    ("hjson", "#0c64e8"),
+    ("hlsl", "#aace60"),
+    ("html", "#e34c26"),
+    ("html-erb", "#701516"),
+    ("ini", "#d1dbe0"),
+    ("java", "#b07219"),
+    ("javascript", "#f1e05a"),
+    ("jest-snapshot", "#15c213"),
+    ("jinja", "#a52a22"),
+    ("json", "#292929"),
+    ("json5", "#267cb9"),
+    ("jsonc", "#292929"),
+    ("julia", "#a270ba"),
+    ("jupyter-notebook", "#da5b0b"),
+    ("kotlin", "#a97bff"),
+    ("less", "#1d365d"),
+    ("lua", "#000080"),
+    ("make", "#427819"),
+    ("markdown", "#083fa1"),
+    ("matlab", "#e16737"),
+    ("mercury", "#ff2b2b"),
+    ("nix", "#7e7eff"),
+    ("nushell", "#4e9906"),
+    ("objective-c", "#438eff"),
+    ("org", "#77aa99"),
+    ("pascal", "#e3f171"),
+    ("perl", "#0298c3"),
+    ("php", "#4f5d95"),
+    ("plsql", "#dad8d8"),
+    ("powershell", "#012456"),
+    ("prolog", "#74283c"),
+    ("puppet", "#302b6d"),
+    ("python", "#3572a5"),
+    ("r", "#198ce7"),
+    ("racket", "#3c5caa"),
+    ("raku", "#0000fb"),
+    ("rebol", "#358a5b"),
+    ("restructuredtext", "#141414"),
+    ("ruby", "#701516"),
+    ("rust", "#dea584"),
+    ("sass", "#a53b70"),
+    ("scala", "#c22d40"),
+    ("scss", "#c6538c"),
+    ("shaderlab", "#222c37"),
+    ("shell", "#89e051"),
+    ("sparql", "#0c4597"),
+    ("sql", "#e38c00"),
+    ("svelte", "#ff3e00"),
+    ("svg", "#ff9900"),
+    ("swift", "#f05138"),
+    ("terraform", "#844fba"),
+    ("tex", "#3d6117"),
+    ("textile", "#ffe7ac"),
+    ("toml", "#9c4221"),
+    ("tsql", "#e38c00"),
+    ("tsv", "#237346"),
+    ("tsx", "#3178c6"),
+    ("typescript", "#3178c6"),
+    ("typescript-declaration", "#3178c6"),
+    ("v", "#4f87c4"),
+    ("vba", "#867db1"),
+    ("verilog", "#b2b7f8"),
+    ("vim-script", "#199f4b"),
+    ("vue", "#41b883"),
+    ("xml", "#0060ac"),
+    ("yaml", "#cb171e"),
+];
+
+pub(crate) fn color(id: &str) -> Option<&'static str> {
+    COLORS
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .ok()
+        .map(|i| COLORS[i].1)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in COLORS.iter().zip(COLORS.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Colors out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_colors() {
+    for &(id, color) in COLORS.iter() {
+        assert!(
+            crate::category::category(id).is_some(),
+            "Color for unknown language {:?}",
+            id
+        );
+        let hex = color.strip_prefix('#').unwrap_or("");
+        assert!(
+            hex.len() == 6
+                && hex
+                    .bytes()
+                    .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b)),
+            "Invalid color {:?} for {:?}",
+            color,
+            id
+        );
+    }
+
+    assert_eq!(color("rust"), Some("#dea584"));
+    assert_eq!(color("zip"), None);
+}
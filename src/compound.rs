@@ -0,0 +1,111 @@
+use crate::Language;
+
+// The array is sorted by the extension name
+//
+// Extensions are lowercase and contain at least one `.`
+pub(crate) static COMPOUND_EXTENSIONS: &[(&str, Language)] = &[
+    #[cfg(feature = "full")]
+    ("blade.php", Language("Blade", "blade")),
+    #[cfg(feature = "full")]
+    (
+        "d.ts",
+        Language("TypeScript Declaration", "typescript-declaration"),
+    ),
+    #[cfg(feature = "full")]
+    ("html.erb", Language("HTML+ERB", "html-erb")),
+    ("spec.ts", Language("TypeScript", "typescript")),
+    #[cfg(feature = "full")]
+    ("tar.gz", Language("Gzipped Tar", "tar-gzip")),
+];
+
+#[cfg(feature = "std")]
+/// Finds the language of the longest compound extension of `filename`,
+/// ignoring case, e.g. `tar.gz` for `foo.TAR.GZ`.
+#[inline]
+pub(crate) fn from_filename(filename: &str) -> Option<Language<'static>> {
+    find(filename).copied()
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn find(filename: &str) -> Option<&'static Language<'static>> {
+    COMPOUND_EXTENSIONS
+        .iter()
+        .filter(|&&(ext, _)| has_extension(filename, ext))
+        .max_by_key(|&&(ext, _)| ext.len())
+        .map(|(_, lang)| lang)
+}
+
+// Requires a non-empty stem, such that `.tar.gz` is not `tar.gz`
+#[cfg(feature = "std")]
+fn has_extension(filename: &str, ext: &str) -> bool {
+    let filename = filename.as_bytes();
+    let ext = ext.as_bytes();
+    filename.len() > ext.len() + 1 && {
+        let (stem, suffix) = filename.split_at(filename.len() - ext.len());
+        stem.ends_with(b".") && suffix.eq_ignore_ascii_case(ext)
+    }
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in COMPOUND_EXTENSIONS
+        .iter()
+        .zip(COMPOUND_EXTENSIONS.iter().skip(1))
+    {
+        assert!(
+            a.0 < b.0,
+            "Compound extensions out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[cfg(feature = "full")]
+#[cfg(feature = "std")]
+#[test]
+fn check_longest_suffix() {
+    use crate::from_path;
+
+    let dts = Language("TypeScript Declaration", "typescript-declaration");
+    assert_eq!(from_path("foo.d.ts"), Some(dts));
+    assert_eq!(from_path("foo.D.TS"), Some(dts));
+    assert_eq!(from_path("foo.bar.d.ts"), Some(dts));
+    assert_eq!(
+        from_path("d.ts"),
+        Some(Language("TypeScript", "typescript"))
+    );
+    assert_eq!(
+        from_path("foo.spec.ts"),
+        Some(Language("TypeScript", "typescript"))
+    );
+    assert_eq!(
+        from_path("foo.ts"),
+        Some(Language("TypeScript", "typescript"))
+    );
+
+    #[cfg(feature = "full")]
+    {
+        assert_eq!(
+            from_path("welcome.blade.php"),
+            Some(Language("Blade", "blade"))
+        );
+        assert_eq!(from_path("welcome.php"), Some(Language("PHP", "php")));
+        assert_eq!(
+            from_path("index.html.erb"),
+            Some(Language("HTML+ERB", "html-erb"))
+        );
+        assert_eq!(
+            from_path("archive.tar.gz"),
+            Some(Language("Gzipped Tar", "tar-gzip"))
+        );
+    }
+}
+
+#[test]
+fn check_compound_extensions() {
+    for &(ext, _) in COMPOUND_EXTENSIONS.iter() {
+        assert!(ext.contains('.'), "{:?} is not compound", ext);
+        assert_eq!(ext, ext.to_ascii_lowercase(), "{:?} is not lowercase", ext);
+    }
+}
@@ -0,0 +1,302 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{languages, Language, LanguageDetector, LanguageValidationError};
+
+#[derive(serde::Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct Config {
+    extensions: BTreeMap<String, ConfigLanguage>,
+    filenames: BTreeMap<String, ConfigLanguage>,
+    prefer: Vec<String>,
+    disable: Vec<String>,
+}
+
+/// Either the ID of a built-in language, or a custom language.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum ConfigLanguage {
+    Id(String),
+    Custom { name: String, id: String },
+}
+
+impl Config {
+    fn into_detector(self) -> Result<LanguageDetector, ConfigError> {
+        let mut builder = LanguageDetector::builder();
+        for id in &self.prefer {
+            builder = builder.prefer(builtin(id)?.id());
+        }
+        for id in &self.disable {
+            builder = builder.disable(builtin(id)?.id());
+        }
+
+        let mut custom = Vec::new();
+        for (ext, lang) in self.extensions {
+            match lang {
+                ConfigLanguage::Id(id) => {
+                    builder = builder.override_extension(&ext, builtin(&id)?.id());
+                }
+                ConfigLanguage::Custom { name, id } => custom.push((ext, name, id)),
+            }
+        }
+
+        let mut detector = builder.build();
+        for (ext, name, id) in custom {
+            detector
+                .register_extension(&ext, Language(&name, &id))
+                .map_err(|error| ConfigError::InvalidLanguage { key: ext, error })?;
+        }
+
+        for (filename, lang) in self.filenames {
+            let result = match &lang {
+                ConfigLanguage::Id(id) => detector.register_filename(&filename, builtin(id)?),
+                ConfigLanguage::Custom { name, id } => {
+                    detector.register_filename(&filename, Language(name, id))
+                }
+            };
+            result.map_err(|error| ConfigError::InvalidLanguage {
+                key: filename,
+                error,
+            })?;
+        }
+
+        Ok(detector)
+    }
+}
+
+fn builtin(id: &str) -> Result<Language<'static>, ConfigError> {
+    languages::from_id(&id.to_ascii_lowercase())
+        .ok_or_else(|| ConfigError::UnknownId { id: id.to_owned() })
+}
+
+impl LanguageDetector {
+    /// Loads a detector from a TOML or YAML config file, as determined
+    /// by the extension of `path`, i.e. `.toml`, `.yaml`, or `.yml`.
+    ///
+    /// The config may contain the following, all of which are optional:
+    ///
+    /// - `extensions`: Extensions mapped to [overridden] built-in languages
+    ///   by ID, or to [custom] languages given by `name` and `id`
+    /// - `filenames`: [Filenames] mapped to languages, the same as `extensions`
+    /// - `prefer`: IDs of [preferred] languages for ambiguous extensions
+    /// - `disable`: IDs of [disabled] languages
+    ///
+    /// ```toml
+    /// prefer = ["prolog"]
+    /// disable = ["glsl"]
+    ///
+    /// [extensions]
+    /// m = "matlab"
+    /// kicad_sch = { name = "KiCad", id = "kicad" }
+    ///
+    /// [filenames]
+    /// BUILD = { name = "Starlark", id = "starlark" }
+    /// ```
+    ///
+    /// Built-in languages are given by ID, ignoring ASCII case.
+    /// Unknown IDs and invalid custom languages result in an error.
+    ///
+    /// *This function requires the `config` feature.*
+    ///
+    /// [overridden]: struct.LanguageDetectorBuilder.html#method.override_extension
+    /// [custom]: #method.register_extension
+    /// [Filenames]: #method.register_filename
+    /// [preferred]: struct.LanguageDetectorBuilder.html#method.prefer
+    /// [disabled]: struct.LanguageDetectorBuilder.html#method.disable
+    pub fn from_config_path<P: AsRef<Path>>(path: P) -> Result<LanguageDetector, ConfigError> {
+        let path = path.as_ref();
+        let parse = match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => Self::from_toml_str,
+            Some(ext) if ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml") => {
+                Self::from_yaml_str
+            }
+            _ => {
+                return Err(ConfigError::UnsupportedFormat {
+                    path: path.to_owned(),
+                })
+            }
+        };
+        let config = fs::read_to_string(path).map_err(ConfigError::Io)?;
+        parse(&config)
+    }
+
+    /// Loads a detector from a TOML config.
+    ///
+    /// *[See also `from_config_path`][from_config_path].*
+    ///
+    /// *This function requires the `config` feature.*
+    ///
+    /// [from_config_path]: #method.from_config_path
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{LanguageDetector, Language};
+    /// let detector = LanguageDetector::from_toml_str(r#"
+    ///     prefer = ["prolog"]
+    ///
+    ///     [extensions]
+    ///     m = "matlab"
+    ///     kicad_sch = { name = "KiCad", id = "kicad" }
+    /// "#).unwrap();
+    ///
+    /// assert_eq!(detector.from_path("foo.pl"), Some(Language("Prolog", "prolog")));
+    /// assert_eq!(detector.from_path("foo.m"), Some(Language("MATLAB", "matlab")));
+    /// assert_eq!(detector.from_path("foo.kicad_sch"), Some(Language("KiCad", "kicad")));
+    /// ```
+    pub fn from_toml_str(config: &str) -> Result<LanguageDetector, ConfigError> {
+        toml::from_str::<Config>(config)
+            .map_err(ConfigError::Toml)?
+            .into_detector()
+    }
+
+    /// Loads a detector from a YAML config.
+    ///
+    /// *[See also `from_config_path`][from_config_path].*
+    ///
+    /// *This function requires the `config` feature.*
+    ///
+    /// [from_config_path]: #method.from_config_path
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{ConfigError, LanguageDetector, Language};
+    /// let detector = LanguageDetector::from_yaml_str("
+    /// disable: [glsl]
+    /// filenames:
+    ///   BUILD:
+    ///     name: Starlark
+    ///     id: starlark
+    ///   Jakefile: javascript
+    /// ").unwrap();
+    ///
+    /// assert_eq!(detector.from_path("foo.glsl"), None);
+    /// assert_eq!(detector.from_path("BUILD"), Some(Language("Starlark", "starlark")));
+    /// assert_eq!(detector.from_path("Jakefile"), Some(Language("JavaScript", "javascript")));
+    ///
+    /// assert!(matches!(
+    ///     LanguageDetector::from_yaml_str("prefer: [unknown]"),
+    ///     Err(ConfigError::UnknownId { .. }),
+    /// ));
+    /// ```
+    pub fn from_yaml_str(config: &str) -> Result<LanguageDetector, ConfigError> {
+        serde_yaml::from_str::<Config>(config)
+            .map_err(ConfigError::Yaml)?
+            .into_detector()
+    }
+}
+
+/// Errors returned when loading a [`LanguageDetector`] from a config.
+///
+/// *This type requires the `config` feature.*
+///
+/// [`LanguageDetector`]: struct.LanguageDetector.html
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum ConfigError {
+    /// The config file could not be read.
+    Io(io::Error),
+    /// The config was not valid TOML, or did not match the expected format.
+    Toml(toml::de::Error),
+    /// The config was not valid YAML, or did not match the expected format.
+    Yaml(serde_yaml::Error),
+    /// The extension of the config file is neither `.toml`, `.yaml`, nor `.yml`.
+    UnsupportedFormat {
+        /// The path of the config file.
+        path: PathBuf,
+    },
+    /// The ID is not the ID of any built-in language.
+    UnknownId {
+        /// The unknown ID.
+        id: String,
+    },
+    /// A custom language is invalid.
+    InvalidLanguage {
+        /// The extension or filename the language was given for.
+        key: String,
+        /// Why the language is invalid.
+        error: LanguageValidationError,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "failed to read config: {}", err),
+            Self::Toml(err) => write!(f, "invalid toml: {}", err),
+            Self::Yaml(err) => write!(f, "invalid yaml: {}", err),
+            Self::UnsupportedFormat { path } => write!(
+                f,
+                "unsupported config format {:?}, expected .toml, .yaml, or .yml",
+                path
+            ),
+            Self::UnknownId { id } => write!(f, "unknown language id {:?}", id),
+            Self::InvalidLanguage { key, error } => write!(f, "{} for {:?}", error, key),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Toml(err) => Some(err),
+            Self::Yaml(err) => Some(err),
+            Self::InvalidLanguage { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn check_config_path() {
+    use crate::test_util::TempDir;
+
+    let dir = TempDir::new("config");
+    let path = dir.join("detect-lang.toml");
+    fs::write(&path, "[extensions]\nfoo = \"rust\"\n").unwrap();
+    let detector = LanguageDetector::from_config_path(&path).unwrap();
+    assert_eq!(detector.from_path("x.foo"), Some(Language("Rust", "rust")));
+
+    let path = dir.join("detect-lang.YML");
+    fs::write(&path, "extensions:\n  foo: python\n").unwrap();
+    let detector = LanguageDetector::from_config_path(&path).unwrap();
+    assert_eq!(
+        detector.from_path("x.foo"),
+        Some(Language("Python", "python"))
+    );
+
+    let path = dir.join("detect-lang.json");
+    assert!(matches!(
+        LanguageDetector::from_config_path(&path),
+        Err(ConfigError::UnsupportedFormat { .. })
+    ));
+    assert!(matches!(
+        LanguageDetector::from_config_path(dir.join("missing.toml")),
+        Err(ConfigError::Io(_))
+    ));
+}
+
+#[test]
+fn check_config_errors() {
+    assert!(matches!(
+        LanguageDetector::from_toml_str("[extensions]\nfoo = \"unknown\"\n"),
+        Err(ConfigError::UnknownId { id }) if id == "unknown"
+    ));
+    assert!(matches!(
+        LanguageDetector::from_toml_str("[extensions]\nfoo = { name = \"Foo\", id = \"Foo Bar\" }\n"),
+        Err(ConfigError::InvalidLanguage { key, .. }) if key == "foo"
+    ));
+    assert!(matches!(
+        LanguageDetector::from_toml_str("unknown = 1\n"),
+        Err(ConfigError::Toml(_))
+    ));
+    assert!(matches!(
+        LanguageDetector::from_yaml_str("prefer: 1\n"),
+        Err(ConfigError::Yaml(_))
+    ));
+}
@@ -0,0 +1,214 @@
+use core::str;
+
+use crate::{magic, modeline, shebang, Language};
+
+/// The maximum number of bytes inspected by [`from_content`].
+///
+/// [`from_content`]: fn.from_content.html
+pub const MAX_CONTENT_LEN: usize = 8 * 1024;
+
+// A rule matches if every `(prefix, suffix)` pattern matches at least
+// one line, ignoring surrounding whitespace. Rules are checked in order.
+//
+// Languages not included in the `common` feature are only
+// included when the `full` feature is enabled.
+pub(crate) static RULES: &[(Language, &[(&str, &str)])] = &[
+    #[cfg(feature = "full")]
+    (Language("XML", "xml"), &[("<?xml", "")]),
+    (Language("HTML", "html"), &[("<!DOCTYPE html", "")]),
+    (Language("HTML", "html"), &[("<!doctype html", "")]),
+    (Language("HTML", "html"), &[("<html", "")]),
+    (
+        Language("C++", "cpp"),
+        &[("#include", ""), ("namespace ", "")],
+    ),
+    (
+        Language("C++", "cpp"),
+        &[("#include", ""), ("using namespace ", ";")],
+    ),
+    (
+        Language("C++", "cpp"),
+        &[("#include", ""), ("template", ">")],
+    ),
+    (Language("C++", "cpp"), &[("#include <iostream>", "")]),
+    (Language("C", "c"), &[("#include", "")]),
+    #[cfg(feature = "full")]
+    (Language("Go", "go"), &[("package ", ""), ("func ", "{")]),
+    (Language("Rust", "rust"), &[("fn main()", "")]),
+    (Language("Rust", "rust"), &[("use std::", ";")]),
+    (Language("Rust", "rust"), &[("pub fn ", "")]),
+    (Language("Python", "python"), &[("def ", ":")]),
+    (
+        Language("Python", "python"),
+        &[("from ", ""), ("import ", "")],
+    ),
+    #[cfg(feature = "full")]
+    (Language("Ruby", "ruby"), &[("def ", ""), ("end", "")]),
+    (
+        Language("JavaScript", "javascript"),
+        &[("module.exports", "")],
+    ),
+    (
+        Language("JavaScript", "javascript"),
+        &[("\"use strict\"", "")],
+    ),
+];
+
+/// Identifies a language from the content of a file, e.g. when the
+/// path is unknown or has no extension.
+/// Returns `None` if the language was not identified.
+///
+/// At most the first [`MAX_CONTENT_LEN`] bytes are inspected, in order:
+///
+/// 1. The [magic number] of a binary format, e.g. `\x7fELF` for ELF,
+///    if the content [is binary]
+/// 2. The interpreter of a `#!` line, e.g. `#!/usr/bin/env python3`
+/// 3. A Vim or Emacs [modeline], e.g. `# vim: ft=python`
+/// 4. Lightweight keyword heuristics, e.g. `<?xml` for XML and
+///    `#include` for C
+///
+/// The heuristics are a best effort, and are only intended as a last
+/// resort when nothing else is known about the file.
+///
+/// [`MAX_CONTENT_LEN`]: constant.MAX_CONTENT_LEN.html
+/// [is binary]: fn.is_binary.html
+/// [magic number]: fn.from_magic_bytes.html
+/// [modeline]: fn.from_modeline.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_content, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(from_content(b"#!/usr/bin/env python3\n"), Some(Language("Python", "python")));
+/// assert_eq!(from_content(b"<?xml version=\"1.0\"?>\n<foo/>\n"), Some(Language("XML", "xml")));
+/// assert_eq!(from_content(b"#include <stdio.h>\n"), Some(Language("C", "c")));
+/// assert_eq!(from_content(b"fn main() {}\n"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_content(b"{ \"foo\": 1 }\n"), Some(Language("JSON", "json")));
+///
+/// assert_eq!(from_content(b"Hello World\n"), None);
+/// # }
+/// ```
+pub fn from_content(content: &[u8]) -> Option<Language<'static>> {
+    if crate::is_binary(content) {
+        if let Some(lang) = magic::from_magic_bytes(content) {
+            return Some(lang);
+        }
+    }
+
+    let truncated = content.len() > MAX_CONTENT_LEN;
+    let content = text(content)?;
+
+    if let Some(lang) = shebang::from_shebang(content) {
+        return Some(lang);
+    }
+
+    if let Some(lang) = modeline::from_modeline(content) {
+        return Some(lang);
+    }
+
+    if !truncated && is_json(content) {
+        return Some(Language("JSON", "json"));
+    }
+
+    RULES
+        .iter()
+        .find(|(_, patterns)| {
+            patterns.iter().all(|&(prefix, suffix)| {
+                content.lines().map(str::trim).any(|line| {
+                    line.starts_with(prefix)
+                        && line.len() >= prefix.len() + suffix.len()
+                        && line.ends_with(suffix)
+                })
+            })
+        })
+        .map(|&(lang, _)| lang)
+}
+
+/// Returns the first [`MAX_CONTENT_LEN`] bytes of `content` as text,
+/// ignoring an incomplete UTF-8 sequence at the end due to truncation.
+/// Returns `None` if the content is not valid UTF-8.
+///
+/// [`MAX_CONTENT_LEN`]: constant.MAX_CONTENT_LEN.html
+pub(crate) fn text(content: &[u8]) -> Option<&str> {
+    let content = &content[..content.len().min(MAX_CONTENT_LEN)];
+    match str::from_utf8(content) {
+        Ok(content) => Some(content),
+        Err(err) if err.error_len().is_none() => str::from_utf8(&content[..err.valid_up_to()]).ok(),
+        Err(_) => None,
+    }
+}
+
+// An object with string keys, e.g. `{ "foo": 1 }`, or an array
+// of objects, e.g. `[{ "foo": 1 }]`
+fn is_json(content: &str) -> bool {
+    let content = content.trim();
+    let inner = match (content.chars().next(), content.chars().last()) {
+        (Some('{'), Some('}')) => &content[1..],
+        (Some('['), Some(']')) => match content[1..].trim_start().strip_prefix('{') {
+            Some(inner) => inner,
+            None => return false,
+        },
+        _ => return false,
+    };
+    inner.trim_start().starts_with(['"', '}'])
+}
+
+#[test]
+fn check_rules() {
+    for &(lang, patterns) in RULES.iter() {
+        assert!(!patterns.is_empty(), "Rule without patterns for {:?}", lang);
+        assert_eq!(
+            crate::languages::from_id(lang.id()),
+            Some(lang),
+            "Unknown language {:?}",
+            lang
+        );
+    }
+}
+
+#[test]
+fn check_from_content() {
+    let cases: &[(&str, Option<&str>)] = &[
+        ("#!/bin/sh\nfn main() {}\n", Some("shell")),
+        ("<!DOCTYPE html>\n<html></html>\n", Some("html")),
+        ("<html lang=\"en\">\n</html>\n", Some("html")),
+        ("#include <vector>\nnamespace foo {}\n", Some("cpp")),
+        ("#include <iostream>\n", Some("cpp")),
+        ("#include <stdio.h>\nint main() {}\n", Some("c")),
+        ("package main\n\nfunc main() {\n}\n", Some("go")),
+        ("use std::io;\n", Some("rust")),
+        ("def foo():\n    pass\n", Some("python")),
+        ("from foo import bar\nimport baz\n", Some("python")),
+        ("def foo\n  1\nend\n", Some("ruby")),
+        (
+            "\"use strict\";\nconst fs = require(\"fs\");\n",
+            Some("javascript"),
+        ),
+        ("[\n  { \"foo\": 1 }\n]\n", Some("json")),
+        ("{}", Some("json")),
+        ("[1, 2, 3]", None),
+        ("{ foo }", None),
+        ("", None),
+    ];
+    for &(content, id) in cases {
+        #[cfg(not(feature = "full"))]
+        let id = id.filter(|&id| crate::languages::from_id(id).is_some());
+        assert_eq!(
+            from_content(content.as_bytes()).map(|lang| lang.id()),
+            id,
+            "{:?}",
+            content
+        );
+    }
+
+    // Invalid UTF-8
+    assert_eq!(from_content(b"#include <stdio.h>\n\xFF\n"), None);
+
+    // Truncated
+    let mut content = b"#include <stdio.h>\n".to_vec();
+    content.resize(MAX_CONTENT_LEN, b' ');
+    content.extend_from_slice("é\nnamespace foo {}\n".as_bytes());
+    assert_eq!(from_content(&content), Some(Language("C", "c")));
+}
@@ -0,0 +1,238 @@
+use crate::aliases::{ID_ALIASES, NAME_ALIASES};
+use crate::binary::{BINARY_EXTENSIONS, BINARY_LANGUAGES};
+use crate::build_system::BUILD_FILES;
+use crate::candidates::CANDIDATES;
+use crate::category::CATEGORIES;
+use crate::color::COLORS;
+use crate::compound::COMPOUND_EXTENSIONS;
+use crate::content::RULES;
+use crate::embedded::EMBEDDED_LANGUAGES;
+use crate::file_mime::FILE_MIME_TYPES;
+use crate::filenames::FILENAMES;
+use crate::heuristics::HEURISTICS;
+use crate::highlight::{HLJS_LANGUAGES, PRISM_LANGUAGES};
+use crate::icon::ICONS;
+use crate::languages::LANGUAGES;
+use crate::linguist::LINGUIST_IDS;
+use crate::lsp::LSP_IDS;
+use crate::magic::SIGNATURES;
+use crate::mime::MIME_TYPES;
+use crate::modeline::MODES;
+use crate::preferred::PREFERRED_EXTENSIONS;
+use crate::project::MANIFESTS;
+use crate::pygments::PYGMENTS_LEXERS;
+use crate::shebang::INTERPRETERS;
+use crate::textmate::TEXTMATE_SCOPES;
+use crate::vendor::{DOCUMENTATION, GENERATED, TESTS, VENDORED};
+use crate::xml::XML_DIALECTS;
+use crate::Language;
+
+/// The revision of the built-in language database.
+///
+/// The revision is bumped whenever any of the built-in tables change,
+/// e.g. when languages or extensions are added, removed, or renamed.
+/// Cached detection results should be invalidated when it changes.
+///
+/// *[See also `database_fingerprint`][database_fingerprint].*
+///
+/// [database_fingerprint]: fn.database_fingerprint.html
+pub const DATABASE_VERSION: u32 = 48;
+
+/// Returns a hash computed over all entries of the built-in tables,
+/// including the patterns used for identifying languages by content.
+///
+/// Unlike [`DATABASE_VERSION`], the fingerprint also depends on which
+/// languages are included by the enabled features, e.g. `common`.
+/// The fingerprint is stable across runs and platforms.
+///
+/// [`DATABASE_VERSION`]: constant.DATABASE_VERSION.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::database_fingerprint;
+/// assert_eq!(database_fingerprint(), database_fingerprint());
+/// ```
+pub fn database_fingerprint() -> u64 {
+    let mut hasher = Fnv1a::new();
+
+    hasher.write_table("languages", LANGUAGES.iter().map(entry));
+    hasher.write_table("filenames", FILENAMES.iter().map(entry));
+    hasher.write_table("interpreters", INTERPRETERS.iter().map(entry));
+    hasher.write_table("compound", COMPOUND_EXTENSIONS.iter().map(entry));
+    hasher.write_table(
+        "candidates",
+        CANDIDATES
+            .iter()
+            .flat_map(|&(ext, langs)| langs.iter().map(move |&lang| entry(&(ext, lang)))),
+    );
+    hasher.write_table(
+        "heuristics",
+        HEURISTICS.iter().flat_map(|&(ext, rules)| {
+            rules
+                .iter()
+                .flat_map(move |&(Language(name, id), patterns)| {
+                    patterns.iter().map(move |pattern| {
+                        let [kind, pattern] = pattern.parts();
+                        [ext, name, id, kind, pattern]
+                    })
+                })
+        }),
+    );
+    hasher.write_table(
+        "preferred",
+        PREFERRED_EXTENSIONS.iter().map(|&(id, ext)| [id, ext]),
+    );
+    hasher.write_table(
+        "categories",
+        CATEGORIES
+            .iter()
+            .map(|&(id, category)| [id, category.as_str()]),
+    );
+    hasher.write_table("colors", COLORS.iter().map(|&(id, color)| [id, color]));
+    hasher.write_table("binary", BINARY_LANGUAGES.iter().map(|&id| [id]));
+    hasher.write_table(
+        "binary-extensions",
+        BINARY_EXTENSIONS.iter().map(|&ext| [ext]),
+    );
+    hasher.write_table("aliases", ID_ALIASES.iter().map(|&(alias, id)| [alias, id]));
+    hasher.write_table(
+        "name-aliases",
+        NAME_ALIASES.iter().map(|&(alias, id)| [alias, id]),
+    );
+    hasher.write_table(
+        "embedded",
+        EMBEDDED_LANGUAGES
+            .iter()
+            .flat_map(|&(id, embedded)| embedded.iter().map(move |&embedded| [id, embedded])),
+    );
+    hasher.write_table(
+        "file-mime",
+        FILE_MIME_TYPES.iter().map(|&(mime, id)| [mime, id]),
+    );
+    hasher.write_table("hljs", HLJS_LANGUAGES.iter().map(|&(id, name)| [id, name]));
+    hasher.write_table(
+        "prism",
+        PRISM_LANGUAGES.iter().map(|&(id, name)| [id, name]),
+    );
+    hasher.write_table("icons", ICONS.iter().map(|&(id, icon)| [id, icon]));
+    hasher.write_table("lsp", LSP_IDS.iter().map(|&(id, lsp)| [id, lsp]));
+    hasher.write_str("linguist");
+    for &(id, linguist_id) in LINGUIST_IDS.iter() {
+        hasher.write_str(id);
+        hasher.write(&linguist_id.to_le_bytes());
+    }
+    hasher.write_table(
+        "pygments",
+        PYGMENTS_LEXERS.iter().map(|&(id, lexer)| [id, lexer]),
+    );
+    hasher.write_table("mime", MIME_TYPES.iter().map(|&(id, mime)| [id, mime]));
+    hasher.write_table("modes", MODES.iter().map(|&(mode, id)| [mode, id]));
+    hasher.write_table(
+        "textmate",
+        TEXTMATE_SCOPES.iter().map(|&(id, scope)| [id, scope]),
+    );
+    hasher.write_table("vendored", VENDORED.iter().map(|&pattern| [pattern]));
+    hasher.write_table("generated", GENERATED.iter().map(|&pattern| [pattern]));
+    hasher.write_table(
+        "documentation",
+        DOCUMENTATION.iter().map(|&pattern| [pattern]),
+    );
+    hasher.write_table("tests", TESTS.iter().map(|&pattern| [pattern]));
+    hasher.write_table("manifests", MANIFESTS.iter().map(entry));
+    hasher.write_table(
+        "build-files",
+        BUILD_FILES
+            .iter()
+            .map(|&(filename, build_system)| [filename, build_system.name()]),
+    );
+    hasher.write_table(
+        "xml-dialects",
+        XML_DIALECTS
+            .iter()
+            .map(|&(root, namespace, Language(name, id))| [root, namespace, name, id]),
+    );
+    #[cfg(feature = "full")]
+    {
+        hasher.write_str("android");
+        hasher.write_str(crate::xml::ANDROID_NAMESPACE);
+        hasher.write_table(
+            "android-resource-roots",
+            crate::xml::ANDROID_RESOURCE_ROOTS
+                .iter()
+                .map(|&root| [root]),
+        );
+    }
+    hasher.write_str("magic");
+    for &(signature, Language(name, id)) in SIGNATURES {
+        hasher.write_bytes(signature);
+        hasher.write_str(name);
+        hasher.write_str(id);
+    }
+    hasher.write_str("content");
+    for &(Language(name, id), patterns) in RULES {
+        hasher.write_str(name);
+        hasher.write_str(id);
+        hasher.write(&(patterns.len() as u64).to_le_bytes());
+        for &(prefix, suffix) in patterns {
+            hasher.write_str(prefix);
+            hasher.write_str(suffix);
+        }
+    }
+
+    hasher.0
+}
+
+fn entry<'a>(&(key, Language(name, id)): &(&'a str, Language<'a>)) -> [&'a str; 3] {
+    [key, name, id]
+}
+
+/// 64-bit [FNV-1a](https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function).
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 ^= u64::from(b);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    // Length prefixed, such that `("ab", "c")` and `("a", "bc")` differ
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write(&(bytes.len() as u64).to_le_bytes());
+        self.write(bytes);
+    }
+
+    fn write_str(&mut self, s: &str) {
+        self.write_bytes(s.as_bytes());
+    }
+
+    fn write_table<'a, I, const N: usize>(&mut self, name: &str, entries: I)
+    where
+        I: Iterator<Item = [&'a str; N]>,
+    {
+        self.write_str(name);
+        for entry in entries {
+            for s in entry.iter() {
+                self.write_str(s);
+            }
+        }
+    }
+}
+
+// If this fails, then the tables changed. Bump `DATABASE_VERSION`
+// and update the fingerprint.
+#[cfg(feature = "full")]
+#[test]
+fn check_database_version() {
+    assert_eq!(
+        (DATABASE_VERSION, database_fingerprint()),
+        (48, 0x5afe88594332c582),
+        "Tables changed, bump DATABASE_VERSION"
+    );
+}
@@ -0,0 +1,276 @@
+use std::ffi::OsStr;
+use std::fmt;
+use std::path::Path;
+use std::str;
+
+use crate::{filenames, shebang, Language};
+
+/// The information available to a [`Detector`] implementation.
+///
+/// [`Detector`]: trait.Detector.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::DetectContext;
+/// let ctx = DetectContext::new("foo").with_content(b"#!/bin/sh\necho foo\n");
+/// assert_eq!(ctx.first_line(), Some("#!/bin/sh"));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct DetectContext<'a> {
+    path: &'a Path,
+    first_line: Option<&'a str>,
+    content: Option<&'a [u8]>,
+}
+
+impl<'a> DetectContext<'a> {
+    /// Creates a context containing only a path.
+    ///
+    /// Note that the path is not required to exist.
+    #[inline]
+    pub fn new<P: AsRef<Path> + ?Sized>(path: &'a P) -> Self {
+        Self {
+            path: path.as_ref(),
+            first_line: None,
+            content: None,
+        }
+    }
+
+    /// Sets the first line of the file.
+    #[inline]
+    pub fn with_first_line(mut self, first_line: &'a str) -> Self {
+        self.first_line = Some(first_line);
+        self
+    }
+
+    /// Sets the content of the file, which may also be a prefix of the content.
+    #[inline]
+    pub fn with_content(mut self, content: &'a [u8]) -> Self {
+        self.content = Some(content);
+        self
+    }
+
+    /// Returns the path.
+    #[inline]
+    pub fn path(&self) -> &'a Path {
+        self.path
+    }
+
+    /// Returns the first line, either as given by [`with_first_line`]
+    /// or otherwise taken from the [content]. The line terminator
+    /// is not included.
+    ///
+    /// Returns `None` if neither is available, or if the first line
+    /// in the content is not valid UTF-8.
+    ///
+    /// [`with_first_line`]: #method.with_first_line
+    /// [content]: #method.with_content
+    pub fn first_line(&self) -> Option<&'a str> {
+        self.first_line.or_else(|| first_line(self.content?))
+    }
+
+    /// Returns the content.
+    #[inline]
+    pub fn content(&self) -> Option<&'a [u8]> {
+        self.content
+    }
+}
+
+/// A strategy for identifying a language.
+///
+/// Detectors can be combined using [`ChainDetector`], and closures
+/// taking a [`DetectContext`] implement `Detector` as well.
+///
+/// [`ChainDetector`]: struct.ChainDetector.html
+/// [`DetectContext`]: struct.DetectContext.html
+///
+/// # Example
+///
+/// ```
+/// use detect_lang::{ChainDetector, DetectContext, Detector, ExtensionDetector, Language};
+///
+/// let detector = ChainDetector::new()
+///     .with(|ctx: &DetectContext| match ctx.path().to_str() {
+///         Some("BUILD") => Some(Language("Starlark", "starlark")),
+///         _ => None,
+///     })
+///     .with(ExtensionDetector);
+///
+/// assert_eq!(detector.detect(&DetectContext::new("BUILD")), Some(Language("Starlark", "starlark")));
+/// assert_eq!(detector.detect(&DetectContext::new("foo.rs")), Some(Language("Rust", "rust")));
+/// ```
+pub trait Detector {
+    /// Identifies a language from the given context.
+    /// Returns `None` if the language was not identified.
+    fn detect(&self, ctx: &DetectContext<'_>) -> Option<Language<'static>>;
+}
+
+impl<F> Detector for F
+where
+    F: Fn(&DetectContext<'_>) -> Option<Language<'static>>,
+{
+    #[inline]
+    fn detect(&self, ctx: &DetectContext<'_>) -> Option<Language<'static>> {
+        self(ctx)
+    }
+}
+
+/// Identifies a language from the extension of the path.
+///
+/// *[See also `from_extension`][from_extension].*
+///
+/// [from_extension]: fn.from_extension.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{DetectContext, Detector, ExtensionDetector, Language};
+/// let ctx = DetectContext::new("foo.rs");
+/// assert_eq!(ExtensionDetector.detect(&ctx), Some(Language("Rust", "rust")));
+/// ```
+#[derive(Clone, Copy, Default, Debug)]
+pub struct ExtensionDetector;
+
+impl Detector for ExtensionDetector {
+    #[inline]
+    fn detect(&self, ctx: &DetectContext<'_>) -> Option<Language<'static>> {
+        let ext = ctx.path().extension().and_then(OsStr::to_str)?;
+        crate::from_extension(ext)
+    }
+}
+
+/// Identifies a language from well-known filenames, e.g. `Makefile`.
+/// The casing of the filename does not affect the result.
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{DetectContext, Detector, FilenameDetector, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// let ctx = DetectContext::new("foo/Makefile");
+/// assert_eq!(FilenameDetector.detect(&ctx), Some(Language("Make", "make")));
+/// # }
+/// ```
+#[derive(Clone, Copy, Default, Debug)]
+pub struct FilenameDetector;
+
+impl Detector for FilenameDetector {
+    #[inline]
+    fn detect(&self, ctx: &DetectContext<'_>) -> Option<Language<'static>> {
+        let filename = ctx.path().file_name().and_then(OsStr::to_str)?;
+        filenames::from_filename(filename)
+    }
+}
+
+/// Identifies a language from the interpreter of a `#!` [first line],
+/// e.g. `#!/usr/bin/env python3`.
+///
+/// [first line]: struct.DetectContext.html#method.first_line
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{DetectContext, Detector, ShebangDetector, Language};
+/// let ctx = DetectContext::new("foo").with_first_line("#!/usr/bin/env python3");
+/// assert_eq!(ShebangDetector.detect(&ctx), Some(Language("Python", "python")));
+/// ```
+#[derive(Clone, Copy, Default, Debug)]
+pub struct ShebangDetector;
+
+impl Detector for ShebangDetector {
+    #[inline]
+    fn detect(&self, ctx: &DetectContext<'_>) -> Option<Language<'static>> {
+        shebang::from_shebang(ctx.first_line()?)
+    }
+}
+
+/// Identifies a language from the [content] of the file,
+/// using [`from_content`].
+///
+/// [content]: struct.DetectContext.html#method.content
+/// [`from_content`]: fn.from_content.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{ContentDetector, DetectContext, Detector, Language};
+/// let ctx = DetectContext::new("foo").with_content(b"#!/bin/bash\necho foo\n");
+/// assert_eq!(ContentDetector.detect(&ctx), Some(Language("Shell", "shell")));
+/// ```
+#[derive(Clone, Copy, Default, Debug)]
+pub struct ContentDetector;
+
+impl Detector for ContentDetector {
+    fn detect(&self, ctx: &DetectContext<'_>) -> Option<Language<'static>> {
+        crate::from_content(ctx.content()?)
+    }
+}
+
+/// Runs a list of detectors in order, returning the first identified language.
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{ChainDetector, DetectContext, Detector, FilenameDetector, ExtensionDetector, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// let detector = ChainDetector::new()
+///     .with(FilenameDetector)
+///     .with(ExtensionDetector);
+///
+/// assert_eq!(detector.detect(&DetectContext::new("CMakeLists.txt")), Some(Language("CMake", "cmake")));
+/// assert_eq!(detector.detect(&DetectContext::new("foo.rs")), Some(Language("Rust", "rust")));
+/// assert_eq!(detector.detect(&DetectContext::new("foo")), None);
+/// # }
+/// ```
+#[derive(Default)]
+pub struct ChainDetector {
+    detectors: Vec<Box<dyn Detector>>,
+}
+
+impl ChainDetector {
+    /// Creates an empty chain, which never identifies a language.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends `detector` to the end of the chain.
+    #[inline]
+    pub fn with<D: Detector + 'static>(mut self, detector: D) -> Self {
+        self.push(detector);
+        self
+    }
+
+    /// Appends `detector` to the end of the chain.
+    #[inline]
+    pub fn push<D: Detector + 'static>(&mut self, detector: D) {
+        self.detectors.push(Box::new(detector));
+    }
+}
+
+impl Detector for ChainDetector {
+    fn detect(&self, ctx: &DetectContext<'_>) -> Option<Language<'static>> {
+        self.detectors
+            .iter()
+            .find_map(|detector| detector.detect(ctx))
+    }
+}
+
+fn first_line(content: &[u8]) -> Option<&str> {
+    let end = content
+        .iter()
+        .position(|&b| b == b'\n')
+        .unwrap_or(content.len());
+    let line = str::from_utf8(&content[..end]).ok()?;
+    Some(line.strip_suffix('\r').unwrap_or(line))
+}
+
+impl fmt::Debug for ChainDetector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChainDetector")
+            .field("detectors", &self.detectors.len())
+            .finish()
+    }
+}
@@ -0,0 +1,41 @@
+// The array is sorted by the language ID
+pub(crate) static EMBEDDED_LANGUAGES: [(&str, &[&str]); 3] = [
+    ("astro", &["html", "typescript", "css"]),
+    ("svelte", &["html", "javascript", "css"]),
+    ("vue", &["html", "javascript", "css"]),
+];
+
+pub(crate) fn embedded_languages(id: &str) -> &'static [&'static str] {
+    EMBEDDED_LANGUAGES
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .map_or(&[], |i| EMBEDDED_LANGUAGES[i].1)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in EMBEDDED_LANGUAGES
+        .iter()
+        .zip(EMBEDDED_LANGUAGES.iter().skip(1))
+    {
+        assert!(
+            a.0 < b.0,
+            "Embedded languages out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_embedded_languages() {
+    for &(id, embedded) in EMBEDDED_LANGUAGES.iter() {
+        for &embedded in embedded {
+            assert!(
+                crate::languages::from_id(embedded).is_some(),
+                "Unknown language {:?} embedded in {:?}",
+                embedded,
+                id
+            );
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use std::path::Path;
+
+use crate::builder::DEFAULT_DETECTOR;
+use crate::Language;
+
+/// Describes how a language was identified.
+///
+/// *[See also `detect_explained`][detect_explained].*
+///
+/// [detect_explained]: fn.detect_explained.html
+#[non_exhaustive]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum DetectionSource {
+    /// A built-in extension mapping, e.g. `rs` for Rust.
+    BuiltinExtension,
+    /// A custom mapping, e.g. added to a [`Registry`].
+    ///
+    /// [`Registry`]: struct.Registry.html
+    UserOverride,
+    /// A built-in multi-part extension mapping, e.g. `tar.gz`.
+    CompoundExtension,
+    /// A well-known filename, e.g. `Makefile`.
+    Filename,
+    /// The interpreter of a `#!` line, e.g. `#!/bin/sh`.
+    Shebang,
+    /// A Vim or Emacs modeline, e.g. `# vim: ft=python`.
+    Modeline,
+    /// The content of the file, used to disambiguate an extension
+    /// mapped to multiple languages, e.g. `h` for C, C++, and Objective-C.
+    Heuristic,
+    /// The content of the file.
+    Content,
+    /// The content of the file, as guessed by the [classifier].
+    ///
+    /// This requires the `classifier` feature.
+    ///
+    /// [classifier]: fn.classify.html
+    Classifier,
+}
+
+impl DetectionSource {
+    /// Returns a rough confidence between `0.0` and `1.0` of languages
+    /// identified by the source. For example, a well-known filename is
+    /// more reliable than keyword heuristics on the content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::DetectionSource;
+    /// assert!(DetectionSource::Filename.confidence() > DetectionSource::Content.confidence());
+    /// assert_eq!(DetectionSource::UserOverride.confidence(), 1.0);
+    /// ```
+    pub fn confidence(self) -> f32 {
+        match self {
+            Self::UserOverride => 1.0,
+            Self::Filename => 0.95,
+            Self::BuiltinExtension | Self::CompoundExtension | Self::Modeline => 0.9,
+            Self::Shebang => 0.85,
+            Self::Heuristic => 0.75,
+            Self::Content => 0.5,
+            Self::Classifier => 0.3,
+        }
+    }
+}
+
+/// The confidence of a built-in extension mapped to multiple languages,
+/// which was not disambiguated by the content.
+const AMBIGUOUS_CONFIDENCE: f32 = 0.5;
+
+/// A language along with how it was identified, and how confident
+/// the identification is.
+///
+/// *[See also `detect`][detect].*
+///
+/// [detect]: fn.detect.html
+#[derive(PartialEq, Clone, Copy, Debug)]
+pub struct Detection<'a> {
+    language: Language<'a>,
+    source: DetectionSource,
+    confidence: f32,
+}
+
+impl<'a> Detection<'a> {
+    pub(crate) fn new(language: Language<'a>, source: DetectionSource, ambiguous: bool) -> Self {
+        let confidence = match source {
+            DetectionSource::BuiltinExtension if ambiguous => AMBIGUOUS_CONFIDENCE,
+            source => source.confidence(),
+        };
+        Self {
+            language,
+            source,
+            confidence,
+        }
+    }
+
+    /// Returns the identified language.
+    #[inline]
+    pub fn language(&self) -> Language<'a> {
+        self.language
+    }
+
+    /// Returns how the language was identified.
+    #[inline]
+    pub fn source(&self) -> DetectionSource {
+        self.source
+    }
+
+    /// Returns a rough confidence between `0.0` and `1.0`.
+    ///
+    /// This is the [confidence of the source], except for extensions
+    /// mapped to multiple languages, which were not disambiguated by
+    /// the content, e.g. `foo.h` without any content.
+    ///
+    /// [confidence of the source]: enum.DetectionSource.html#method.confidence
+    #[inline]
+    pub fn confidence(&self) -> f32 {
+        self.confidence
+    }
+}
+
+/// Identifies a language from a path and optionally the content of the file,
+/// along with how the language was identified and a rough confidence.
+/// Returns `None` if the language was not identified.
+///
+/// The language is identified the same as by [`detect_explained`].
+///
+/// [`detect_explained`]: fn.detect_explained.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{detect, DetectionSource, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// let detection = detect("foo.rs", None).unwrap();
+/// assert_eq!(detection.language(), Language("Rust", "rust"));
+/// assert_eq!(detection.source(), DetectionSource::BuiltinExtension);
+/// assert_eq!(detection.confidence(), 0.9);
+///
+/// // Ambiguous extensions are less certain
+/// let detection = detect("foo.h", None).unwrap();
+/// assert_eq!(detection.language(), Language("C", "c"));
+/// assert!(detection.confidence() < 0.9);
+///
+/// let detection = detect("foo.h", Some(b"#import <Foundation/Foundation.h>\n")).unwrap();
+/// assert_eq!(detection.language(), Language("Objective-C", "objective-c"));
+/// assert_eq!(detection.source(), DetectionSource::Heuristic);
+///
+/// assert_eq!(detect("foo", None), None);
+/// # }
+/// ```
+#[inline]
+pub fn detect<P: AsRef<Path>>(path: P, content: Option<&[u8]>) -> Option<Detection<'static>> {
+    DEFAULT_DETECTOR.detect(path, content)
+}
+
+/// Identifies a language from a path and optionally the content of the file,
+/// along with how the language was identified.
+/// Returns `None` if the language was not identified.
+///
+/// The path is checked first, in which case the content is only inspected
+/// to disambiguate extensions mapped to multiple languages, e.g. `h` for
+/// C, C++, and Objective-C.
+///
+/// With the `classifier` feature, the content is [classified] as a last
+/// resort, if the language was not otherwise identified.
+///
+/// [classified]: fn.classify.html
+///
+/// Note that `detect_explained` does not check if the path exists,
+/// nor does it attempt to load the file.
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{detect_explained, DetectionSource, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(
+///     detect_explained("foo.rs", None),
+///     Some((Language("Rust", "rust"), DetectionSource::BuiltinExtension)),
+/// );
+/// assert_eq!(
+///     detect_explained("Makefile", None),
+///     Some((Language("Make", "make"), DetectionSource::Filename)),
+/// );
+/// assert_eq!(
+///     detect_explained("foo", Some(b"#!/usr/bin/env python3\n")),
+///     Some((Language("Python", "python"), DetectionSource::Shebang)),
+/// );
+/// assert_eq!(detect_explained("foo", None), None);
+/// # }
+/// ```
+#[inline]
+pub fn detect_explained<P: AsRef<Path>>(
+    path: P,
+    content: Option<&[u8]>,
+) -> Option<(Language<'static>, DetectionSource)> {
+    DEFAULT_DETECTOR.detect_explained(path, content)
+}
@@ -0,0 +1,42 @@
+use crate::{languages, Language};
+
+/// Identifies a language from the info string of a Markdown fenced code block,
+/// e.g. `rust` in ```` ```rust ````.
+/// The casing of the info string does not affect the result.
+/// Returns `None` if the info string is empty or the language was not identified.
+///
+/// Only the first word of the info string is used, and anything following
+/// a `{` or `,` is ignored, e.g. `python {.line-numbers}` and `rust,ignore`.
+/// The word is either a language [ID] or an extension.
+///
+/// [ID]: struct.Language.html#method.id
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_code_fence, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(from_code_fence("rust"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_code_fence("rs"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_code_fence("rb"), Some(Language("Ruby", "ruby")));
+/// assert_eq!(from_code_fence("sh"), Some(Language("Shell", "shell")));
+/// assert_eq!(from_code_fence("JS"), Some(Language("JavaScript", "javascript")));
+/// assert_eq!(from_code_fence("python {.line-numbers}"), Some(Language("Python", "python")));
+/// assert_eq!(from_code_fence("python{.line-numbers}"), Some(Language("Python", "python")));
+/// assert_eq!(from_code_fence("rust,ignore"), Some(Language("Rust", "rust")));
+///
+/// assert_eq!(from_code_fence(""), None);
+/// assert_eq!(from_code_fence("unknown"), None);
+/// # }
+/// ```
+pub fn from_code_fence(info: &str) -> Option<Language<'static>> {
+    let word = info.split_whitespace().next()?;
+    let word = word.split(['{', ',']).next()?;
+    if word.is_empty() {
+        return None;
+    }
+
+    let word = word.to_lowercase();
+    languages::from_id(&word).or_else(|| crate::from_lowercase_extension(&word))
+}
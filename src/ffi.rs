@@ -0,0 +1,203 @@
+//! C bindings, for linking non-Rust tooling against the same
+//! detection data, e.g. from C, Swift, or Zig.
+//!
+//! Build a shared or static library with:
+//!
+//! ```text
+//! cargo rustc --release --features ffi --crate-type cdylib
+//! cargo rustc --release --features ffi --crate-type staticlib
+//! ```
+//!
+//! and declare the functions in C as:
+//!
+//! ```c
+//! typedef struct {
+//!     const char *name;
+//!     const char *id;
+//! } DetectLangLanguage;
+//!
+//! DetectLangLanguage detect_lang_from_path(const char *path);
+//! DetectLangLanguage detect_lang_from_extension(const char *extension);
+//! DetectLangLanguage detect_lang_from_path_with_content(
+//!     const char *path, const unsigned char *content, size_t len);
+//! ```
+//!
+//! The returned strings are NUL-terminated, live for the remainder of
+//! the program, and must not be freed. Both are `NULL` if the language
+//! was not identified.
+//!
+//! *This module requires the `ffi` feature.*
+
+#![allow(unsafe_code)]
+
+use std::collections::BTreeMap;
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_uchar};
+use std::path::Path;
+use std::ptr;
+use std::slice;
+use std::sync::Mutex;
+
+use crate::Language;
+
+/// A language returned by the C bindings. Both `name` and `id` are
+/// `NULL` if the language was not identified.
+///
+/// *[See also the module documentation][ffi].*
+///
+/// [ffi]: index.html
+#[repr(C)]
+#[derive(Clone, Copy, Debug)]
+pub struct DetectLangLanguage {
+    /// The name of the language, e.g. `Rust`.
+    pub name: *const c_char,
+    /// The ID of the language, e.g. `rust`.
+    pub id: *const c_char,
+}
+
+impl DetectLangLanguage {
+    const NONE: Self = Self {
+        name: ptr::null(),
+        id: ptr::null(),
+    };
+}
+
+type CStrings = BTreeMap<(&'static str, &'static str), (CString, CString)>;
+
+/// NUL-terminated copies of the built-in languages, created on demand.
+/// The `CString` buffers are never dropped, so pointers to them remain
+/// valid even as the map grows.
+static C_STRINGS: Mutex<CStrings> = Mutex::new(BTreeMap::new());
+
+fn to_c(lang: Option<Language<'static>>) -> DetectLangLanguage {
+    let Language(name, id) = match lang {
+        Some(lang) => lang,
+        None => return DetectLangLanguage::NONE,
+    };
+    let mut c_strings = C_STRINGS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let (c_name, c_id) = c_strings.entry((name, id)).or_insert_with(|| {
+        // Built-in names and IDs never contain NUL
+        let c_name = CString::new(name).unwrap_or_default();
+        let c_id = CString::new(id).unwrap_or_default();
+        (c_name, c_id)
+    });
+    DetectLangLanguage {
+        name: c_name.as_ptr(),
+        id: c_id.as_ptr(),
+    }
+}
+
+#[cfg(unix)]
+fn to_path(s: &CStr) -> Option<&Path> {
+    use std::ffi::OsStr;
+    use std::os::unix::ffi::OsStrExt;
+    Some(Path::new(OsStr::from_bytes(s.to_bytes())))
+}
+
+#[cfg(not(unix))]
+fn to_path(s: &CStr) -> Option<&Path> {
+    s.to_str().ok().map(Path::new)
+}
+
+/// Identifies a language from a path.
+///
+/// *[See also `detect_lang::from_path`][from_path].*
+///
+/// [from_path]: ../fn.from_path.html
+///
+/// # Safety
+///
+/// `path` must be `NULL` or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn detect_lang_from_path(path: *const c_char) -> DetectLangLanguage {
+    if path.is_null() {
+        return DetectLangLanguage::NONE;
+    }
+    let path = CStr::from_ptr(path);
+    to_c(to_path(path).and_then(crate::from_path))
+}
+
+/// Identifies a language from a file extension, e.g. `rs`.
+/// The casing of the extension does not affect the result.
+///
+/// *[See also `detect_lang::from_extension`][from_extension].*
+///
+/// [from_extension]: ../fn.from_extension.html
+///
+/// # Safety
+///
+/// `extension` must be `NULL` or a valid NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn detect_lang_from_extension(
+    extension: *const c_char,
+) -> DetectLangLanguage {
+    if extension.is_null() {
+        return DetectLangLanguage::NONE;
+    }
+    let extension = CStr::from_ptr(extension);
+    to_c(extension.to_str().ok().and_then(crate::from_extension))
+}
+
+/// Identifies a language from a path and the content of the file.
+///
+/// *[See also `detect_lang::from_path_with_content`][from_path_with_content].*
+///
+/// [from_path_with_content]: ../fn.from_path_with_content.html
+///
+/// # Safety
+///
+/// `path` must be `NULL` or a valid NUL-terminated string, and `content`
+/// must be `NULL` or point to at least `len` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn detect_lang_from_path_with_content(
+    path: *const c_char,
+    content: *const c_uchar,
+    len: usize,
+) -> DetectLangLanguage {
+    if path.is_null() {
+        return DetectLangLanguage::NONE;
+    }
+    let path = CStr::from_ptr(path);
+    let content = if content.is_null() {
+        &[]
+    } else {
+        slice::from_raw_parts(content, len)
+    };
+    to_c(to_path(path).and_then(|path| crate::from_path_with_content(path, content)))
+}
+
+#[test]
+fn check_ffi() {
+    unsafe fn to_str<'a>(s: *const c_char) -> &'a str {
+        CStr::from_ptr(s).to_str().unwrap()
+    }
+
+    fn c(s: &str) -> CString {
+        CString::new(s).unwrap()
+    }
+
+    unsafe {
+        let lang = detect_lang_from_path(c("foo/bar.rs").as_ptr());
+        assert_eq!(to_str(lang.name), "Rust");
+        assert_eq!(to_str(lang.id), "rust");
+
+        let again = detect_lang_from_extension(c("RS").as_ptr());
+        assert_eq!(again.name, lang.name);
+        assert_eq!(again.id, lang.id);
+
+        let content = b"#!/usr/bin/env python3\n";
+        let lang =
+            detect_lang_from_path_with_content(c("foo").as_ptr(), content.as_ptr(), content.len());
+        assert_eq!(to_str(lang.id), "python");
+
+        let lang = detect_lang_from_path_with_content(c("foo").as_ptr(), ptr::null(), 0);
+        assert!(lang.name.is_null() && lang.id.is_null());
+
+        assert!(detect_lang_from_path(ptr::null()).id.is_null());
+        assert!(detect_lang_from_extension(c("unknown").as_ptr())
+            .id
+            .is_null());
+    }
+}
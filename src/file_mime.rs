@@ -0,0 +1,137 @@
+use crate::{languages, Language};
+
+// The array is sorted by the MIME type
+pub(crate) static FILE_MIME_TYPES: [(&str, &str); 50] = [
+    ("application/gzip", "gzip"),
+    ("application/javascript", "javascript"),
+    ("application/json", "json"),
+    ("application/vnd.ms-excel", "excel"),
+    ("application/vnd.oasis.opendocument.spreadsheet", "ods"),
+    (
+        "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        "excel",
+    ),
+    ("application/vnd.rar", "rar"),
+    ("application/x-7z-compressed", "7z"),
+    ("application/x-bzip2", "bzip2"),
+    ("application/x-gzip", "gzip"),
+    ("application/x-rar", "rar"),
+    ("application/x-tar", "tar"),
+    ("application/x-xz", "xz"),
+    ("application/xml", "xml"),
+    ("application/zip", "zip"),
+    ("application/zstd", "zstd"),
+    ("image/svg+xml", "svg"),
+    ("text/css", "css"),
+    ("text/csv", "csv"),
+    ("text/html", "html"),
+    ("text/javascript", "javascript"),
+    ("text/markdown", "markdown"),
+    ("text/tab-separated-values", "tsv"),
+    ("text/x-bibtex", "bibtex"),
+    ("text/x-c", "c"),
+    ("text/x-c++", "cpp"),
+    ("text/x-clojure", "clojure"),
+    ("text/x-csharp", "csharp"),
+    ("text/x-dart", "dart"),
+    ("text/x-go", "go"),
+    ("text/x-java", "java"),
+    ("text/x-java-source", "java"),
+    ("text/x-kotlin", "kotlin"),
+    ("text/x-lisp", "common-lisp"),
+    ("text/x-lua", "lua"),
+    ("text/x-makefile", "make"),
+    ("text/x-msdos-batch", "batch"),
+    ("text/x-objective-c", "objective-c"),
+    ("text/x-pascal", "pascal"),
+    ("text/x-perl", "perl"),
+    ("text/x-python", "python"),
+    ("text/x-ruby", "ruby"),
+    ("text/x-rust", "rust"),
+    ("text/x-scala", "scala"),
+    ("text/x-script.python", "python"),
+    ("text/x-shellscript", "shell"),
+    ("text/x-swift", "swift"),
+    ("text/x-tex", "tex"),
+    ("text/x-toml", "toml"),
+    ("text/xml", "xml"),
+];
+
+/// Identifies a language from a MIME type as reported by [`file(1)`],
+/// i.e. `file --mime-type`, e.g. `text/x-c` or `text/x-shellscript`.
+/// Returns `None` if the language was not identified.
+///
+/// Parameters are ignored, e.g. `text/x-c; charset=us-ascii`, and the
+/// case of the MIME type does not affect the result.
+///
+/// The supported MIME types are the `text/x-*` types used by `file(1)`
+/// for source code, e.g. `text/x-python` and `text/x-script.python`,
+/// along with the standard types it emits for data and archive formats,
+/// e.g. `application/json` and `application/gzip`. Generic types,
+/// e.g. `text/plain` and `application/octet-stream`, return `None`.
+///
+/// [`file(1)`]: https://man7.org/linux/man-pages/man1/file.1.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_file_mime, Language};
+/// assert_eq!(from_file_mime("text/x-c"), Some(Language("C", "c")));
+/// assert_eq!(from_file_mime("text/x-c++"), Some(Language("C++", "cpp")));
+/// assert_eq!(from_file_mime("text/x-python"), Some(Language("Python", "python")));
+/// assert_eq!(from_file_mime("text/x-script.python"), Some(Language("Python", "python")));
+/// assert_eq!(from_file_mime("text/x-shellscript"), Some(Language("Shell", "shell")));
+/// assert_eq!(from_file_mime("application/json"), Some(Language("JSON", "json")));
+///
+/// // Parameters are ignored
+/// assert_eq!(from_file_mime("text/x-c; charset=us-ascii"), Some(Language("C", "c")));
+///
+/// assert_eq!(from_file_mime("text/plain"), None);
+/// ```
+pub fn from_file_mime(mime: &str) -> Option<Language<'static>> {
+    let mime = mime.split(';').next()?.trim().to_ascii_lowercase();
+    let i = FILE_MIME_TYPES
+        .binary_search_by_key(&mime.as_str(), |&(mime, _)| mime)
+        .ok()?;
+    languages::from_id(FILE_MIME_TYPES[i].1)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in FILE_MIME_TYPES.iter().zip(FILE_MIME_TYPES.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "MIME types out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_file_mime_types() {
+    for &(mime, id) in FILE_MIME_TYPES.iter() {
+        assert!(
+            languages::from_id(id).is_some(),
+            "Unknown language {:?} for {:?}",
+            id,
+            mime
+        );
+    }
+
+    assert_eq!(
+        from_file_mime("TEXT/X-RUBY"),
+        Some(Language("Ruby", "ruby"))
+    );
+    assert_eq!(
+        from_file_mime("text/x-makefile; charset=us-ascii"),
+        Some(Language("Make", "make"))
+    );
+    assert_eq!(
+        from_file_mime("text/x-msdos-batch"),
+        Some(Language("Batch", "batch"))
+    );
+    assert_eq!(from_file_mime("application/octet-stream"), None);
+    assert_eq!(from_file_mime(""), None);
+}
@@ -0,0 +1,218 @@
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use crate::Language;
+
+macro_rules! f {
+    ($filename:literal, $name:literal, $id:literal) => {
+        ($filename, Language($name, $id))
+    };
+}
+
+// The array is sorted by the lowercase filename
+//
+// Languages not included in the `common` feature are only
+// included when the `full` feature is enabled.
+pub(crate) static FILENAMES: &[(&str, Language)] = &[
+    f!(".bash_logout", "Shell", "shell"),
+    f!(".bash_profile", "Shell", "shell"),
+    f!(".bashrc", "Shell", "shell"),
+    #[cfg(feature = "full")]
+    f!(".DS_Store", "System File", "system-file"),
+    #[cfg(feature = "full")]
+    f!(".gitattributes", "Git Config", "git-config"),
+    #[cfg(feature = "full")]
+    f!(".gitconfig", "Git Config", "git-config"),
+    #[cfg(feature = "full")]
+    f!(".gitignore", "Git Config", "git-config"),
+    #[cfg(feature = "full")]
+    f!(".gitmodules", "Git Config", "git-config"),
+    #[cfg(feature = "full")]
+    f!(".gvimrc", "Vim Script", "vim-script"),
+    f!(".profile", "Shell", "shell"),
+    #[cfg(feature = "full")]
+    f!(".vimrc", "Vim Script", "vim-script"),
+    f!(".zprofile", "Shell", "shell"),
+    f!(".zshenv", "Shell", "shell"),
+    f!(".zshrc", "Shell", "shell"),
+    f!("Cargo.lock", "TOML", "toml"),
+    #[cfg(feature = "full")]
+    f!("CMakeLists.txt", "CMake", "cmake"),
+    f!("composer.lock", "JSON", "json"),
+    #[cfg(feature = "full")]
+    f!("Containerfile", "Dockerfile", "dockerfile"),
+    #[cfg(feature = "full")]
+    f!("desktop.ini", "System File", "system-file"),
+    #[cfg(feature = "full")]
+    f!("Dockerfile", "Dockerfile", "dockerfile"),
+    #[cfg(feature = "full")]
+    f!("ehthumbs.db", "System File", "system-file"),
+    f!("flake.lock", "JSON", "json"),
+    #[cfg(feature = "full")]
+    f!("Gemfile", "Ruby", "ruby"),
+    #[cfg(feature = "full")]
+    f!("Gemfile.lock", "Gemfile.lock", "gemfile-lock"),
+    #[cfg(feature = "full")]
+    f!("GNUmakefile", "Make", "make"),
+    #[cfg(feature = "full")]
+    f!("go.sum", "Go Checksums", "go-checksums"),
+    #[cfg(feature = "full")]
+    f!("Jenkinsfile", "Groovy", "groovy"),
+    #[cfg(feature = "full")]
+    f!("Makefile", "Make", "make"),
+    f!("Pipfile.lock", "JSON", "json"),
+    f!("Podfile.lock", "YAML", "yaml"),
+    f!("poetry.lock", "TOML", "toml"),
+    #[cfg(feature = "full")]
+    f!("Rakefile", "Ruby", "ruby"),
+    #[cfg(feature = "full")]
+    f!("Thumbs.db", "System File", "system-file"),
+    #[cfg(feature = "full")]
+    f!("Vagrantfile", "Ruby", "ruby"),
+    f!("yarn.lock", "YAML", "yaml"),
+];
+
+/// Identifies a language from a well-known filename, e.g. `Makefile`,
+/// `Dockerfile`, or `CMakeLists.txt`.
+/// The casing of the filename does not affect the result.
+/// Returns `None` if the language was not identified.
+///
+/// Only the whole filename is matched, i.e. not a path nor an extension.
+///
+/// *[See also `from_path`][from_path].*
+///
+/// [from_path]: fn.from_path.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_filename, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(from_filename("Makefile"), Some(Language("Make", "make")));
+/// assert_eq!(from_filename("Dockerfile"), Some(Language("Dockerfile", "dockerfile")));
+/// assert_eq!(from_filename("CMakeLists.txt"), Some(Language("CMake", "cmake")));
+/// assert_eq!(from_filename("Gemfile"), Some(Language("Ruby", "ruby")));
+/// assert_eq!(from_filename("Jenkinsfile"), Some(Language("Groovy", "groovy")));
+///
+/// // The case is ignored
+/// assert_eq!(from_filename("makefile"), Some(Language("Make", "make")));
+///
+/// assert_eq!(from_filename("foo.rs"), None);
+/// assert_eq!(from_filename("foo/Makefile"), None);
+/// # }
+/// ```
+#[inline]
+pub fn from_filename<S: AsRef<str>>(filename: S) -> Option<Language<'static>> {
+    find(filename.as_ref()).copied()
+}
+
+/// Same as [`from_filename`], except returning a reference into the table.
+///
+/// [`from_filename`]: fn.from_filename.html
+pub(crate) fn find(filename: &str) -> Option<&'static Language<'static>> {
+    FILENAMES
+        .binary_search_by(|&(name, _)| cmp_ignore_ascii_case(name, filename))
+        .ok()
+        .map(|i| &FILENAMES[i].1)
+}
+
+#[cfg(feature = "std")]
+/// Identifies a language from a well-known filename followed by a
+/// qualifier, e.g. `Dockerfile.prod` or `Makefile.linux`.
+#[inline]
+pub(crate) fn from_qualified_filename(filename: &str) -> Option<Language<'static>> {
+    find_qualified(filename).copied()
+}
+
+#[cfg(feature = "std")]
+pub(crate) fn find_qualified(filename: &str) -> Option<&'static Language<'static>> {
+    match filename.find('.') {
+        Some(i) if i > 0 && i + 1 < filename.len() => find(&filename[..i]),
+        _ => None,
+    }
+}
+
+fn cmp_ignore_ascii_case(a: &str, b: &str) -> Ordering {
+    let a = a.bytes().map(|b| b.to_ascii_lowercase());
+    let b = b.bytes().map(|b| b.to_ascii_lowercase());
+    a.cmp(b)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in FILENAMES.iter().zip(FILENAMES.iter().skip(1)) {
+        assert!(
+            cmp_ignore_ascii_case(a.0, b.0) == Ordering::Less,
+            "Filenames out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+/// Returns the well-known filenames of the language with the given ID,
+/// in the order of the built-in table, e.g. `["GNUmakefile", "Makefile"]`
+/// for `make`. Returns an empty slice if the ID is unknown, or if the
+/// language has no well-known filenames.
+///
+/// Filenames are matched ignoring ASCII case, i.e. `makefile` is
+/// also identified as Make.
+///
+/// *[See also `extensions_for`][extensions_for].*
+///
+/// [extensions_for]: fn.extensions_for.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::filenames_for;
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(filenames_for("make"), ["GNUmakefile", "Makefile"]);
+/// assert_eq!(filenames_for("dockerfile"), ["Containerfile", "Dockerfile"]);
+/// assert!(filenames_for("ruby").contains(&"Rakefile"));
+///
+/// assert!(filenames_for("rust").is_empty());
+/// assert!(filenames_for("unknown").is_empty());
+/// # }
+/// ```
+pub fn filenames_for(id: &str) -> &'static [&'static str] {
+    static FILENAMES_BY_ID: OnceLock<BTreeMap<&str, Vec<&str>>> = OnceLock::new();
+
+    let filenames = FILENAMES_BY_ID.get_or_init(|| {
+        let mut filenames = BTreeMap::<_, Vec<_>>::new();
+        for &(filename, lang) in FILENAMES.iter() {
+            filenames.entry(lang.id()).or_default().push(filename);
+        }
+        filenames
+    });
+    filenames.get(id).map_or(&[], Vec::as_slice)
+}
+
+#[cfg(feature = "full")]
+#[cfg(feature = "std")]
+#[test]
+fn dotfiles() {
+    use crate::from_path;
+
+    assert_eq!(from_path(".bashrc"), Some(Language("Shell", "shell")));
+    assert_eq!(from_path("home/.zshrc"), Some(Language("Shell", "shell")));
+    assert_eq!(
+        from_path(".vimrc"),
+        Some(Language("Vim Script", "vim-script"))
+    );
+    assert_eq!(
+        from_path(".gitignore"),
+        Some(Language("Git Config", "git-config"))
+    );
+    assert_eq!(
+        from_path("foo/.GitAttributes"),
+        Some(Language("Git Config", "git-config"))
+    );
+    assert_eq!(from_path(".unknownrc"), None);
+}
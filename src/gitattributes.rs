@@ -0,0 +1,267 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::glob::{glob_match, normalize};
+use crate::{languages, Language};
+
+/// Language overrides parsed from [`.gitattributes`], as used by
+/// [GitHub Linguist].
+///
+/// Only the `linguist-language` attribute is honored, e.g.
+/// `*.rs linguist-language=Rust`. The language is resolved by name
+/// or ID, ignoring case, e.g. `C++`, `common-lisp`, and `Common-Lisp`.
+/// Unsetting the attribute, i.e. `-linguist-language` or `!linguist-language`,
+/// removes overrides given by previous lines. All other attributes, e.g.
+/// `linguist-documentation` and `linguist-vendored`, are ignored.
+///
+/// As in Git, when multiple lines match the same path, the last line wins.
+///
+/// Patterns follow the `.gitattributes` rules:
+///
+/// - A pattern without a `/` matches the filename at any depth, e.g. `*.rs`
+/// - A pattern with a `/` matches relative to the repository root, e.g.
+///   `docs/*.txt` or `/build.rs`
+/// - `*` and `?` do not match `/`, while `**/` matches zero or more
+///   directories and a trailing `/**` matches everything inside a directory
+/// - `[abc]`, `[a-z]`, and `[!a-z]` match a character in (or not in) a set
+///
+/// [`.gitattributes`]: https://git-scm.com/docs/gitattributes
+/// [GitHub Linguist]: https://github.com/github/linguist/blob/master/docs/overrides.md
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{GitattributesOverrides, Language};
+/// let overrides = GitattributesOverrides::parse(
+///     "*.h linguist-language=C++\n\
+///      vendor/** linguist-vendored\n\
+///      /scripts/* linguist-language=Python\n",
+/// );
+///
+/// assert_eq!(overrides.language("src/foo.h"), Some(Language("C++", "cpp")));
+/// assert_eq!(overrides.language("scripts/build"), Some(Language("Python", "python")));
+/// assert_eq!(overrides.language("src/scripts/build"), None);
+/// assert_eq!(overrides.language("vendor/foo.rs"), None);
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct GitattributesOverrides {
+    rules: Vec<Rule>,
+}
+
+#[derive(Clone, Debug)]
+struct Rule {
+    pattern: String,
+    /// `None` if the attribute is unset.
+    language: Option<Language<'static>>,
+}
+
+impl GitattributesOverrides {
+    /// Creates an empty set of overrides.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses the content of a `.gitattributes` file.
+    ///
+    /// Blank lines, comments, macro definitions, and lines with unknown
+    /// languages are ignored.
+    pub fn parse(content: &str) -> Self {
+        let mut overrides = Self::new();
+        for line in content.lines() {
+            overrides.add_line(line);
+        }
+        overrides
+    }
+
+    /// Loads the overrides of the repository at `root`, from the
+    /// `.gitattributes` file at the root followed by `.git/info/attributes`,
+    /// which takes precedence as in Git. Missing files are ignored.
+    ///
+    /// Note that `.gitattributes` files in subdirectories are not loaded,
+    /// use [`add_nested`] for those.
+    ///
+    /// [`add_nested`]: #method.add_nested
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use detect_lang::{detect_with_gitattributes, GitattributesOverrides};
+    /// let overrides = GitattributesOverrides::load("path/to/repo")?;
+    /// let lang = detect_with_gitattributes("src/foo.h", &overrides);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn load<P: AsRef<Path>>(root: P) -> io::Result<Self> {
+        let root = root.as_ref();
+        let mut overrides = Self::new();
+        for path in &[
+            root.join(".gitattributes"),
+            root.join(".git/info/attributes"),
+        ] {
+            match fs::read_to_string(path) {
+                Ok(content) => content.lines().for_each(|line| overrides.add_line(line)),
+                Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(overrides)
+    }
+
+    /// Parses the content of a `.gitattributes` file located in `dir`,
+    /// relative to the repository root, e.g. `src` for `src/.gitattributes`.
+    /// The lines take precedence over all previously added lines.
+    ///
+    /// As in Git, patterns are relative to `dir`, i.e. `*.h` matches
+    /// `src/foo/bar.h` but not `foo.h`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{GitattributesOverrides, Language};
+    /// let mut overrides = GitattributesOverrides::parse("*.h linguist-language=C\n");
+    /// overrides.add_nested("src", "*.h linguist-language=C++\n/gen/* -linguist-language\n");
+    ///
+    /// assert_eq!(overrides.language("foo.h"), Some(Language("C", "c")));
+    /// assert_eq!(overrides.language("src/foo/bar.h"), Some(Language("C++", "cpp")));
+    /// assert_eq!(overrides.language("src/gen/bar.h"), None);
+    /// ```
+    pub fn add_nested<P: AsRef<Path>>(&mut self, dir: P, content: &str) {
+        let dir = match normalize(dir.as_ref()) {
+            Some(dir) => dir,
+            None => return,
+        };
+        let start = self.rules.len();
+        for line in content.lines() {
+            self.add_line(line);
+        }
+        if dir.is_empty() {
+            return;
+        }
+        for rule in &mut self.rules[start..] {
+            rule.pattern = format!("{}/{}", dir, rule.pattern);
+        }
+    }
+
+    /// Parses a single line of a `.gitattributes` file, which
+    /// takes precedence over all previously added lines.
+    pub fn add_line(&mut self, line: &str) {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with("[attr]") {
+            return;
+        }
+
+        let mut parts = line.split_whitespace();
+        let pattern = match parts.next() {
+            Some(pattern) => pattern,
+            None => return,
+        };
+
+        for attr in parts {
+            let language = if let Some(name) = attr.strip_prefix("linguist-language=") {
+                match languages::from_name(name)
+                    .or_else(|| languages::from_id(&name.to_lowercase()))
+                {
+                    Some(lang) => Some(lang),
+                    None => continue,
+                }
+            } else if attr == "-linguist-language" || attr == "!linguist-language" {
+                None
+            } else {
+                continue;
+            };
+
+            // Patterns without a `/` match at any depth, and patterns
+            // with a `/` are relative to the root
+            let pattern = if pattern.contains('/') {
+                pattern.trim_start_matches('/').to_owned()
+            } else {
+                format!("**/{}", pattern)
+            };
+            self.rules.push(Rule { pattern, language });
+        }
+    }
+
+    /// Returns the language override for `path`, relative to the
+    /// repository root. Returns `None` if no override applies.
+    pub fn language<P: AsRef<Path>>(&self, path: P) -> Option<Language<'static>> {
+        let path = normalize(path.as_ref())?;
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| glob_match(&rule.pattern, &path))
+            .and_then(|rule| rule.language)
+    }
+}
+
+/// Identifies a language from a path, relative to the repository root,
+/// applying `linguist-language` overrides from `.gitattributes` before
+/// falling back to [`from_path`].
+/// Returns `None` if the language was not identified.
+///
+/// *[See also `GitattributesOverrides`][GitattributesOverrides].*
+///
+/// [`from_path`]: fn.from_path.html
+/// [GitattributesOverrides]: struct.GitattributesOverrides.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{detect_with_gitattributes, GitattributesOverrides, Language};
+/// let overrides = GitattributesOverrides::parse(
+///     "*.rs linguist-language=Unknown\n\
+///      *.inc linguist-language=Rust\n\
+///      *.h linguist-language=C++\n\
+///      legacy/*.h -linguist-language\n",
+/// );
+///
+/// assert_eq!(detect_with_gitattributes("foo.inc", &overrides), Some(Language("Rust", "rust")));
+/// assert_eq!(detect_with_gitattributes("src/foo.h", &overrides), Some(Language("C++", "cpp")));
+/// assert_eq!(detect_with_gitattributes("legacy/foo.h", &overrides), Some(Language("C", "c")));
+///
+/// // Unknown languages are ignored
+/// assert_eq!(detect_with_gitattributes("foo.rs", &overrides), Some(Language("Rust", "rust")));
+/// ```
+pub fn detect_with_gitattributes<P: AsRef<Path>>(
+    path: P,
+    overrides: &GitattributesOverrides,
+) -> Option<Language<'static>> {
+    let path = path.as_ref();
+    overrides.language(path).or_else(|| crate::from_path(path))
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_load() {
+    use crate::test_util::TempDir;
+
+    let root = TempDir::new("gitattributes");
+    fs::create_dir_all(root.join(".git/info")).unwrap();
+
+    fs::write(
+        root.join(".gitattributes"),
+        "*.rs linguist-language=Text\n*.h linguist-language=C++\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join(".git/info/attributes"),
+        "*.h linguist-language=C\n",
+    )
+    .unwrap();
+
+    let overrides = GitattributesOverrides::load(&root).unwrap();
+    assert_eq!(
+        detect_with_gitattributes("src/main.rs", &overrides),
+        Some(Language("Text", "text"))
+    );
+    assert_eq!(
+        detect_with_gitattributes("foo.h", &overrides),
+        Some(Language("C", "c"))
+    );
+
+    let path = root.to_path_buf();
+    drop(root);
+
+    let overrides = GitattributesOverrides::load(&path).unwrap();
+    assert_eq!(overrides.language("src/main.rs"), None);
+}
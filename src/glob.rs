@@ -0,0 +1,157 @@
+use std::path::{Component, Path};
+
+/// Matches `path` against a glob `pattern`, where both are `/` separated.
+///
+/// - `*` matches anything except `/`
+/// - `?` matches any single character except `/`
+/// - `[abc]`, `[a-z]`, and `[!a-z]` match a single character in (or not in) the set
+/// - `**/` matches zero or more directories, and a trailing `/**` matches
+///   everything inside a directory
+/// - `\` escapes the following character
+pub(crate) fn glob_match(pattern: &str, path: &str) -> bool {
+    matches(pattern.as_bytes(), path.as_bytes())
+}
+
+/// Converts `path` into a `/` separated relative path.
+pub(crate) fn normalize(path: &Path) -> Option<String> {
+    let mut normalized = String::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(name) => {
+                if !normalized.is_empty() {
+                    normalized.push('/');
+                }
+                normalized.push_str(name.to_str()?);
+            }
+            Component::CurDir | Component::RootDir | Component::Prefix(_) => {}
+            Component::ParentDir => return None,
+        }
+    }
+    Some(normalized)
+}
+
+fn matches(pattern: &[u8], path: &[u8]) -> bool {
+    match pattern {
+        [] => path.is_empty(),
+        [b'*', b'*'] => true,
+        [b'*', b'*', b'/', rest @ ..] => {
+            matches(rest, path)
+                || path
+                    .iter()
+                    .enumerate()
+                    .any(|(i, &c)| c == b'/' && matches(rest, &path[i + 1..]))
+        }
+        [b'*', rest @ ..] => {
+            let rest = trim_stars(rest);
+            for i in 0..=path.len() {
+                if matches(rest, &path[i..]) {
+                    return true;
+                }
+                if path.get(i) == Some(&b'/') {
+                    break;
+                }
+            }
+            false
+        }
+        [b'?', rest @ ..] => match path {
+            [c, path @ ..] if *c != b'/' => matches(rest, path),
+            _ => false,
+        },
+        [b'[', class @ ..] => match (parse_class(class), path) {
+            (Some((set, rest)), [c, path @ ..]) => {
+                *c != b'/' && set.contains(*c) && matches(rest, path)
+            }
+            (None, [b'[', path @ ..]) => matches(class, path),
+            _ => false,
+        },
+        [b'\\', c, rest @ ..] => match path {
+            [p, path @ ..] if p == c => matches(rest, path),
+            _ => false,
+        },
+        [c, rest @ ..] => match path {
+            [p, path @ ..] if p == c => matches(rest, path),
+            _ => false,
+        },
+    }
+}
+
+// Consecutive `*` not part of `**/` are the same as a single `*`
+fn trim_stars(pattern: &[u8]) -> &[u8] {
+    let n = pattern.iter().take_while(|&&c| c == b'*').count();
+    &pattern[n..]
+}
+
+struct Class<'a> {
+    negated: bool,
+    items: &'a [u8],
+}
+
+impl Class<'_> {
+    fn contains(&self, c: u8) -> bool {
+        let mut found = false;
+        let mut i = 0;
+        while i < self.items.len() {
+            let start = self.items[i];
+            if self.items.get(i + 1) == Some(&b'-') && i + 2 < self.items.len() {
+                found |= (start..=self.items[i + 2]).contains(&c);
+                i += 3;
+            } else {
+                found |= start == c;
+                i += 1;
+            }
+        }
+        found != self.negated
+    }
+}
+
+// Parses a character class following `[`, returning the class and the
+// remaining pattern, or `None` if the class is not terminated
+fn parse_class(pattern: &[u8]) -> Option<(Class<'_>, &[u8])> {
+    let (negated, pattern) = match pattern {
+        [b'!', rest @ ..] | [b'^', rest @ ..] => (true, rest),
+        _ => (false, pattern),
+    };
+
+    // A `]` immediately after `[` or `[!` is part of the class
+    let end = pattern
+        .iter()
+        .skip(1)
+        .position(|&c| c == b']')
+        .map(|i| i + 1)?;
+
+    let class = Class {
+        negated,
+        items: &pattern[..end],
+    };
+    Some((class, &pattern[end + 1..]))
+}
+
+#[test]
+fn check_glob_match() {
+    assert!(glob_match("*.rs", "main.rs"));
+    assert!(!glob_match("*.rs", "src/main.rs"));
+    assert!(!glob_match("*.rs", "main.rsx"));
+    assert!(glob_match("src/*.rs", "src/main.rs"));
+    assert!(!glob_match("src/*.rs", "src/foo/main.rs"));
+
+    assert!(glob_match("**/*.rs", "main.rs"));
+    assert!(glob_match("**/*.rs", "src/foo/main.rs"));
+    assert!(glob_match("src/**/*.rs", "src/main.rs"));
+    assert!(glob_match("src/**/*.rs", "src/foo/bar/main.rs"));
+    assert!(glob_match("docs/**", "docs/foo/bar.md"));
+    assert!(!glob_match("docs/**", "docs"));
+    assert!(glob_match("docs/*", "docs/bar.md"));
+    assert!(!glob_match("docs/*", "docs/foo/bar.md"));
+
+    assert!(glob_match("foo.?", "foo.c"));
+    assert!(!glob_match("foo.?", "foo.cc"));
+    assert!(glob_match("foo.[ch]", "foo.h"));
+    assert!(!glob_match("foo.[ch]", "foo.d"));
+    assert!(glob_match("foo.[a-c]", "foo.b"));
+    assert!(glob_match("foo.[!a-c]", "foo.d"));
+    assert!(!glob_match("foo.[!a-c]", "foo.b"));
+    assert!(glob_match("foo[", "foo["));
+
+    assert!(glob_match("\\*.rs", "*.rs"));
+    assert!(!glob_match("\\*.rs", "main.rs"));
+}
@@ -0,0 +1,586 @@
+use crate::{content, Language};
+
+/// A pattern matched against each line of the content, ignoring
+/// leading and trailing whitespace.
+// Without the `full` feature, there are no heuristics
+#[cfg_attr(not(feature = "full"), allow(dead_code))]
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Pattern {
+    /// The line starts with the string.
+    Line(&'static str),
+    /// The line contains the string.
+    Contains(&'static str),
+}
+
+use Pattern::*;
+
+impl Pattern {
+    fn matches(self, line: &str) -> bool {
+        match self {
+            Line(prefix) => line.starts_with(prefix),
+            Contains(s) => line.contains(s),
+        }
+    }
+
+    pub(crate) fn parts(self) -> [&'static str; 2] {
+        match self {
+            Line(prefix) => ["line", prefix],
+            Contains(s) => ["contains", s],
+        }
+    }
+}
+
+/// A language and the patterns identifying it.
+pub(crate) type Rule = (Language<'static>, &'static [Pattern]);
+
+// The array is sorted by the extension name
+//
+// The rules of each extension are checked in order, and the first
+// language with any matching pattern is used. If no patterns match,
+// then the extension is resolved as usual.
+pub(crate) static HEURISTICS: &[(&str, &[Rule])] = &[
+    #[cfg(feature = "full")]
+    (
+        "cls",
+        &[
+            (
+                Language("VBA", "vba"),
+                &[Line("VERSION 1.0 CLASS"), Line("Attribute VB_")],
+            ),
+            (
+                Language("TeX", "tex"),
+                &[
+                    Line("\\NeedsTeXFormat"),
+                    Line("\\ProvidesClass"),
+                    Line("\\LoadClass"),
+                    Line("%"),
+                ],
+            ),
+            (
+                Language("Apex", "apex"),
+                &[
+                    Contains("public class "),
+                    Contains("global class "),
+                    Contains("private class "),
+                    Contains(" with sharing "),
+                    Line("@isTest"),
+                ],
+            ),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "d",
+        &[
+            (
+                Language("DTrace", "dtrace"),
+                &[
+                    Line("#pragma D"),
+                    Line("BEGIN"),
+                    Contains("::entry"),
+                    Contains("::return"),
+                    Line("syscall::"),
+                ],
+            ),
+            (
+                Language("D", "d"),
+                &[
+                    Line("module "),
+                    Line("import std."),
+                    Line("import core."),
+                    Line("void main("),
+                ],
+            ),
+            // Dependency files generated by compilers, e.g. `gcc -MD`
+            (
+                Language("Make", "make"),
+                &[Contains(".o: "), Contains(".o : ")],
+            ),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "h",
+        &[
+            (
+                Language("Objective-C", "objective-c"),
+                &[
+                    Line("#import"),
+                    Line("@interface"),
+                    Line("@protocol"),
+                    Line("@end"),
+                ],
+            ),
+            (
+                Language("C++", "cpp"),
+                &[
+                    Line("class "),
+                    Line("template"),
+                    Line("namespace "),
+                    Line("public:"),
+                    Line("private:"),
+                    Contains("std::"),
+                    Contains("#include <iostream>"),
+                ],
+            ),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "inc",
+        &[
+            (Language("PHP", "php"), &[Line("<?php"), Contains("<?=")]),
+            (
+                Language("C++", "cpp"),
+                &[
+                    Line("#include"),
+                    Line("#define"),
+                    Line("#ifndef"),
+                    Line("#pragma"),
+                ],
+            ),
+            (
+                Language("Pascal", "pascal"),
+                &[
+                    Line("{$"),
+                    Line("procedure "),
+                    Line("function "),
+                    Line("begin"),
+                    Line("end;"),
+                ],
+            ),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "m",
+        &[
+            (
+                Language("Objective-C", "objective-c"),
+                &[
+                    Line("#import"),
+                    Line("#include"),
+                    Line("@interface"),
+                    Line("@implementation"),
+                    Line("@protocol"),
+                    Line("@end"),
+                ],
+            ),
+            // Checked before MATLAB, as both use `%` comments
+            (
+                Language("Mercury", "mercury"),
+                &[
+                    Line(":- module"),
+                    Line(":- interface"),
+                    Line(":- implementation"),
+                    Line(":- import_module"),
+                    Line(":- pred"),
+                    Line(":- func"),
+                ],
+            ),
+            // Including Octave
+            (
+                Language("MATLAB", "matlab"),
+                &[
+                    Line("function "),
+                    Line("%"),
+                    Line("end"),
+                    Contains("disp("),
+                    Contains("fprintf("),
+                    Contains("zeros("),
+                ],
+            ),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "pl",
+        &[
+            (
+                Language("Prolog", "prolog"),
+                &[Line(":-"), Contains(") :-"), Line("?-")],
+            ),
+            (
+                Language("Perl", "perl"),
+                &[
+                    Line("#!/usr/bin/perl"),
+                    Line("#!/usr/bin/env perl"),
+                    Line("use strict"),
+                    Line("use warnings"),
+                    Line("my "),
+                    Line("sub "),
+                    Line("package "),
+                ],
+            ),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "r",
+        &[
+            (
+                Language("Rebol", "rebol"),
+                &[Line("REBOL ["), Line("REBOL["), Line("Rebol [")],
+            ),
+            (
+                Language("R", "r"),
+                &[Contains("<- "), Contains("library("), Contains("function(")],
+            ),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "sql",
+        // Dialects are identified by their uppercase keywords, while
+        // generic SQL is resolved as usual
+        &[
+            (
+                Language("PL/SQL", "plsql"),
+                &[
+                    Contains("CREATE OR REPLACE PACKAGE"),
+                    Contains("DBMS_OUTPUT."),
+                    Contains("%ROWTYPE"),
+                ],
+            ),
+            (
+                Language("T-SQL", "tsql"),
+                &[
+                    Contains("DECLARE @"),
+                    Line("SET NOCOUNT "),
+                    Contains("IDENTITY("),
+                    Contains("NVARCHAR("),
+                ],
+            ),
+            (
+                Language("PostgreSQL", "postgresql"),
+                &[
+                    Contains("LANGUAGE plpgsql"),
+                    Contains(" SERIAL"),
+                    Contains("RETURNS TRIGGER"),
+                    Line("\\connect "),
+                ],
+            ),
+            (
+                Language("MySQL", "mysql"),
+                &[
+                    Contains("ENGINE=InnoDB"),
+                    Contains("AUTO_INCREMENT"),
+                    Line("DELIMITER "),
+                ],
+            ),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "t",
+        &[
+            (
+                Language("Raku", "raku"),
+                &[
+                    Line("use v6"),
+                    Line("unit module "),
+                    Line("unit class "),
+                    Line("my class "),
+                ],
+            ),
+            (
+                Language("Perl", "perl"),
+                &[
+                    Line("#!/usr/bin/perl"),
+                    Line("#!/usr/bin/env perl"),
+                    Line("use strict"),
+                    Line("use warnings"),
+                    Line("use Test::More"),
+                ],
+            ),
+        ],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "ts",
+        // Qt Linguist translations
+        &[(
+            Language("XML", "xml"),
+            &[Line("<?xml"), Line("<!DOCTYPE TS>"), Line("<TS")],
+        )],
+    ),
+    #[cfg(feature = "full")]
+    (
+        "v",
+        &[
+            (
+                Language("Coq", "coq"),
+                &[
+                    Line("Require Import"),
+                    Line("Theorem "),
+                    Line("Lemma "),
+                    Line("Proof."),
+                    Line("Qed."),
+                    Line("Inductive "),
+                    Line("Fixpoint "),
+                    Line("Definition "),
+                    Line("Example "),
+                    Line("Module "),
+                    Line("From "),
+                ],
+            ),
+            (
+                Language("V", "v"),
+                &[
+                    Line("fn "),
+                    Line("pub fn "),
+                    Line("module main"),
+                    Line("import "),
+                ],
+            ),
+            (
+                Language("Verilog", "verilog"),
+                &[
+                    Line("module "),
+                    Line("endmodule"),
+                    Line("always @"),
+                    Line("assign "),
+                    Line("`timescale"),
+                ],
+            ),
+        ],
+    ),
+];
+
+/// Disambiguates a lowercase extension using the content of the file.
+/// Returns `None` if the extension has no heuristics, or if no
+/// patterns match.
+pub(crate) fn disambiguate(ext: &str, content: &[u8]) -> Option<Language<'static>> {
+    let i = HEURISTICS
+        .binary_search_by_key(&ext, |&(ext, _)| ext)
+        .ok()?;
+    let content = content::text(content)?;
+
+    HEURISTICS[i]
+        .1
+        .iter()
+        .find(|(_, patterns)| {
+            content
+                .lines()
+                .map(str::trim)
+                .any(|line| patterns.iter().any(|pattern| pattern.matches(line)))
+        })
+        .map(|&(lang, _)| lang)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in HEURISTICS.iter().zip(HEURISTICS.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Heuristics out of order - {:?} should come after {:?}",
+            a.0,
+            b.0,
+        );
+    }
+}
+
+#[test]
+fn check_candidates() {
+    for &(ext, rules) in HEURISTICS.iter() {
+        let candidates = crate::candidates::find(ext)
+            .unwrap_or_else(|| panic!("{:?} is not an ambiguous extension", ext));
+        for (lang, _) in rules.iter() {
+            assert!(
+                candidates.contains(lang),
+                "{:?} is not a candidate for {:?}",
+                lang,
+                ext
+            );
+        }
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_headers() {
+    let cases: &[(&str, &str)] = &[
+        ("#import <Foundation/Foundation.h>\n", "objective-c"),
+        ("@interface Foo : NSObject\n@end\n", "objective-c"),
+        ("#pragma once\n\nclass Foo {\npublic:\n};\n", "cpp"),
+        ("template <typename T>\nT foo(T t);\n", "cpp"),
+        ("namespace foo {\n}\n", "cpp"),
+        ("#include <string>\nstd::string foo();\n", "cpp"),
+    ];
+    for &(content, id) in cases {
+        assert_eq!(
+            disambiguate("h", content.as_bytes()).map(|lang| lang.id()),
+            Some(id),
+            "{:?}",
+            content
+        );
+    }
+
+    assert_eq!(
+        disambiguate("h", b"#include <stdio.h>\nint foo(void);\n"),
+        None
+    );
+    assert_eq!(disambiguate("rs", b"class Foo {};\n"), None);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_m() {
+    let cases: &[(&str, &str)] = &[
+        (
+            "#import \"Foo.h\"\n\n@implementation Foo\n@end\n",
+            "objective-c",
+        ),
+        (":- module foo.\n:- interface.\n", "mercury"),
+        ("% Mercury\n:- import_module io.\n", "mercury"),
+        ("function y = foo(x)\n  y = x;\nend\n", "matlab"),
+        ("% MATLAB\nx = zeros(3);\n", "matlab"),
+        ("disp('Hello World')\n", "matlab"),
+    ];
+    for &(content, id) in cases {
+        assert_eq!(
+            disambiguate("m", content.as_bytes()).map(|lang| lang.id()),
+            Some(id),
+            "{:?}",
+            content
+        );
+    }
+
+    assert_eq!(disambiguate("m", b"x = 1;\n"), None);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_pl() {
+    let cases: &[(&str, &str)] = &[
+        (":- module(foo, [bar/1]).\n", "prolog"),
+        (":- use_module(library(lists)).\n", "prolog"),
+        ("parent(X, Y) :- father(X, Y).\n", "prolog"),
+        ("#!/usr/bin/perl\nprint \"Hello World\";\n", "perl"),
+        ("use strict;\nuse warnings;\n", "perl"),
+        ("my $foo = 1;\n", "perl"),
+        ("sub foo {\n}\n", "perl"),
+    ];
+    for &(content, id) in cases {
+        assert_eq!(
+            disambiguate("pl", content.as_bytes()).map(|lang| lang.id()),
+            Some(id),
+            "{:?}",
+            content
+        );
+    }
+
+    // Without content, both are candidates
+    let (_, langs) = crate::ambiguous_extensions()
+        .find(|&(ext, _)| ext == "pl")
+        .unwrap();
+    assert_eq!(
+        langs,
+        [Language("Perl", "perl"), Language("Prolog", "prolog")]
+    );
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_v() {
+    let cases: &[(&str, &str)] = &[
+        ("Theorem foo : True.\nProof.\n  trivial.\nQed.\n", "coq"),
+        ("Require Import Arith.\n", "coq"),
+        ("module main\n\nfn main() {\n}\n", "v"),
+        ("fn main() {\n    x := 1\n}\n", "v"),
+        ("import os\n\nx := os.args\n", "v"),
+        ("Definition x := 0.\n", "coq"),
+        ("Example foo : 1 + 1 = 2.\n", "coq"),
+        ("Module Foo.\nEnd Foo.\n", "coq"),
+        ("From Coq Require Import List.\n", "coq"),
+        ("module foo(input a, output b);\nendmodule\n", "verilog"),
+        ("`timescale 1ns / 1ps\n", "verilog"),
+    ];
+    for &(content, id) in cases {
+        assert_eq!(
+            disambiguate("v", content.as_bytes()).map(|lang| lang.id()),
+            Some(id),
+            "{:?}",
+            content
+        );
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_ts() {
+    let qt = "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<!DOCTYPE TS>\n<TS version=\"2.1\" language=\"de\">\n</TS>\n";
+    assert_eq!(
+        disambiguate("ts", qt.as_bytes()),
+        Some(Language("XML", "xml"))
+    );
+    assert_eq!(
+        disambiguate("ts", b"<TS version=\"2.1\">\n</TS>\n"),
+        Some(Language("XML", "xml"))
+    );
+
+    let ts = "import { foo } from \"./foo\";\n\nconst x: number = 1;\n";
+    assert_eq!(disambiguate("ts", ts.as_bytes()), None);
+    assert_eq!(
+        crate::from_path_with_content("foo.ts", ts.as_bytes()),
+        Some(Language("TypeScript", "typescript"))
+    );
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_ambiguous() {
+    let cases: &[(&str, &str, &str)] = &[
+        (
+            "cls",
+            "VERSION 1.0 CLASS\nAttribute VB_Name = \"Foo\"\n",
+            "vba",
+        ),
+        (
+            "cls",
+            "\\NeedsTeXFormat{LaTeX2e}\n\\ProvidesClass{foo}\n",
+            "tex",
+        ),
+        ("cls", "public with sharing class Foo {\n}\n", "apex"),
+        ("d", "#pragma D option quiet\n", "dtrace"),
+        ("d", "syscall::open:entry\n{\n}\n", "dtrace"),
+        ("d", "import std.stdio;\n\nvoid main() {\n}\n", "d"),
+        ("d", "foo.o: foo.c foo.h \\\n  bar.h\n", "make"),
+        ("inc", "<?php\necho 'Hello World';\n", "php"),
+        ("inc", "#ifndef FOO_INC\n#define FOO_INC\n#endif\n", "cpp"),
+        ("inc", "{$IFDEF FPC}\nprocedure Foo;\n", "pascal"),
+        ("r", "REBOL [\n    Title: \"Foo\"\n]\n", "rebol"),
+        ("r", "library(ggplot2)\nx <- c(1, 2, 3)\n", "r"),
+        (
+            "sql",
+            "CREATE OR REPLACE PACKAGE foo AS\nEND foo;\n",
+            "plsql",
+        ),
+        ("sql", "DECLARE @foo INT = 1;\n", "tsql"),
+        (
+            "sql",
+            "CREATE TABLE foo (id SERIAL PRIMARY KEY);\n",
+            "postgresql",
+        ),
+        ("sql", "CREATE TABLE foo (id INT) ENGINE=InnoDB;\n", "mysql"),
+        ("t", "use v6;\nuse Test;\n", "raku"),
+        ("t", "use strict;\nuse Test::More tests => 1;\n", "perl"),
+    ];
+    for &(ext, content, id) in cases {
+        assert_eq!(
+            disambiguate(ext, content.as_bytes()).map(|lang| lang.id()),
+            Some(id),
+            "{:?} {:?}",
+            ext,
+            content
+        );
+    }
+
+    // Generic SQL is resolved as usual
+    let sql = "SELECT * FROM foo;\n";
+    assert_eq!(disambiguate("sql", sql.as_bytes()), None);
+    assert_eq!(
+        crate::from_path_with_content("foo.sql", sql.as_bytes()),
+        Some(Language("SQL", "sql"))
+    );
+}
@@ -0,0 +1,195 @@
+// The arrays are sorted by the language ID
+//
+// Names are the ones used in `language-*` CSS classes, which are
+// either the name or an alias of the language in highlight.js and Prism
+pub(crate) static HLJS_LANGUAGES: [(&str, &str); 65] = [
+    ("batch", "dos"),
+    ("c", "c"),
+    ("clojure", "clojure"),
+    ("cmake", "cmake"),
+    ("common-lisp", "lisp"),
+    ("coq", "coq"),
+    ("cpp", "cpp"),
+    ("csharp", "csharp"),
+    ("css", "css"),
+    ("d", "d"),
+    ("dart", "dart"),
+    ("dockerfile", "dockerfile"),
+    ("glsl", "glsl"),
+    ("gml", "gml"),
+    ("go", "go"),
+    ("graphql", "graphql"),
+    ("groovy", "groovy"),
+    ("haml", "haml"),
+    ("handlebars", "handlebars"),
+    ("html", "html"),
+    ("html-erb", "erb"),
+    ("ini", "ini"),
+    ("java", "java"),
+    ("javascript", "javascript"),
+    ("json", "json"),
+    ("kotlin", "kotlin"),
+    ("less", "less"),
+    ("lua", "lua"),
+    ("make", "makefile"),
+    ("markdown", "markdown"),
+    ("matlab", "matlab"),
+    ("mercury", "mercury"),
+    ("mysql", "sql"),
+    ("nix", "nix"),
+    ("objective-c", "objectivec"),
+    ("pascal", "delphi"),
+    ("perl", "perl"),
+    ("php", "php"),
+    ("plsql", "sql"),
+    ("postgresql", "pgsql"),
+    ("powershell", "powershell"),
+    ("prolog", "prolog"),
+    ("puppet", "puppet"),
+    ("python", "python"),
+    ("r", "r"),
+    ("ruby", "ruby"),
+    ("rust", "rust"),
+    ("scala", "scala"),
+    ("scss", "scss"),
+    ("shell", "bash"),
+    ("sql", "sql"),
+    ("svg", "xml"),
+    ("swift", "swift"),
+    ("tex", "latex"),
+    ("text", "plaintext"),
+    ("toml", "toml"),
+    ("tsql", "sql"),
+    ("tsx", "typescript"),
+    ("typescript", "typescript"),
+    ("typescript-declaration", "typescript"),
+    ("verilog", "verilog"),
+    ("vim-script", "vim"),
+    ("xhtml", "xml"),
+    ("xml", "xml"),
+    ("yaml", "yaml"),
+];
+
+pub(crate) static PRISM_LANGUAGES: [(&str, &str); 82] = [
+    ("apex", "apex"),
+    ("asciidoc", "asciidoc"),
+    ("batch", "batch"),
+    ("bicep", "bicep"),
+    ("c", "c"),
+    ("clojure", "clojure"),
+    ("cmake", "cmake"),
+    ("coq", "coq"),
+    ("cpp", "cpp"),
+    ("csharp", "csharp"),
+    ("css", "css"),
+    ("csv", "csv"),
+    ("cue", "cue"),
+    ("d", "d"),
+    ("dart", "dart"),
+    ("dhall", "dhall"),
+    ("dockerfile", "docker"),
+    ("gdscript", "gdscript"),
+    ("glsl", "glsl"),
+    ("gml", "gml"),
+    ("go", "go"),
+    ("graphql", "graphql"),
+    ("groovy", "groovy"),
+    ("haml", "haml"),
+    ("handlebars", "handlebars"),
+    ("hcl", "hcl"),
+    ("hlsl", "hlsl"),
+    ("html", "html"),
+    ("html-erb", "erb"),
+    ("ini", "ini"),
+    ("java", "java"),
+    ("javascript", "javascript"),
+    ("jinja", "django"),
+    ("json", "json"),
+    ("json5", "json5"),
+    ("jsonc", "json"),
+    ("kotlin", "kotlin"),
+    ("less", "less"),
+    ("lua", "lua"),
+    ("make", "makefile"),
+    ("markdown", "markdown"),
+    ("matlab", "matlab"),
+    ("mysql", "sql"),
+    ("nix", "nix"),
+    ("objective-c", "objectivec"),
+    ("pascal", "pascal"),
+    ("perl", "perl"),
+    ("php", "php"),
+    ("plsql", "plsql"),
+    ("postgresql", "sql"),
+    ("powershell", "powershell"),
+    ("prolog", "prolog"),
+    ("puppet", "puppet"),
+    ("python", "python"),
+    ("r", "r"),
+    ("racket", "racket"),
+    ("restructuredtext", "rest"),
+    ("ruby", "ruby"),
+    ("rust", "rust"),
+    ("sass", "sass"),
+    ("scala", "scala"),
+    ("scss", "scss"),
+    ("shell", "bash"),
+    ("sparql", "sparql"),
+    ("sql", "sql"),
+    ("svg", "svg"),
+    ("swift", "swift"),
+    ("terraform", "hcl"),
+    ("tex", "latex"),
+    ("textile", "textile"),
+    ("toml", "toml"),
+    ("tsql", "sql"),
+    ("tsx", "tsx"),
+    ("typescript", "typescript"),
+    ("typescript-declaration", "typescript"),
+    ("v", "v"),
+    ("vba", "vba"),
+    ("verilog", "verilog"),
+    ("vim-script", "vim"),
+    ("xhtml", "markup"),
+    ("xml", "xml"),
+    ("yaml", "yaml"),
+];
+
+pub(crate) fn hljs_name(id: &str) -> Option<&'static str> {
+    HLJS_LANGUAGES
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .ok()
+        .map(|i| HLJS_LANGUAGES[i].1)
+}
+
+pub(crate) fn prism_name(id: &str) -> Option<&'static str> {
+    PRISM_LANGUAGES
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .ok()
+        .map(|i| PRISM_LANGUAGES[i].1)
+}
+
+#[test]
+fn check_order() {
+    for table in [&HLJS_LANGUAGES[..], &PRISM_LANGUAGES[..]] {
+        for (a, b) in table.iter().zip(table.iter().skip(1)) {
+            assert!(
+                a.0 < b.0,
+                "Highlighter languages out of order - {:?} should come after {:?}",
+                a,
+                b,
+            );
+        }
+    }
+}
+
+#[test]
+fn check_highlighters() {
+    for &(id, _) in HLJS_LANGUAGES.iter().chain(PRISM_LANGUAGES.iter()) {
+        assert!(
+            crate::category::category(id).is_some(),
+            "Highlighter language for unknown language {:?}",
+            id
+        );
+    }
+}
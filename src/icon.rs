@@ -0,0 +1,86 @@
+// The array is sorted by the language ID
+//
+// Icons are given by their name in Devicon, e.g. `devicon-rust-plain`
+pub(crate) static ICONS: [(&str, &str); 51] = [
+    ("astro", "astro"),
+    ("c", "c"),
+    ("clojure", "clojure"),
+    ("cmake", "cmake"),
+    ("cpp", "cplusplus"),
+    ("csharp", "csharp"),
+    ("css", "css3"),
+    ("dart", "dart"),
+    ("dockerfile", "docker"),
+    ("gdscript", "godot"),
+    ("go", "go"),
+    ("godot-resource", "godot"),
+    ("graphql", "graphql"),
+    ("groovy", "groovy"),
+    ("handlebars", "handlebars"),
+    ("html", "html5"),
+    ("java", "java"),
+    ("javascript", "javascript"),
+    ("json", "json"),
+    ("julia", "julia"),
+    ("jupyter-notebook", "jupyter"),
+    ("kotlin", "kotlin"),
+    ("less", "less"),
+    ("lua", "lua"),
+    ("markdown", "markdown"),
+    ("matlab", "matlab"),
+    ("mysql", "mysql"),
+    ("nix", "nixos"),
+    ("objective-c", "objectivec"),
+    ("perl", "perl"),
+    ("php", "php"),
+    ("postgresql", "postgresql"),
+    ("powershell", "powershell"),
+    ("python", "python"),
+    ("r", "r"),
+    ("ruby", "ruby"),
+    ("rust", "rust"),
+    ("sass", "sass"),
+    ("scala", "scala"),
+    ("scss", "sass"),
+    ("shell", "bash"),
+    ("svelte", "svelte"),
+    ("swift", "swift"),
+    ("terraform", "terraform"),
+    ("tex", "latex"),
+    ("tsx", "react"),
+    ("typescript", "typescript"),
+    ("typescript-declaration", "typescript"),
+    ("vue", "vuejs"),
+    ("xml", "xml"),
+    ("yaml", "yaml"),
+];
+
+pub(crate) fn icon(id: &str) -> Option<&'static str> {
+    ICONS
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .ok()
+        .map(|i| ICONS[i].1)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in ICONS.iter().zip(ICONS.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Icons out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_icons() {
+    for &(id, _) in ICONS.iter() {
+        assert!(
+            crate::category::category(id).is_some(),
+            "Icon for unknown language {:?}",
+            id
+        );
+    }
+}
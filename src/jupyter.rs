@@ -0,0 +1,80 @@
+use crate::Language;
+
+/// Identifies the language of the kernel of a [Jupyter Notebook], from
+/// the `metadata.kernelspec.language` or `metadata.language_info.name`
+/// of the notebook. Returns `None` if the content is not a notebook, or
+/// the language of the kernel was not identified.
+///
+/// The content must be the whole notebook, as the metadata is commonly
+/// stored at the end of the file.
+///
+/// [`from_path_with_content`] and [`detect_explained`] use this for
+/// `.ipynb` files, instead of reporting them as Jupyter Notebook.
+///
+/// *This function requires the `jupyter` feature.*
+///
+/// [Jupyter Notebook]: https://jupyter.org/
+/// [`from_path_with_content`]: fn.from_path_with_content.html
+/// [`detect_explained`]: fn.detect_explained.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_notebook, from_path, from_path_with_content, Language};
+/// let notebook = br#"{
+///     "cells": [],
+///     "metadata": {
+///         "kernelspec": { "name": "python3", "language": "python" }
+///     },
+///     "nbformat": 4
+/// }"#;
+/// assert_eq!(from_notebook(notebook), Some(Language("Python", "python")));
+///
+/// assert_eq!(from_path_with_content("foo.ipynb", notebook), Some(Language("Python", "python")));
+/// assert_eq!(from_path("foo.ipynb"), Some(Language("Jupyter Notebook", "jupyter-notebook")));
+/// ```
+pub fn from_notebook(content: &[u8]) -> Option<Language<'static>> {
+    let notebook = serde_json::from_slice::<serde_json::Value>(content).ok()?;
+    let metadata = notebook.get("metadata")?;
+    metadata
+        .pointer("/kernelspec/language")
+        .or_else(|| metadata.pointer("/language_info/name"))?
+        .as_str()
+        .and_then(crate::from_name_or_alias)
+}
+
+#[test]
+fn check_notebooks() {
+    let cases: &[(&str, Option<&str>)] = &[
+        (
+            r#"{"metadata": {"kernelspec": {"language": "python"}}}"#,
+            Some("python"),
+        ),
+        (
+            r#"{"metadata": {"kernelspec": {"language": "R"}}}"#,
+            Some("r"),
+        ),
+        (
+            r#"{"metadata": {"kernelspec": {"language": "julia"}}}"#,
+            Some("julia"),
+        ),
+        (
+            r#"{"metadata": {"language_info": {"name": "python"}}}"#,
+            Some("python"),
+        ),
+        (
+            r#"{"metadata": {"kernelspec": {"language": "unknown"}}}"#,
+            None,
+        ),
+        (r#"{"metadata": {}}"#, None),
+        (r#"{"metadata": {"kernelspec": {"language": 1}}}"#, None),
+        (r#"{"metadata": {"kern"#, None),
+        ("", None),
+    ];
+    for &(content, id) in cases {
+        let lang = from_notebook(content.as_bytes());
+        #[cfg(not(feature = "full"))]
+        let id = id.filter(|&id| id == "python");
+        assert_eq!(lang.map(|lang| lang.id()), id, "{:?}", content);
+    }
+}
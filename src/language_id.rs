@@ -0,0 +1,290 @@
+use crate::Language;
+
+macro_rules! language_ids {
+    ($($variant:ident, $const:ident => $name:literal, $id:literal,)*) => {
+        /// The IDs of the built-in languages, for matching languages
+        /// without comparing strings.
+        ///
+        /// New variants may be added when languages are added,
+        /// so matches must include a wildcard arm.
+        ///
+        /// *[See also `Language::lang_id`][lang_id].*
+        ///
+        /// [lang_id]: struct.Language.html#method.lang_id
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use detect_lang::{from_path, LanguageId};
+        /// let lang = from_path("foo.rs").unwrap();
+        /// match lang.lang_id() {
+        ///     Some(LanguageId::Rust) => {}
+        ///     Some(LanguageId::Cpp) => unreachable!(),
+        ///     _ => unreachable!(),
+        /// }
+        /// ```
+        #[non_exhaustive]
+        #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
+        pub enum LanguageId {
+            $(
+                #[doc = concat!("`", $id, "`")]
+                $variant,
+            )*
+        }
+
+        impl LanguageId {
+            /// Returns the ID as a string, e.g. `"rust"` for `LanguageId::Rust`.
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// # use detect_lang::LanguageId;
+            /// assert_eq!(LanguageId::Rust.as_str(), "rust");
+            /// assert_eq!(LanguageId::ObjectiveC.as_str(), "objective-c");
+            /// ```
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $id,)*
+                }
+            }
+        }
+
+        // The array is sorted by the language ID
+        pub(crate) static LANGUAGE_IDS: &[(&str, LanguageId)] = &[
+            $(($id, LanguageId::$variant),)*
+        ];
+
+        #[cfg(test)]
+        static CONSTS: &[Language<'static>] = &[$(consts::$const,)*];
+
+        /// Constants of the built-in languages, for comparing languages
+        /// without spelling out names and IDs.
+        ///
+        /// Constants are available regardless of the enabled features,
+        /// e.g. `common`, but the languages might not be identified.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// # use detect_lang::{consts, from_path, Language};
+        /// assert_eq!(consts::RUST, Language("Rust", "rust"));
+        /// assert_eq!(from_path("foo.rs"), Some(consts::RUST));
+        /// assert_eq!(from_path("foo.cpp"), Some(consts::CPP));
+        /// ```
+        pub mod consts {
+            use crate::Language;
+
+            $(
+                #[doc = concat!("`Language(\"", $name, "\", \"", $id, "\")`")]
+                pub const $const: Language<'static> = Language($name, $id);
+            )*
+        }
+    };
+}
+
+language_ids! {
+    SevenZip, SEVEN_ZIP => "7-Zip", "7z",
+    AndroidLayout, ANDROID_LAYOUT => "Android Layout", "android-layout",
+    Apex, APEX => "Apex", "apex",
+    AsciiDoc, ASCIIDOC => "AsciiDoc", "asciidoc",
+    Astro, ASTRO => "Astro", "astro",
+    Batch, BATCH => "Batch", "batch",
+    BibTex, BIBTEX => "BibTeX", "bibtex",
+    Bicep, BICEP => "Bicep", "bicep",
+    Blade, BLADE => "Blade", "blade",
+    Bzip2, BZIP2 => "Bzip2", "bzip2",
+    C, C => "C", "c",
+    CassandraQl, CASSANDRA_QL => "Cassandra Query Language", "cassandra-ql",
+    Clojure, CLOJURE => "Clojure", "clojure",
+    CMake, CMAKE => "CMake", "cmake",
+    CommonLisp, COMMON_LISP => "Common Lisp", "common-lisp",
+    Coq, COQ => "Coq", "coq",
+    Cpp, CPP => "C++", "cpp",
+    CSharp, CSHARP => "C#", "csharp",
+    Cson, CSON => "CSON", "cson",
+    Css, CSS => "CSS", "css",
+    Csv, CSV => "CSV", "csv",
+    Cue, CUE => "CUE", "cue",
+    D, D => "D", "d",
+    Dart, DART => "Dart", "dart",
+    Dhall, DHALL => "Dhall", "dhall",
+    Dockerfile, DOCKERFILE => "Dockerfile", "dockerfile",
+    DTrace, DTRACE => "DTrace", "dtrace",
+    Elf, ELF => "ELF", "elf",
+    EmacsLisp, EMACS_LISP => "Emacs Lisp", "emacs-lisp",
+    Excel, EXCEL => "Excel", "excel",
+    Feather, FEATHER => "Feather", "feather",
+    GdScript, GDSCRIPT => "GDScript", "gdscript",
+    GemfileLock, GEMFILE_LOCK => "Gemfile.lock", "gemfile-lock",
+    GitConfig, GIT_CONFIG => "Git Config", "git-config",
+    Glsl, GLSL => "GLSL", "glsl",
+    Gml, GML => "GameMaker Language", "gml",
+    Go, GO => "Go", "go",
+    GoChecksums, GO_CHECKSUMS => "Go Checksums", "go-checksums",
+    GodotResource, GODOT_RESOURCE => "Godot Resource", "godot-resource",
+    GraphQl, GRAPHQL => "GraphQL", "graphql",
+    Groovy, GROOVY => "Groovy", "groovy",
+    Gzip, GZIP => "Gzip", "gzip",
+    Haml, HAML => "Haml", "haml",
+    Handlebars, HANDLEBARS => "Handlebars", "handlebars",
+    Hcl, HCL => "HCL", "hcl",
+    HiveQl, HIVEQL => "HiveQL", "hiveql",
+    Hjson, HJSON => "Hjson", "hjson",
+    Hlsl, HLSL => "HLSL", "hlsl",
+    Html, HTML => "HTML", "html",
+    HtmlErb, HTML_ERB => "HTML+ERB", "html-erb",
+    Ini, INI => "INI", "ini",
+    Java, JAVA => "Java", "java",
+    JavaScript, JAVASCRIPT => "JavaScript", "javascript",
+    JestSnapshot, JEST_SNAPSHOT => "Jest Snapshot", "jest-snapshot",
+    Jinja, JINJA => "Jinja", "jinja",
+    Json, JSON => "JSON", "json",
+    JsonLines, JSON_LINES => "JSON Lines", "json-lines",
+    Json5, JSON5 => "JSON5", "json5",
+    Jsonc, JSONC => "JSON with Comments", "jsonc",
+    Julia, JULIA => "Julia", "julia",
+    JupyterNotebook, JUPYTER_NOTEBOOK => "Jupyter Notebook", "jupyter-notebook",
+    Kotlin, KOTLIN => "Kotlin", "kotlin",
+    Less, LESS => "Less", "less",
+    Lua, LUA => "Lua", "lua",
+    MachO, MACH_O => "Mach-O", "mach-o",
+    Make, MAKE => "Make", "make",
+    Markdown, MARKDOWN => "Markdown", "markdown",
+    Matlab, MATLAB => "MATLAB", "matlab",
+    MavenPom, MAVEN_POM => "Maven POM", "maven-pom",
+    Mercury, MERCURY => "Mercury", "mercury",
+    MySql, MYSQL => "MySQL", "mysql",
+    Nix, NIX => "Nix", "nix",
+    Nushell, NUSHELL => "Nushell", "nushell",
+    ObjectiveC, OBJECTIVE_C => "Objective-C", "objective-c",
+    Ods, ODS => "OpenDocument Spreadsheet", "ods",
+    Org, ORG => "Org", "org",
+    Parquet, PARQUET => "Parquet", "parquet",
+    Pascal, PASCAL => "Pascal", "pascal",
+    Pdf, PDF => "PDF", "pdf",
+    Pe, PE => "Portable Executable", "pe",
+    Perl, PERL => "Perl", "perl",
+    Php, PHP => "PHP", "php",
+    PlSql, PLSQL => "PL/SQL", "plsql",
+    Png, PNG => "PNG", "png",
+    Pod, POD => "Pod", "pod",
+    PostgreSql, POSTGRESQL => "PostgreSQL", "postgresql",
+    PowerShell, POWERSHELL => "PowerShell", "powershell",
+    Prolog, PROLOG => "Prolog", "prolog",
+    Psv, PSV => "PSV", "psv",
+    Puppet, PUPPET => "Puppet", "puppet",
+    Python, PYTHON => "Python", "python",
+    R, R => "R", "r",
+    Racket, RACKET => "Racket", "racket",
+    Raku, RAKU => "Raku", "raku",
+    Rar, RAR => "RAR", "rar",
+    Rebol, REBOL => "Rebol", "rebol",
+    ReStructuredText, RESTRUCTUREDTEXT => "reStructuredText", "restructuredtext",
+    Ruby, RUBY => "Ruby", "ruby",
+    Rust, RUST => "Rust", "rust",
+    Sass, SASS => "SASS", "sass",
+    Scala, SCALA => "Scala", "scala",
+    Scss, SCSS => "SCSS", "scss",
+    ShaderLab, SHADERLAB => "ShaderLab", "shaderlab",
+    Shell, SHELL => "Shell", "shell",
+    Sparql, SPARQL => "SPARQL", "sparql",
+    Sql, SQL => "SQL", "sql",
+    Sqlite, SQLITE => "SQLite", "sqlite",
+    Svelte, SVELTE => "Svelte", "svelte",
+    Svg, SVG => "SVG", "svg",
+    Swift, SWIFT => "Swift", "swift",
+    SystemFile, SYSTEM_FILE => "System File", "system-file",
+    Tar, TAR => "Tar", "tar",
+    TarGzip, TAR_GZIP => "Gzipped Tar", "tar-gzip",
+    Terraform, TERRAFORM => "Terraform", "terraform",
+    Tex, TEX => "TeX", "tex",
+    Text, TEXT => "Text", "text",
+    Textile, TEXTILE => "Textile", "textile",
+    Toml, TOML => "TOML", "toml",
+    TSql, TSQL => "T-SQL", "tsql",
+    Tsv, TSV => "TSV", "tsv",
+    Tsx, TSX => "TSX", "tsx",
+    TypeScript, TYPESCRIPT => "TypeScript", "typescript",
+    TypeScriptDeclaration, TYPESCRIPT_DECLARATION => "TypeScript Declaration", "typescript-declaration",
+    V, V => "V", "v",
+    Vba, VBA => "VBA", "vba",
+    Verilog, VERILOG => "Verilog", "verilog",
+    VimScript, VIM_SCRIPT => "Vim Script", "vim-script",
+    Vue, VUE => "Vue", "vue",
+    Xhtml, XHTML => "XHTML", "xhtml",
+    Xml, XML => "XML", "xml",
+    XmlPropertyList, XML_PROPERTY_LIST => "XML Property List", "xml-property-list",
+    Xslt, XSLT => "XSLT", "xslt",
+    Xz, XZ => "XZ", "xz",
+    Yaml, YAML => "YAML", "yaml",
+    Zip, ZIP => "ZIP", "zip",
+    Zstd, ZSTD => "Zstandard", "zstd",
+}
+
+impl LanguageId {
+    /// Returns the ID of a built-in language, e.g. `LanguageId::Cpp`
+    /// for `"cpp"`. Returns `None` if the ID is unknown.
+    ///
+    /// The ID is matched exactly, i.e. the case is not ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::LanguageId;
+    /// assert_eq!(LanguageId::from_id("cpp"), Some(LanguageId::Cpp));
+    /// assert_eq!(LanguageId::from_id("Rust"), None);
+    /// ```
+    pub fn from_id(id: &str) -> Option<Self> {
+        LANGUAGE_IDS
+            .binary_search_by_key(&id, |&(id, _)| id)
+            .ok()
+            .map(|i| LANGUAGE_IDS[i].1)
+    }
+
+    /// Returns the language of the ID.
+    /// Returns `None` if the language is not included by the enabled
+    /// features, e.g. `common`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{Language, LanguageId};
+    /// assert_eq!(LanguageId::Rust.language(), Some(Language("Rust", "rust")));
+    /// ```
+    #[inline]
+    pub fn language(self) -> Option<Language<'static>> {
+        crate::languages::from_id(self.as_str())
+    }
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in LANGUAGE_IDS.iter().zip(LANGUAGE_IDS.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Language IDs out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_language_ids() {
+    for lang in crate::languages() {
+        let id = LanguageId::from_id(lang.id());
+        assert!(id.is_some(), "Missing language ID for {:?}", lang);
+        assert_eq!(id.unwrap().as_str(), lang.id());
+    }
+    for lang in crate::languages() {
+        assert!(CONSTS.contains(&lang), "Missing constant for {:?}", lang);
+    }
+    for &(id, lang_id) in LANGUAGE_IDS.iter() {
+        assert_eq!(lang_id.as_str(), id);
+        assert!(
+            crate::category::category(id).is_some(),
+            "Language ID {:?} is not a language",
+            id
+        );
+    }
+}
@@ -1,3 +1,14 @@
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+
+use crate::candidates::CANDIDATES;
+use crate::compound::COMPOUND_EXTENSIONS;
+use crate::filenames::FILENAMES;
+use crate::magic::SIGNATURES;
+use crate::xml::XML_DIALECTS;
 use crate::Language;
 
 macro_rules! l {
@@ -7,67 +18,450 @@ macro_rules! l {
 }
 
 // The array is sorted by the extension name
-pub(crate) const LANGUAGES: [(&str, Language); 58] = [
+//
+// Languages not included in the `common` feature are only
+// included when the `full` feature is enabled.
+pub(crate) static LANGUAGES: &[(&str, Language)] = &[
+    #[cfg(feature = "full")]
+    l!("7z", "7-Zip", "7z"),
+    #[cfg(feature = "full")]
+    l!("adoc", "AsciiDoc", "asciidoc"),
+    #[cfg(feature = "full")]
+    l!("asciidoc", "AsciiDoc", "asciidoc"),
+    #[cfg(feature = "full")]
+    l!("astro", "Astro", "astro"),
+    #[cfg(feature = "full")]
+    l!("bas", "VBA", "vba"),
+    #[cfg(feature = "full")]
     l!("bat", "Batch", "batch"),
+    #[cfg(feature = "full")]
+    l!("bib", "BibTeX", "bibtex"),
+    #[cfg(feature = "full")]
+    l!("bicep", "Bicep", "bicep"),
+    #[cfg(feature = "full")]
+    l!("bz2", "Bzip2", "bzip2"),
     l!("c", "C", "c"),
     l!("cc", "C++", "cpp"),
+    #[cfg(feature = "full")]
     l!("cl", "Common Lisp", "common-lisp"),
+    #[cfg(feature = "full")]
     l!("clj", "Clojure", "clojure"),
+    #[cfg(feature = "full")]
+    l!("cls", "TeX", "tex"), // LaTeX class
+    #[cfg(feature = "full")]
     l!("comp", "GLSL", "glsl"),
     l!("cpp", "C++", "cpp"),
+    #[cfg(feature = "full")]
+    l!("cql", "Cassandra Query Language", "cassandra-ql"),
+    #[cfg(feature = "full")]
     l!("cs", "C#", "csharp"),
+    #[cfg(feature = "full")]
+    l!("cson", "CSON", "cson"),
     l!("css", "CSS", "css"),
+    #[cfg(feature = "full")]
+    l!("csv", "CSV", "csv"), // Delimited formats get distinct IDs, like in GitHub Linguist
+    #[cfg(feature = "full")]
+    l!("cue", "CUE", "cue"),
     l!("cxx", "C++", "cpp"),
+    #[cfg(feature = "full")]
+    l!("d", "D", "d"),
+    #[cfg(feature = "full")]
     l!("dart", "Dart", "dart"),
+    #[cfg(feature = "full")]
+    l!("dhall", "Dhall", "dhall"),
+    #[cfg(feature = "full")]
+    l!("di", "D", "d"),
+    #[cfg(feature = "full")]
+    l!("el", "Emacs Lisp", "emacs-lisp"),
+    #[cfg(feature = "full")]
+    l!("feather", "Feather", "feather"),
+    #[cfg(feature = "full")]
     l!("frag", "GLSL", "glsl"),
+    #[cfg(feature = "full")]
+    l!("gd", "GDScript", "gdscript"),
+    #[cfg(feature = "full")]
     l!("geom", "GLSL", "glsl"),
+    #[cfg(feature = "full")]
     l!("glsl", "GLSL", "glsl"),
+    #[cfg(feature = "full")]
+    l!("gml", "GameMaker Language", "gml"),
+    #[cfg(feature = "full")]
     l!("go", "Go", "go"),
+    #[cfg(feature = "full")]
+    l!("godot", "Godot Resource", "godot-resource"),
+    #[cfg(feature = "full")]
+    l!("gql", "GraphQL", "graphql"),
+    #[cfg(feature = "full")]
+    l!("gradle", "Groovy", "groovy"),
+    #[cfg(feature = "full")]
+    l!("graphql", "GraphQL", "graphql"),
+    #[cfg(feature = "full")]
+    l!("groovy", "Groovy", "groovy"),
+    #[cfg(feature = "full")]
+    l!("gz", "Gzip", "gzip"),
     l!("h", "C", "c"),
+    #[cfg(feature = "full")]
     l!("haml", "Haml", "haml"),
+    #[cfg(feature = "full")]
     l!("handlebars", "Handlebars", "handlebars"),
+    #[cfg(feature = "full")]
     l!("hbs", "Handlebars", "handlebars"),
-    l!("hlsl", "HLSL", "HLSL"),
+    #[cfg(feature = "full")]
+    l!("hcl", "HCL", "hcl"),
+    #[cfg(feature = "full")]
+    l!("hjson", "Hjson", "hjson"),
+    #[cfg(feature = "full")]
+    l!("hlsl", "HLSL", "hlsl"),
     l!("hpp", "C++", "cpp"),
+    #[cfg(feature = "full")]
+    l!("hql", "HiveQL", "hiveql"),
     l!("html", "HTML", "html"),
     l!("hxx", "C++", "cpp"),
+    #[cfg(feature = "full")]
+    l!("inc", "PHP", "php"),
+    #[cfg(feature = "full")]
     l!("ini", "INI", "ini"),
+    #[cfg(feature = "full")]
+    l!("ipynb", "Jupyter Notebook", "jupyter-notebook"),
+    #[cfg(feature = "full")]
     l!("java", "Java", "java"),
+    #[cfg(feature = "full")]
     l!("jinja", "Jinja", "jinja"),
+    #[cfg(feature = "full")]
     l!("jinja2", "Jinja", "jinja"),
+    #[cfg(feature = "full")]
+    l!("jl", "Julia", "julia"),
     l!("js", "JavaScript", "javascript"),
     l!("json", "JSON", "json"),
+    #[cfg(feature = "full")]
+    l!("json5", "JSON5", "json5"),
+    #[cfg(feature = "full")]
     l!("jsonc", "JSON with Comments", "jsonc"),
+    #[cfg(feature = "full")]
+    l!("jsonl", "JSON Lines", "json-lines"),
+    #[cfg(feature = "full")]
     l!("kt", "Kotlin", "kotlin"),
+    #[cfg(feature = "full")]
+    l!("kts", "Kotlin", "kotlin"), // Kotlin scripts
+    #[cfg(feature = "full")]
     l!("less", "Less", "less"),
+    #[cfg(feature = "full")]
+    l!("lnk", "System File", "system-file"),
+    #[cfg(feature = "full")]
     l!("lua", "Lua", "lua"),
+    #[cfg(feature = "full")]
+    l!("m", "Objective-C", "objective-c"),
+    #[cfg(feature = "full")]
+    l!("mak", "Make", "make"),
     l!("md", "Markdown", "markdown"),
+    #[cfg(feature = "full")]
+    l!("mk", "Make", "make"),
+    #[cfg(feature = "full")]
+    l!("mysql", "MySQL", "mysql"),
+    #[cfg(feature = "full")]
+    l!("ndjson", "JSON Lines", "json-lines"),
+    #[cfg(feature = "full")]
+    l!("nix", "Nix", "nix"),
+    #[cfg(feature = "full")]
+    l!("nomad", "HCL", "hcl"), // Nomad job specification
+    #[cfg(feature = "full")]
+    l!("nu", "Nushell", "nushell"),
+    #[cfg(feature = "full")]
+    l!("ods", "OpenDocument Spreadsheet", "ods"),
+    #[cfg(feature = "full")]
+    l!("org", "Org", "org"),
+    #[cfg(feature = "full")]
+    l!("p6", "Raku", "raku"),
+    #[cfg(feature = "full")]
+    l!("parquet", "Parquet", "parquet"),
+    #[cfg(feature = "full")]
+    l!("php", "PHP", "php"),
+    #[cfg(feature = "full")]
+    l!("phtml", "PHP", "php"),
+    #[cfg(feature = "full")]
+    l!("pkb", "PL/SQL", "plsql"),
+    #[cfg(feature = "full")]
+    l!("pks", "PL/SQL", "plsql"),
+    #[cfg(feature = "full")]
     l!("pl", "Perl", "perl"),
+    #[cfg(feature = "full")]
+    l!("plist", "XML Property List", "xml-property-list"),
+    #[cfg(feature = "full")]
+    l!("pls", "PL/SQL", "plsql"),
+    #[cfg(feature = "full")]
+    l!("pod", "Pod", "pod"),
+    #[cfg(feature = "full")]
+    l!("pp", "Puppet", "puppet"), // Also Pascal, see `CANDIDATES`
+    #[cfg(feature = "full")]
+    l!("prefab", "YAML", "yaml"),
+    #[cfg(feature = "full")]
+    l!("prolog", "Prolog", "prolog"),
+    #[cfg(feature = "full")]
+    l!("ps1", "PowerShell", "powershell"),
+    #[cfg(feature = "full")]
+    l!("psd1", "PowerShell", "powershell"),
+    #[cfg(feature = "full")]
+    l!("psm1", "PowerShell", "powershell"),
+    #[cfg(feature = "full")]
+    l!("psql", "PostgreSQL", "postgresql"),
+    #[cfg(feature = "full")]
+    l!("psv", "PSV", "psv"),
     l!("py", "Python", "python"),
-    l!("pyc", "Python", "python"),
-    l!("pyo", "Python", "python"),
+    #[cfg(feature = "full")]
+    l!("r", "R", "r"),
+    #[cfg(feature = "full")]
+    l!("raku", "Raku", "raku"),
+    #[cfg(feature = "full")]
+    l!("rakumod", "Raku", "raku"),
+    #[cfg(feature = "full")]
+    l!("rar", "RAR", "rar"),
+    #[cfg(feature = "full")]
     l!("rb", "Ruby", "ruby"),
+    #[cfg(feature = "full")]
+    l!("reb", "Rebol", "rebol"),
+    #[cfg(feature = "full")]
+    l!("rebol", "Rebol", "rebol"),
+    #[cfg(feature = "full")]
     l!("rkt", "Racket", "racket"),
     l!("rs", "Rust", "rust"),
+    #[cfg(feature = "full")]
+    l!("rst", "reStructuredText", "restructuredtext"),
+    #[cfg(feature = "full")]
     l!("sass", "SASS", "sass"),
+    #[cfg(feature = "full")]
     l!("sc", "Scala", "scala"),
+    #[cfg(feature = "full")]
     l!("scala", "Scala", "scala"),
+    #[cfg(feature = "full")]
     l!("scss", "SCSS", "scss"),
     l!("sh", "Shell", "shell"),
-    l!("sql", "SQL", "sql"),
+    #[cfg(feature = "full")]
+    l!("shader", "ShaderLab", "shaderlab"),
+    #[cfg(feature = "full")]
+    l!("snap", "Jest Snapshot", "jest-snapshot"),
+    #[cfg(feature = "full")]
+    l!("sparql", "SPARQL", "sparql"),
+    #[cfg(feature = "full")]
+    l!("sql", "SQL", "sql"), // Generic SQL, dialects have separate IDs
+    #[cfg(feature = "full")]
+    l!("sty", "TeX", "tex"), // LaTeX package
+    #[cfg(feature = "full")]
+    l!("svelte", "Svelte", "svelte"),
+    #[cfg(feature = "full")]
+    l!("svg", "SVG", "svg"),
+    #[cfg(feature = "full")]
     l!("swift", "Swift", "swift"),
+    #[cfg(feature = "full")]
+    l!("t", "Perl", "perl"), // Perl test scripts
+    #[cfg(feature = "full")]
+    l!("tar", "Tar", "tar"),
+    #[cfg(feature = "full")]
     l!("tesc", "GLSL", "glsl"),
+    #[cfg(feature = "full")]
     l!("tese", "GLSL", "glsl"),
+    #[cfg(feature = "full")]
     l!("tex", "TeX", "tex"),
+    #[cfg(feature = "full")]
+    l!("textile", "Textile", "textile"),
+    #[cfg(feature = "full")]
+    l!("tf", "Terraform", "terraform"), // Terraform is HCL, but has a separate ID
+    #[cfg(feature = "full")]
+    l!("tfvars", "Terraform", "terraform"),
+    #[cfg(feature = "full")]
+    l!("tgz", "Gzipped Tar", "tar-gzip"),
     l!("toml", "TOML", "toml"),
+    #[cfg(feature = "full")]
+    l!("tres", "Godot Resource", "godot-resource"),
+    #[cfg(feature = "full")]
+    l!("trigger", "Apex", "apex"),
     l!("ts", "TypeScript", "typescript"),
+    #[cfg(feature = "full")]
+    l!("tscn", "Godot Resource", "godot-resource"),
+    #[cfg(feature = "full")]
+    l!("tsql", "T-SQL", "tsql"),
+    #[cfg(feature = "full")]
+    l!("tsv", "TSV", "tsv"),
+    #[cfg(feature = "full")]
+    l!("tsx", "TSX", "tsx"),
+    #[cfg(feature = "full")]
+    l!("txt", "Text", "text"),
+    #[cfg(feature = "full")]
+    l!("unity", "YAML", "yaml"), // Unity scenes and prefabs are YAML
+    #[cfg(feature = "full")]
+    l!("v", "Verilog", "verilog"),
+    #[cfg(feature = "full")]
+    l!("vba", "VBA", "vba"),
+    #[cfg(feature = "full")]
     l!("vert", "GLSL", "glsl"),
+    #[cfg(feature = "full")]
+    l!("vh", "Verilog", "verilog"),
+    #[cfg(feature = "full")]
+    l!("vim", "Vim Script", "vim-script"),
+    #[cfg(feature = "full")]
+    l!("vsh", "V", "v"),
+    #[cfg(feature = "full")]
+    l!("vue", "Vue", "vue"),
+    #[cfg(feature = "full")]
     l!("xhtml", "XHTML", "xhtml"),
+    #[cfg(feature = "full")]
+    l!("xls", "Excel", "excel"),
+    #[cfg(feature = "full")]
+    l!("xlsx", "Excel", "excel"),
+    #[cfg(feature = "full")]
     l!("xml", "XML", "xml"),
+    #[cfg(feature = "full")]
+    l!("xsl", "XSLT", "xslt"),
+    #[cfg(feature = "full")]
+    l!("xslt", "XSLT", "xslt"),
+    #[cfg(feature = "full")]
+    l!("xz", "XZ", "xz"),
     l!("yaml", "YAML", "yaml"),
     l!("yml", "YAML", "yaml"),
+    #[cfg(feature = "full")]
+    l!("zip", "ZIP", "zip"),
+    #[cfg(feature = "full")]
+    l!("zst", "Zstandard", "zstd"),
 ];
 
+/// Returns all languages, including those only identified by filename.
+/// The iterator may contain duplicates.
+pub(crate) fn all() -> impl Iterator<Item = Language<'static>> {
+    let extensions = LANGUAGES.iter().map(|&(_, lang)| lang);
+    let filenames = FILENAMES.iter().map(|&(_, lang)| lang);
+    let candidates = CANDIDATES
+        .iter()
+        .flat_map(|&(_, langs)| langs.iter().copied());
+    let compound = COMPOUND_EXTENSIONS.iter().map(|&(_, lang)| lang);
+    let signatures = SIGNATURES.iter().map(|&(_, lang)| lang);
+    #[cfg(feature = "full")]
+    let signatures = signatures.chain(Some(crate::magic::PORTABLE_EXECUTABLE));
+    let dialects = XML_DIALECTS.iter().map(|&(_, _, lang)| lang);
+    #[cfg(feature = "full")]
+    let dialects = dialects.chain(Some(crate::xml::ANDROID_LAYOUT));
+    extensions
+        .chain(filenames)
+        .chain(candidates)
+        .chain(compound)
+        .chain(signatures)
+        .chain(dialects)
+}
+
+/// Returns all built-in languages, without duplicates, sorted by ID.
+///
+/// This includes languages only identified by filename, e.g. Dockerfile,
+/// by multi-part extensions, e.g. Gzipped Tar, or by magic number, e.g. ELF.
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{languages, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert!(languages().any(|lang| lang == Language("Rust", "rust")));
+/// assert!(languages().any(|lang| lang == Language("Dockerfile", "dockerfile")));
+///
+/// // Languages are sorted by ID, and only occur once
+/// let ids = languages().map(|lang| lang.id()).collect::<Vec<_>>();
+/// assert!(ids.windows(2).all(|w| w[0] < w[1]));
+/// # }
+/// ```
+pub fn languages() -> impl Iterator<Item = Language<'static>> {
+    let mut langs = all().collect::<Vec<_>>();
+    langs.sort_by_key(|lang| lang.id());
+    langs.dedup_by_key(|lang| lang.id());
+    langs.into_iter()
+}
+
+/// Returns all built-in extensions along with their language,
+/// sorted by extension. Extensions are lowercase and without a
+/// leading `.`, e.g. `rs`.
+///
+/// Each extension maps to the language returned by [`from_extension`].
+/// Other candidates of ambiguous extensions are given by [`candidates`].
+///
+/// [`from_extension`]: fn.from_extension.html
+/// [`candidates`]: fn.candidates.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{extensions, from_extension, Language};
+/// assert!(extensions().any(|ext| ext == ("rs", Language("Rust", "rust"))));
+///
+/// for (ext, lang) in extensions() {
+///     assert_eq!(from_extension(ext), Some(lang));
+/// }
+/// ```
+pub fn extensions() -> impl Iterator<Item = (&'static str, Language<'static>)> {
+    LANGUAGES.iter().copied()
+}
+
+#[cfg(feature = "std")]
+/// Returns the extensions of the language with the given ID,
+/// sorted and without a leading `.`, e.g. `["c++", "cc", "cpp", ...]`
+/// for `cpp`. Returns an empty slice if the ID is unknown.
+///
+/// This includes ambiguous extensions, which the language is a
+/// [candidate] of, e.g. `h` for C++.
+///
+/// *[See also `Language::extensions`][extensions].*
+///
+/// [candidate]: fn.candidates.html
+/// [extensions]: struct.Language.html#method.extensions
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::extensions_for;
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(extensions_for("rust"), ["rs"]);
+/// assert!(extensions_for("cpp").contains(&"cpp"));
+/// assert!(extensions_for("cpp").contains(&"h"));
+/// assert!(extensions_for("c").contains(&"h"));
+///
+/// assert!(extensions_for("unknown").is_empty());
+/// # }
+/// ```
+pub fn extensions_for(id: &str) -> &'static [&'static str] {
+    static EXTENSIONS: OnceLock<BTreeMap<&str, Vec<&str>>> = OnceLock::new();
+
+    let extensions = EXTENSIONS.get_or_init(|| {
+        let mut extensions = BTreeMap::<_, Vec<_>>::new();
+        let candidates = CANDIDATES
+            .iter()
+            .flat_map(|&(ext, langs)| langs.iter().map(move |lang| (ext, *lang)));
+        for (ext, lang) in LANGUAGES.iter().copied().chain(candidates) {
+            extensions.entry(lang.id()).or_default().push(ext);
+        }
+        for exts in extensions.values_mut() {
+            exts.sort_unstable();
+            exts.dedup();
+        }
+        extensions
+    });
+    extensions.get(id).map_or(&[], Vec::as_slice)
+}
+
+/// Finds a language by its ID.
+pub(crate) fn from_id(id: &str) -> Option<Language<'static>> {
+    all().find(|lang| lang.id() == id)
+}
+
+/// Finds a language by its name, ignoring case.
+///
+/// Unlike extensions, names are compared using Unicode case folding,
+/// as they are typed by humans.
+pub(crate) fn from_name(name: &str) -> Option<Language<'static>> {
+    all().find(|lang| eq_ignore_case(lang.name(), name))
+}
+
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.chars()
+        .flat_map(char::to_lowercase)
+        .eq(b.chars().flat_map(char::to_lowercase))
+}
+
 #[test]
 fn check_order() {
     for (a, b) in LANGUAGES.iter().zip(LANGUAGES.iter().skip(1)) {
@@ -79,3 +473,333 @@ fn check_order() {
         );
     }
 }
+
+#[test]
+fn check_languages() {
+    for lang in languages() {
+        assert_eq!(
+            languages()
+                .filter(|other| other.name() == lang.name())
+                .count(),
+            1,
+            "Duplicate name {:?}",
+            lang.name()
+        );
+        assert_eq!(from_id(lang.id()), Some(lang));
+    }
+}
+
+#[test]
+fn check_detectable_languages() {
+    // Tables which are not included in `all`
+    let mut detectable = Vec::new();
+    detectable.extend(crate::shebang::INTERPRETERS.iter().map(|&(_, lang)| lang));
+    detectable.extend(crate::content::RULES.iter().map(|&(lang, _)| lang));
+    #[cfg(feature = "std")]
+    detectable.extend(
+        crate::heuristics::HEURISTICS
+            .iter()
+            .flat_map(|&(_, rules)| rules.iter().map(|&(lang, _)| lang)),
+    );
+    #[cfg(feature = "std")]
+    detectable.extend(crate::project::MANIFESTS.iter().map(|&(_, lang)| lang));
+    #[cfg(feature = "classifier")]
+    detectable.extend(crate::classifier::SAMPLES.iter().map(|&(lang, _)| lang));
+
+    for lang in detectable {
+        assert_eq!(
+            from_id(lang.id()),
+            Some(lang),
+            "Unknown language {:?}",
+            lang
+        );
+    }
+}
+
+#[cfg(not(feature = "full"))]
+#[test]
+fn check_common() {
+    // The languages listed in the documentation of the `common` feature
+    let common = [
+        "c",
+        "cpp",
+        "css",
+        "html",
+        "javascript",
+        "json",
+        "markdown",
+        "python",
+        "rust",
+        "shell",
+        "toml",
+        "typescript",
+        "yaml",
+    ];
+    let ids = languages().map(|lang| lang.id()).collect::<Vec<_>>();
+    assert_eq!(ids, common);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn check_extensions_for() {
+    for &(ext, lang) in LANGUAGES.iter() {
+        assert!(extensions_for(lang.id()).contains(&ext), "{:?}", ext);
+    }
+    for &(ext, langs) in CANDIDATES.iter() {
+        for lang in langs {
+            assert!(extensions_for(lang.id()).contains(&ext), "{:?}", ext);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn pointer_stability() {
+    use crate::{from_extension, from_lowercase_extension, from_path};
+    use std::ptr;
+
+    for &(ext, lang) in LANGUAGES.iter() {
+        for found in [
+            from_extension(ext),
+            from_lowercase_extension(ext),
+            from_path(format!("foo.{}", ext)),
+        ] {
+            let found = found.unwrap();
+            assert!(ptr::eq(found.name(), lang.name()), "{:?}", ext);
+            assert!(ptr::eq(found.id(), lang.id()), "{:?}", ext);
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(feature = "full")]
+#[test]
+fn documentation_formats() {
+    use crate::from_path;
+
+    assert_eq!(
+        from_path("README.RST"),
+        Some(Language("reStructuredText", "restructuredtext"))
+    );
+    assert_eq!(
+        from_path("README.adoc"),
+        Some(Language("AsciiDoc", "asciidoc"))
+    );
+    assert_eq!(from_path("notes.org"), Some(Language("Org", "org")));
+    assert_eq!(from_path("refs.bib"), Some(Language("BibTeX", "bibtex")));
+    assert_eq!(from_path("foo.sty"), Some(Language("TeX", "tex")));
+    assert_eq!(from_path("foo.CLS"), Some(Language("TeX", "tex")));
+}
+
+#[cfg(feature = "std")]
+#[cfg(feature = "full")]
+#[test]
+fn infrastructure_formats() {
+    use crate::from_path;
+
+    assert_eq!(
+        from_path("main.TF"),
+        Some(Language("Terraform", "terraform"))
+    );
+    assert_eq!(
+        from_path("prod.tfvars"),
+        Some(Language("Terraform", "terraform"))
+    );
+    assert_eq!(from_path("config.HCL"), Some(Language("HCL", "hcl")));
+    assert_eq!(from_path("job.nomad"), Some(Language("HCL", "hcl")));
+    assert_eq!(from_path("default.Nix"), Some(Language("Nix", "nix")));
+    assert_eq!(from_path("package.dhall"), Some(Language("Dhall", "dhall")));
+    assert_eq!(from_path("schema.cue"), Some(Language("CUE", "cue")));
+    assert_eq!(from_path("main.Bicep"), Some(Language("Bicep", "bicep")));
+    assert_eq!(from_path("init.PP"), Some(Language("Puppet", "puppet")));
+}
+
+#[cfg(feature = "std")]
+#[cfg(feature = "full")]
+#[test]
+fn query_and_shell_dialects() {
+    use crate::from_path;
+
+    assert_eq!(from_path("foo.sql"), Some(Language("SQL", "sql")));
+    assert_eq!(
+        from_path("foo.PSQL"),
+        Some(Language("PostgreSQL", "postgresql"))
+    );
+    assert_eq!(from_path("foo.mysql"), Some(Language("MySQL", "mysql")));
+    assert_eq!(from_path("foo.tsql"), Some(Language("T-SQL", "tsql")));
+    assert_eq!(from_path("foo.hql"), Some(Language("HiveQL", "hiveql")));
+    assert_eq!(
+        from_path("foo.cql"),
+        Some(Language("Cassandra Query Language", "cassandra-ql"))
+    );
+    assert_eq!(from_path("foo.sparql"), Some(Language("SPARQL", "sparql")));
+    assert_eq!(
+        from_path("schema.GraphQL"),
+        Some(Language("GraphQL", "graphql"))
+    );
+    assert_eq!(
+        from_path("build.ps1"),
+        Some(Language("PowerShell", "powershell"))
+    );
+    assert_eq!(from_path("config.nu"), Some(Language("Nushell", "nushell")));
+}
+
+#[cfg(feature = "std")]
+#[cfg(feature = "full")]
+#[test]
+fn tabular_formats() {
+    use crate::{from_path, Category};
+
+    assert_eq!(from_path("Data.CSV"), Some(Language("CSV", "csv")));
+    assert_eq!(from_path("data.tsv"), Some(Language("TSV", "tsv")));
+    assert_eq!(from_path("data.Psv"), Some(Language("PSV", "psv")));
+    assert_eq!(
+        from_path("data.PARQUET"),
+        Some(Language("Parquet", "parquet"))
+    );
+    assert_eq!(
+        from_path("data.feather"),
+        Some(Language("Feather", "feather"))
+    );
+    assert_eq!(from_path("Report.XLSX"), Some(Language("Excel", "excel")));
+    assert_eq!(from_path("report.xls"), Some(Language("Excel", "excel")));
+    assert_eq!(
+        from_path("report.ods"),
+        Some(Language("OpenDocument Spreadsheet", "ods"))
+    );
+
+    for ext in &[
+        "csv", "tsv", "psv", "parquet", "feather", "xlsx", "xls", "ods",
+    ] {
+        let lang = crate::from_extension(ext).unwrap();
+        assert_eq!(lang.category(), Some(Category::Data), "{}", ext);
+        let binary = !matches!(*ext, "csv" | "tsv" | "psv");
+        assert_eq!(lang.is_binary(), binary, "{}", ext);
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(feature = "full")]
+#[test]
+fn json_formats() {
+    use crate::{from_path, Category};
+
+    assert_eq!(from_path("config.JSON5"), Some(Language("JSON5", "json5")));
+    assert_eq!(
+        from_path("events.jsonl"),
+        Some(Language("JSON Lines", "json-lines"))
+    );
+    assert_eq!(
+        from_path("events.NDJSON"),
+        Some(Language("JSON Lines", "json-lines"))
+    );
+    assert_eq!(from_path("package.cson"), Some(Language("CSON", "cson")));
+    assert_eq!(from_path("config.Hjson"), Some(Language("Hjson", "hjson")));
+
+    for ext in &["json5", "jsonl", "ndjson", "cson", "hjson"] {
+        let lang = crate::from_extension(ext).unwrap();
+        assert_eq!(lang.category(), Some(Category::Data), "{}", ext);
+        assert!(!lang.is_binary(), "{}", ext);
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(feature = "full")]
+#[test]
+fn component_formats() {
+    use crate::from_path;
+
+    assert_eq!(from_path("App.vue"), Some(Language("Vue", "vue")));
+    assert_eq!(from_path("App.Svelte"), Some(Language("Svelte", "svelte")));
+    assert_eq!(from_path("index.astro"), Some(Language("Astro", "astro")));
+
+    let vue = from_path("App.vue").unwrap();
+    assert_eq!(vue.embedded_languages(), ["html", "javascript", "css"]);
+}
+
+#[cfg(feature = "std")]
+#[cfg(feature = "full")]
+#[test]
+fn game_formats() {
+    use crate::from_path;
+
+    assert_eq!(
+        from_path("build.gradle.kts"),
+        Some(Language("Kotlin", "kotlin"))
+    );
+    assert_eq!(
+        from_path("player.gd"),
+        Some(Language("GDScript", "gdscript"))
+    );
+    assert_eq!(
+        from_path("project.godot"),
+        Some(Language("Godot Resource", "godot-resource"))
+    );
+    assert_eq!(
+        from_path("Level.TSCN"),
+        Some(Language("Godot Resource", "godot-resource"))
+    );
+    assert_eq!(
+        from_path("theme.tres"),
+        Some(Language("Godot Resource", "godot-resource"))
+    );
+    assert_eq!(from_path("Main.unity"), Some(Language("YAML", "yaml")));
+    assert_eq!(from_path("Player.prefab"), Some(Language("YAML", "yaml")));
+    assert_eq!(
+        from_path("Water.shader"),
+        Some(Language("ShaderLab", "shaderlab"))
+    );
+    assert_eq!(
+        from_path("obj_player.gml"),
+        Some(Language("GameMaker Language", "gml"))
+    );
+}
+
+#[cfg(feature = "std")]
+#[cfg(feature = "full")]
+#[test]
+fn archive_formats() {
+    use crate::{detect_explained, from_path, DetectionSource};
+
+    assert_eq!(from_path("foo.gz"), Some(Language("Gzip", "gzip")));
+    assert_eq!(from_path("foo.ZST"), Some(Language("Zstandard", "zstd")));
+    assert_eq!(from_path("foo.zip"), Some(Language("ZIP", "zip")));
+    assert_eq!(from_path("foo.7z"), Some(Language("7-Zip", "7z")));
+    assert_eq!(from_path("foo.tar"), Some(Language("Tar", "tar")));
+
+    let tar_gz = Language("Gzipped Tar", "tar-gzip");
+    assert_eq!(from_path("foo.tar.gz"), Some(tar_gz));
+    assert_eq!(from_path("foo.TAR.GZ"), Some(tar_gz));
+    assert_eq!(from_path("foo.tgz"), Some(tar_gz));
+    assert_eq!(from_path(".tar.gz"), Some(Language("Gzip", "gzip")));
+    assert_eq!(
+        detect_explained("foo.tar.gz", None),
+        Some((tar_gz, DetectionSource::CompoundExtension))
+    );
+
+    for path in &["foo.gz", "foo.zst", "foo.tar.gz", "foo.rar"] {
+        let lang = from_path(path).unwrap();
+        assert!(lang.is_archive(), "{}", path);
+        assert!(lang.is_binary(), "{}", path);
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn case_folding() {
+    use crate::{from_extension, from_lowercase_extension};
+
+    assert_eq!(from_name("kOtLiN"), Some(Language("Kotlin", "kotlin")));
+    assert_eq!(from_name("c++"), Some(Language("C++", "cpp")));
+    // KELVIN SIGN lowercases to `k`
+    assert_eq!(
+        from_name("\u{212A}otlin"),
+        Some(Language("Kotlin", "kotlin"))
+    );
+    assert_eq!(from_name("Kotlin "), None);
+
+    // Extensions are only ASCII folded
+    assert_eq!(from_extension("KT"), Some(Language("Kotlin", "kotlin")));
+    assert_eq!(from_extension("\u{212A}t"), None);
+    assert_eq!(from_lowercase_extension("\u{212A}t"), None);
+}
@@ -68,6 +68,16 @@
 //! assert_eq!(from_extension("jSoN"), Some(Language("JSON", "json")));
 //! ```
 //!
+//! # Case Sensitivity
+//!
+//! Extensions and filenames are compared ignoring ASCII case only, as
+//! all built-in extensions and filenames are ASCII. Human-facing names,
+//! e.g. `linguist-language` in [`.gitattributes`], are compared using
+//! Unicode case folding instead, e.g. `KOTLIN` and `\u{212A}otlin`
+//! (starting with a KELVIN SIGN) are both Kotlin.
+//!
+//! [`.gitattributes`]: struct.GitattributesOverrides.html
+//!
 //! # Match Example
 //!
 //! ```
@@ -82,22 +92,292 @@
 //!     None                      => println!("Ehh, what?"),
 //! }
 //! ```
+//!
+//! # Features
+//!
+//! By default all languages are included, through the `full` feature.
+//! If binary size is a concern, then disable the default features and
+//! enable `common` instead, which only includes the following languages:
+//!
+//! C, C++, CSS, HTML, JavaScript, JSON, Markdown, Python, Rust, Shell,
+//! TOML, TypeScript, and YAML.
+//!
+//! ```toml
+//! [dependencies]
+//! detect-lang = { version = "0.1", default-features = false, features = ["std", "common"] }
+//! ```
+//!
+//! The API is the same regardless of the features, only fewer
+//! extensions are identified.
+//!
+//! The default `std` feature includes everything built on the standard
+//! library, e.g. paths, IO, and [`LanguageDetector`]. Without it, the crate
+//! is `no_std` and only requires `alloc`, while the tables and the lookups
+//! not involving paths remain available, e.g. [`from_extension`],
+//! [`from_filename`], [`from_content`], and [`Language::from_id`].
+//! Every other opt-in feature requires `std`.
+//!
+//! ```toml
+//! [dependencies]
+//! detect-lang = { version = "0.1", default-features = false, features = ["full"] }
+//! ```
+//!
+//! The opt-in `classifier` feature adds [`classify`], a small naive Bayes
+//! classifier for content without any other metadata, e.g. pasted snippets.
+//!
+//! The opt-in `tree-sitter` feature adds [`Language::tree_sitter_grammar`],
+//! the name of the [tree-sitter] grammar of the language.
+//!
+//! The opt-in `tokei` feature adds conversions to and from
+//! [`tokei::LanguageType`], through [`Language::tokei_type`] and
+//! [`from_tokei`].
+//!
+//! The opt-in `syntect` feature adds [`Language::find_syntax`], which
+//! finds the syntax of a language in a [syntect] `SyntaxSet`.
+//!
+//! The opt-in `ffi` feature adds the [`ffi`] module, with C bindings
+//! for linking non-Rust tooling against the same detection data.
+//!
+//! The opt-in `wasm` feature adds the [`wasm`] module, with [wasm-bindgen]
+//! bindings for detecting languages client-side, e.g. in web-based editors.
+//!
+//! The opt-in `rayon` feature adds [`scan_dir_par`] and [`from_paths_par`],
+//! which scan directories and identify paths across threads.
+//!
+//! The opt-in `ignore` feature makes [`scan_dir`] skip files ignored by
+//! `.gitignore` and `.ignore` files, using the [ignore] crate.
+//!
+//! The opt-in `cli` feature builds a `detect-lang` binary, which prints
+//! the languages of paths given as arguments or read from stdin.
+//!
+//! The opt-in `jupyter` feature adds [`from_notebook`], and makes
+//! `.ipynb` files with content be identified by the language of the
+//! kernel of the notebook, e.g. Python, instead of Jupyter Notebook.
+//!
+//! [`classify`]: fn.classify.html
+//! [`from_content`]: fn.from_content.html
+//! [`from_extension`]: fn.from_extension.html
+//! [`from_filename`]: fn.from_filename.html
+//! [`Language::from_id`]: struct.Language.html#method.from_id
+//! [`LanguageDetector`]: struct.LanguageDetector.html
+//! [`ffi`]: ffi/index.html
+//! [`from_notebook`]: fn.from_notebook.html
+//! [`from_paths_par`]: fn.from_paths_par.html
+//! [`scan_dir_par`]: fn.scan_dir_par.html
+//! [`scan_dir`]: fn.scan_dir.html
+//! [ignore]: https://docs.rs/ignore
+//! [`wasm`]: wasm/index.html
+//! [wasm-bindgen]: https://docs.rs/wasm-bindgen
+//! [`Language::find_syntax`]: struct.Language.html#method.find_syntax
+//! [syntect]: https://docs.rs/syntect
+//! [`tokei::LanguageType`]: https://docs.rs/tokei/12/tokei/enum.LanguageType.html
+//! [`Language::tokei_type`]: struct.Language.html#method.tokei_type
+//! [`from_tokei`]: fn.from_tokei.html
+//! [`Language::tree_sitter_grammar`]: struct.Language.html#method.tree_sitter_grammar
+//! [tree-sitter]: https://tree-sitter.github.io/tree-sitter/
 
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(not(feature = "ffi"), forbid(unsafe_code))]
+#![cfg_attr(feature = "ffi", deny(unsafe_code))]
 #![deny(missing_docs)]
 // #![deny(missing_doc_code_examples)]
 #![deny(missing_debug_implementations)]
 #![warn(clippy::all)]
 
+extern crate alloc;
+
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::Deref;
+#[cfg(feature = "std")]
 use std::ffi::OsStr;
-use std::ops::Deref;
+#[cfg(feature = "std")]
 use std::path::Path;
 
+mod aliases;
+mod binary;
+#[cfg(feature = "std")]
+mod breakdown;
+#[cfg(feature = "std")]
+mod build_system;
+#[cfg(feature = "std")]
+mod builder;
+mod candidates;
+mod category;
+#[cfg(feature = "classifier")]
+mod classifier;
+mod color;
+mod compound;
+#[cfg(feature = "config")]
+#[cfg(feature = "std")]
+mod config;
+mod content;
+#[cfg(feature = "std")]
+mod database;
+#[cfg(feature = "std")]
+mod detector;
+mod embedded;
+#[cfg(feature = "std")]
+mod explain;
+mod fence;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+mod file_mime;
+mod filenames;
+#[cfg(feature = "std")]
+mod gitattributes;
+#[cfg(feature = "std")]
+mod glob;
+#[cfg(feature = "std")]
+mod heuristics;
+mod highlight;
+mod icon;
+#[cfg(feature = "jupyter")]
+mod jupyter;
+mod language_id;
 mod languages;
+mod linguist;
+mod lsp;
+mod magic;
+mod mime;
+mod modeline;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod parse;
+mod policy;
+mod preferred;
+#[cfg(feature = "std")]
+mod project;
+mod pygments;
+#[cfg(feature = "std")]
+mod reader;
+#[cfg(feature = "tokio")]
+mod reader_async;
+#[cfg(feature = "std")]
+mod registry;
+mod shebang;
+#[cfg(feature = "std")]
+mod stats;
+#[cfg(feature = "syntect")]
+mod syntect;
+#[cfg(all(test, feature = "std"))]
+mod test_util;
+mod textmate;
+#[cfg(feature = "tokei")]
+mod tokei;
+#[cfg(feature = "tree-sitter")]
+mod tree_sitter;
+mod validate;
+#[cfg(feature = "std")]
+mod vendor;
+#[cfg(feature = "tokio")]
+mod walk_async;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+mod xml;
 
+#[cfg(feature = "std")]
+use builder::DEFAULT_DETECTOR;
+use filenames::FILENAMES;
 use languages::LANGUAGES;
 
+pub use aliases::{canonical_id, from_name_or_alias};
+pub use binary::{is_binary, is_binary_extension};
+#[cfg(feature = "std")]
+pub use breakdown::{breakdown_by_bytes, breakdown_by_bytes_with};
+#[cfg(feature = "std")]
+pub use build_system::{build_systems, from_build_file, BuildSystem};
+#[cfg(feature = "std")]
+pub use builder::{LanguageDetector, LanguageDetectorBuilder};
+#[cfg(feature = "std")]
+pub use candidates::from_path_all;
+pub use candidates::{ambiguous_extensions, candidates, from_extension_all, is_ambiguous};
+pub use category::{Category, Role};
+#[cfg(feature = "classifier")]
+pub use classifier::classify;
+#[cfg(feature = "config")]
+pub use config::ConfigError;
+pub use content::{from_content, MAX_CONTENT_LEN};
+#[cfg(feature = "std")]
+pub use database::{database_fingerprint, DATABASE_VERSION};
+#[cfg(feature = "std")]
+pub use detector::{
+    ChainDetector, ContentDetector, DetectContext, Detector, ExtensionDetector, FilenameDetector,
+    ShebangDetector,
+};
+#[cfg(feature = "std")]
+pub use explain::{detect, detect_explained, Detection, DetectionSource};
+pub use fence::from_code_fence;
+pub use file_mime::from_file_mime;
+#[cfg(feature = "std")]
+pub use filenames::filenames_for;
+pub use filenames::from_filename;
+#[cfg(feature = "std")]
+pub use gitattributes::{detect_with_gitattributes, GitattributesOverrides};
+#[cfg(feature = "jupyter")]
+pub use jupyter::from_notebook;
+pub use language_id::{consts, LanguageId};
+#[cfg(feature = "std")]
+pub use languages::extensions_for;
+pub use languages::{extensions, languages};
+pub use magic::from_magic_bytes;
+pub use mime::from_mime;
+pub use modeline::from_modeline;
+#[cfg(feature = "rayon")]
+pub use parallel::{from_paths_par, scan_dir_par};
+pub use parse::ParseLanguageError;
+pub use policy::{from_extension_with_policy, ResolutionPolicy};
+#[cfg(feature = "std")]
+pub use project::{from_manifest, project_languages};
+#[cfg(feature = "std")]
+pub use reader::{from_reader, MAX_SNIFF_LEN};
+#[cfg(feature = "tokio")]
+pub use reader_async::{detect_path_async, from_async_reader};
+#[cfg(feature = "serde")]
+pub use registry::LoadError;
+#[cfg(feature = "std")]
+pub use registry::Registry;
+pub use shebang::{from_interpreter, from_shebang, from_shebang_bytes};
+#[cfg(feature = "std")]
+pub use stats::{scan_dir, FileStats, LanguageStats, PercentageOptions};
+pub use textmate::from_textmate_scope;
+#[cfg(feature = "tokei")]
+pub use tokei::from_tokei;
+pub use validate::LanguageValidationError;
+#[cfg(feature = "std")]
+pub use vendor::{detect_generated, is_documentation, is_generated, is_test_path, is_vendored};
+#[cfg(feature = "tokio")]
+pub use walk_async::{
+    walk_and_detect_async, walk_and_detect_async_with_concurrency, DEFAULT_CONCURRENCY,
+};
+pub use xml::from_xml_root;
+
 /// Languages contain a name and an ID (`Language(name, id)`).
+///
+/// # Pointer Stability
+///
+/// The name and ID of languages returned by the built-in lookups are
+/// `&'static str` pointing into static tables. Identifying a language
+/// from the same extension (or filename) always returns the same pointers,
+/// which makes it possible to compare them using [`std::ptr::eq`] instead
+/// of comparing the strings.
+///
+/// However, different extensions of the same language are not guaranteed
+/// to return the same pointers, e.g. `cpp` and `hpp`.
+///
+/// [`std::ptr::eq`]: https://doc.rust-lang.org/std/ptr/fn.eq.html
+///
+/// ```
+/// # use detect_lang::{from_extension, from_path};
+/// use std::ptr;
+///
+/// let a = from_extension("rs").unwrap();
+/// let b = from_path("foo.RS").unwrap();
+/// assert!(ptr::eq(a.name(), b.name()));
+/// assert!(ptr::eq(a.id(), b.id()));
+/// ```
 #[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
 pub struct Language<'a>(pub &'a str, pub &'a str);
 
@@ -148,6 +428,633 @@ impl<'a> Language<'a> {
     pub fn id(&self) -> &'a str {
         self.1
     }
+
+    /// Returns the [`LanguageId`] of the language, for matching
+    /// languages without comparing strings.
+    /// Returns `None` if the language is unknown.
+    ///
+    /// [`LanguageId`]: enum.LanguageId.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_path, Language, LanguageId};
+    /// assert_eq!(from_path("foo.rs").unwrap().lang_id(), Some(LanguageId::Rust));
+    /// assert_eq!(from_path("foo.cpp").unwrap().lang_id(), Some(LanguageId::Cpp));
+    /// assert_eq!(Language("Foo", "foo").lang_id(), None);
+    /// ```
+    #[inline]
+    pub fn lang_id(&self) -> Option<LanguageId> {
+        LanguageId::from_id(self.id())
+    }
+
+    /// Returns glob patterns matching files of the language, e.g. for
+    /// generating editor configuration. This includes well-known
+    /// filenames, e.g. `Makefile`, followed by extensions, e.g. `*.mk`.
+    ///
+    /// Returns an empty `Vec` if the language is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert_eq!(from_extension("rs").unwrap().globs(), ["*.rs"]);
+    /// assert_eq!(from_extension("mk").unwrap().globs(), ["GNUmakefile", "Makefile", "*.mak", "*.mk"]);
+    ///
+    /// assert!(from_extension("yml").unwrap().globs().contains(&"*.yaml".to_string()));
+    /// assert!(Language("Foo", "foo").globs().is_empty());
+    /// # }
+    /// ```
+    pub fn globs(&self) -> Vec<String> {
+        let filenames = FILENAMES
+            .iter()
+            .filter(|(_, lang)| lang.id() == self.id())
+            .map(|&(filename, _)| filename.to_owned());
+        let extensions = LANGUAGES
+            .iter()
+            .filter(|(_, lang)| lang.id() == self.id())
+            .map(|(ext, _)| format!("*.{}", ext));
+        filenames.chain(extensions).collect()
+    }
+
+    #[cfg(feature = "std")]
+    /// Returns the extensions of the language, sorted and without a
+    /// leading `.`. Returns an empty slice if the language is unknown.
+    ///
+    /// *[See also `extensions_for`][extensions_for].*
+    ///
+    /// [extensions_for]: fn.extensions_for.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// assert_eq!(from_extension("rs").unwrap().extensions(), ["rs"]);
+    /// assert_eq!(from_extension("toml").unwrap().extensions(), ["toml"]);
+    /// assert!(from_extension("yml").unwrap().extensions().contains(&"yaml"));
+    /// assert!(Language("Foo", "foo").extensions().is_empty());
+    /// ```
+    #[inline]
+    pub fn extensions(&self) -> &'static [&'static str] {
+        languages::extensions_for(self.id())
+    }
+
+    #[cfg(feature = "std")]
+    /// Returns the well-known filenames of the language, e.g. `Makefile`.
+    /// Returns an empty slice if the language is unknown.
+    ///
+    /// *[See also `filenames_for`][filenames_for].*
+    ///
+    /// [filenames_for]: fn.filenames_for.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert_eq!(from_extension("mk").unwrap().filenames(), ["GNUmakefile", "Makefile"]);
+    /// assert!(from_extension("rs").unwrap().filenames().is_empty());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn filenames(&self) -> &'static [&'static str] {
+        filenames::filenames_for(self.id())
+    }
+
+    /// Returns `true` if the language is `Language("System File", "system-file")`,
+    /// i.e. operating system metadata such as `.DS_Store`, `Thumbs.db`,
+    /// `desktop.ini`, and `.lnk` shortcuts.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::from_path;
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert!(from_path(".DS_Store").unwrap().is_system_file());
+    /// assert!(from_path("foo/.ds_store").unwrap().is_system_file());
+    /// assert!(from_path("desktop.ini").unwrap().is_system_file());
+    /// assert!(from_path("Thumbs.db").unwrap().is_system_file());
+    /// assert!(from_path("foo.lnk").unwrap().is_system_file());
+    ///
+    /// assert!(!from_path("foo.ini").unwrap().is_system_file());
+    ///
+    /// // Skipping system files when walking directories
+    /// let is_system_file = |p| from_path(p).map_or(false, |l| l.is_system_file());
+    /// assert!(is_system_file(".DS_Store"));
+    /// assert!(!is_system_file("foo.rs"));
+    /// assert!(!is_system_file("foo"));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_system_file(&self) -> bool {
+        self.id() == "system-file"
+    }
+
+    /// Checks that the language follows the conventions of the built-in
+    /// languages, i.e. the name is not empty and the ID is a lowercase
+    /// [URL slug], only containing lowercase ASCII letters, ASCII digits,
+    /// and `-`.
+    ///
+    /// All built-in languages are valid.
+    ///
+    /// [URL slug]: https://en.wikipedia.org/wiki/Clean_URL#Slug
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{Language, LanguageValidationError};
+    /// assert_eq!(Language("KiCad", "kicad").validate(), Ok(()));
+    /// assert_eq!(Language("", "kicad").validate(), Err(LanguageValidationError::EmptyName));
+    /// assert_eq!(Language("KiCad", "").validate(), Err(LanguageValidationError::EmptyId));
+    /// assert_eq!(
+    ///     Language("KiCad", "KiCad Schematic").validate(),
+    ///     Err(LanguageValidationError::InvalidId { id: "KiCad Schematic".to_owned() }),
+    /// );
+    /// ```
+    #[inline]
+    pub fn validate(&self) -> Result<(), LanguageValidationError> {
+        validate::validate(self)
+    }
+
+    /// Returns `true` if the language is an archive or compression format,
+    /// e.g. ZIP and Gzip.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_path, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert!(from_path("foo.zip").unwrap().is_archive());
+    /// assert!(from_path("foo.gz").unwrap().is_archive());
+    /// assert!(from_path("foo.tar.gz").unwrap().is_archive());
+    ///
+    /// assert!(!from_path("foo.rs").unwrap().is_archive());
+    /// assert!(!Language("Foo", "foo").is_archive());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_archive(&self) -> bool {
+        self.category() == Some(Category::Archive)
+    }
+
+    /// Returns `true` if the language is a programming language, e.g. Rust
+    /// and Python, as opposed to markup, data, and prose, e.g. HTML, JSON,
+    /// and Markdown. Returns `false` if the language is unknown.
+    ///
+    /// This is useful for excluding non-code from code statistics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_path, Language};
+    /// assert!(from_path("foo.rs").unwrap().is_programming());
+    /// assert!(from_path("foo.py").unwrap().is_programming());
+    ///
+    /// assert!(!from_path("foo.json").unwrap().is_programming());
+    /// assert!(!from_path("foo.yaml").unwrap().is_programming());
+    /// assert!(!from_path("foo.md").unwrap().is_programming());
+    /// assert!(!Language("Foo", "foo").is_programming());
+    /// ```
+    #[inline]
+    pub fn is_programming(&self) -> bool {
+        self.category() == Some(Category::Programming)
+    }
+
+    /// Returns `true` if files of the language are binary, e.g. Parquet
+    /// and Excel spreadsheets. Returns `false` if the language is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_path, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert!(from_path("data.parquet").unwrap().is_binary());
+    /// assert!(from_path("report.xlsx").unwrap().is_binary());
+    ///
+    /// assert!(!from_path("data.csv").unwrap().is_binary());
+    /// assert!(!from_path("foo.rs").unwrap().is_binary());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn is_binary(&self) -> bool {
+        binary::is_binary_language(self.id())
+    }
+
+    /// Returns the recommended extension when writing a file of the language,
+    /// e.g. `rs` for Rust and `yaml` for YAML (instead of `yml`).
+    /// Returns `None` if the language is unknown or is not authored,
+    /// e.g. [system files].
+    ///
+    /// C and C++ headers are not considered, i.e. C is `c` (not `h`) and
+    /// C++ is `cpp` (not `hpp`).
+    ///
+    /// [system files]: #method.is_system_file
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert_eq!(from_extension("rs").unwrap().preferred_extension(), Some("rs"));
+    /// assert_eq!(from_extension("yml").unwrap().preferred_extension(), Some("yaml"));
+    /// assert_eq!(from_extension("h").unwrap().preferred_extension(), Some("c"));
+    /// assert_eq!(from_extension("hpp").unwrap().preferred_extension(), Some("cpp"));
+    /// assert_eq!(from_extension("cc").unwrap().preferred_extension(), Some("cpp"));
+    ///
+    /// assert_eq!(from_extension("lnk").unwrap().preferred_extension(), None);
+    /// assert_eq!(Language("Foo", "foo").preferred_extension(), None);
+    /// # }
+    /// ```
+    pub fn preferred_extension(&self) -> Option<&'static str> {
+        if self.is_system_file() {
+            return None;
+        }
+        preferred::preferred_extension(self.id())
+    }
+
+    /// Returns the [TextMate scope] of the language's grammar,
+    /// e.g. `source.rust` for Rust and `text.html.markdown` for Markdown.
+    /// Returns `None` if the language is unknown.
+    ///
+    /// *[See also `from_textmate_scope`][from_textmate_scope].*
+    ///
+    /// [TextMate scope]: https://macromates.com/manual/en/language_grammars#naming_conventions
+    /// [from_textmate_scope]: fn.from_textmate_scope.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, from_textmate_scope, Language};
+    /// assert_eq!(from_extension("rs").unwrap().textmate_scope(), Some("source.rust"));
+    /// assert_eq!(from_extension("md").unwrap().textmate_scope(), Some("text.html.markdown"));
+    /// assert_eq!(Language("Foo", "foo").textmate_scope(), None);
+    ///
+    /// let lang = from_extension("cpp").unwrap();
+    /// assert_eq!(from_textmate_scope(lang.textmate_scope().unwrap()), Some(lang));
+    /// ```
+    #[inline]
+    pub fn textmate_scope(&self) -> Option<&'static str> {
+        textmate::textmate_scope(self.id())
+    }
+
+    /// Returns the numeric ID of the language in [GitHub Linguist],
+    /// as used by e.g. GitHub's API, such as `327` for Rust.
+    /// Returns `None` if the language is unknown or not in Linguist.
+    ///
+    /// Unlike names, the numeric IDs are stable in Linguist.
+    ///
+    /// [GitHub Linguist]: https://github.com/github/linguist/blob/master/lib/linguist/languages.yml
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// assert_eq!(from_extension("rs").unwrap().linguist_id(), Some(327));
+    /// assert_eq!(from_extension("py").unwrap().linguist_id(), Some(303));
+    /// assert_eq!(Language("Foo", "foo").linguist_id(), None);
+    /// ```
+    #[inline]
+    pub fn linguist_id(&self) -> Option<u32> {
+        linguist::linguist_id(self.id())
+    }
+
+    /// Returns the [Language Server Protocol] language identifier,
+    /// e.g. `shellscript` for Shell and `latex` for TeX.
+    /// Returns `None` if the language is unknown or has no identifier.
+    ///
+    /// The identifiers differ from the [ID](#method.id) of languages
+    /// in several cases, e.g. `bat` for Batch and `perl6` for Raku.
+    ///
+    /// [Language Server Protocol]: https://microsoft.github.io/language-server-protocol/specifications/lsp/3.17/specification/#textDocumentItem
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert_eq!(from_extension("rs").unwrap().lsp_id(), Some("rust"));
+    /// assert_eq!(from_extension("cs").unwrap().lsp_id(), Some("csharp"));
+    /// assert_eq!(from_extension("sh").unwrap().lsp_id(), Some("shellscript"));
+    /// assert_eq!(from_extension("m").unwrap().lsp_id(), Some("objective-c"));
+    /// assert_eq!(Language("Foo", "foo").lsp_id(), None);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn lsp_id(&self) -> Option<&'static str> {
+        lsp::lsp_id(self.id())
+    }
+
+    /// Returns the short name of the [Pygments] lexer of the language,
+    /// as accepted by `pygments.lexers.get_lexer_by_name`, e.g. `bash`
+    /// for Shell. Returns `None` if the language is unknown or has no lexer.
+    ///
+    /// [Pygments]: https://pygments.org/docs/lexers/
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert_eq!(from_extension("rs").unwrap().pygments_lexer(), Some("rust"));
+    /// assert_eq!(from_extension("sh").unwrap().pygments_lexer(), Some("bash"));
+    /// assert_eq!(from_extension("rst").unwrap().pygments_lexer(), Some("rst"));
+    /// assert_eq!(Language("Foo", "foo").pygments_lexer(), None);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn pygments_lexer(&self) -> Option<&'static str> {
+        pygments::pygments_lexer(self.id())
+    }
+
+    /// Returns the [highlight.js] name of the language, as used in
+    /// `language-*` CSS classes, e.g. `cpp` for C++ and `bash` for Shell.
+    /// Returns `None` if the language is unknown or not supported.
+    ///
+    /// *[See also `prism_name`][prism_name].*
+    ///
+    /// [highlight.js]: https://highlightjs.org/
+    /// [prism_name]: #method.prism_name
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert_eq!(from_extension("cpp").unwrap().hljs_name(), Some("cpp"));
+    /// assert_eq!(from_extension("sh").unwrap().hljs_name(), Some("bash"));
+    /// assert_eq!(from_extension("m").unwrap().hljs_name(), Some("objectivec"));
+    /// assert_eq!(Language("Foo", "foo").hljs_name(), None);
+    ///
+    /// let lang = from_extension("rs").unwrap();
+    /// let class = format!("language-{}", lang.hljs_name().unwrap());
+    /// assert_eq!(class, "language-rust");
+    /// # }
+    /// ```
+    #[inline]
+    pub fn hljs_name(&self) -> Option<&'static str> {
+        highlight::hljs_name(self.id())
+    }
+
+    /// Returns the [Prism] name of the language, as used in
+    /// `language-*` CSS classes, e.g. `cpp` for C++ and `bash` for Shell.
+    /// Returns `None` if the language is unknown or not supported.
+    ///
+    /// *[See also `hljs_name`][hljs_name].*
+    ///
+    /// [Prism]: https://prismjs.com/
+    /// [hljs_name]: #method.hljs_name
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, from_path, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert_eq!(from_extension("cpp").unwrap().prism_name(), Some("cpp"));
+    /// assert_eq!(from_extension("sh").unwrap().prism_name(), Some("bash"));
+    /// assert_eq!(from_path("Dockerfile").unwrap().prism_name(), Some("docker"));
+    /// assert_eq!(Language("Foo", "foo").prism_name(), None);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn prism_name(&self) -> Option<&'static str> {
+        highlight::prism_name(self.id())
+    }
+
+    /// Returns the name of the [tree-sitter] grammar of the language,
+    /// e.g. `rust` for Rust and `c_sharp` for C#, as used by the
+    /// `tree-sitter-<grammar>` crates.
+    /// Returns `None` if the language is unknown or has no grammar.
+    ///
+    /// *This function requires the `tree-sitter` feature.*
+    ///
+    /// [tree-sitter]: https://tree-sitter.github.io/tree-sitter/
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// assert_eq!(from_extension("rs").unwrap().tree_sitter_grammar(), Some("rust"));
+    /// assert_eq!(from_extension("cs").unwrap().tree_sitter_grammar(), Some("c_sharp"));
+    /// assert_eq!(from_extension("sh").unwrap().tree_sitter_grammar(), Some("bash"));
+    /// assert_eq!(Language("Foo", "foo").tree_sitter_grammar(), None);
+    /// ```
+    #[cfg(feature = "tree-sitter")]
+    #[inline]
+    pub fn tree_sitter_grammar(&self) -> Option<&'static str> {
+        tree_sitter::tree_sitter_grammar(self.id())
+    }
+
+    /// Returns the name of the [Devicon] icon of the language, e.g. `rust`
+    /// for Rust and `cplusplus` for C++, which is used in class names
+    /// such as `devicon-rust-plain`.
+    /// Returns `None` if the language is unknown or has no icon.
+    ///
+    /// [Devicon]: https://devicon.dev/
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// assert_eq!(from_extension("rs").unwrap().icon(), Some("rust"));
+    /// assert_eq!(from_extension("cpp").unwrap().icon(), Some("cplusplus"));
+    /// assert_eq!(from_extension("sh").unwrap().icon(), Some("bash"));
+    /// assert_eq!(Language("Foo", "foo").icon(), None);
+    /// ```
+    #[inline]
+    pub fn icon(&self) -> Option<&'static str> {
+        icon::icon(self.id())
+    }
+
+    /// Returns the MIME type of the language, e.g. for the `Content-Type`
+    /// of an HTTP response, such as `text/x-rust` for Rust.
+    /// Returns `None` if the language is unknown or has no MIME type.
+    ///
+    /// *[See also `from_mime`][from_mime].*
+    ///
+    /// [from_mime]: fn.from_mime.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// assert_eq!(from_extension("rs").unwrap().mime(), Some("text/x-rust"));
+    /// assert_eq!(from_extension("json").unwrap().mime(), Some("application/json"));
+    /// assert_eq!(from_extension("html").unwrap().mime(), Some("text/html"));
+    /// assert_eq!(Language("Foo", "foo").mime(), None);
+    /// ```
+    #[inline]
+    pub fn mime(&self) -> Option<&'static str> {
+        mime::mime(self.id())
+    }
+
+    /// Returns the color of the language as used by [GitHub Linguist],
+    /// given as a lowercase `#rrggbb` hex color, e.g. `#dea584` for Rust.
+    /// Returns `None` if the language is unknown or has no color,
+    /// e.g. archive formats.
+    ///
+    /// [GitHub Linguist]: https://github.com/github/linguist
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// assert_eq!(from_extension("rs").unwrap().color(), Some("#dea584"));
+    /// assert_eq!(from_extension("py").unwrap().color(), Some("#3572a5"));
+    /// assert_eq!(from_extension("ts").unwrap().color(), Some("#3178c6"));
+    /// assert_eq!(Language("Foo", "foo").color(), None);
+    /// ```
+    #[inline]
+    pub fn color(&self) -> Option<&'static str> {
+        color::color(self.id())
+    }
+
+    /// Returns the IDs of the languages typically embedded in files of the
+    /// language, e.g. single-file components such as Vue, where `<template>`
+    /// is HTML, `<script>` is JavaScript, and `<style>` is CSS.
+    /// Returns an empty slice if the language is not a composite format.
+    ///
+    /// The files are not parsed, so e.g. a Vue component using
+    /// `<script lang="ts">` still returns `javascript`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert_eq!(from_extension("vue").unwrap().embedded_languages(), ["html", "javascript", "css"]);
+    /// assert_eq!(from_extension("svelte").unwrap().embedded_languages(), ["html", "javascript", "css"]);
+    /// assert_eq!(from_extension("astro").unwrap().embedded_languages(), ["html", "typescript", "css"]);
+    ///
+    /// assert!(from_extension("rs").unwrap().embedded_languages().is_empty());
+    /// # }
+    /// ```
+    #[inline]
+    pub fn embedded_languages(&self) -> &'static [&'static str] {
+        embedded::embedded_languages(self.id())
+    }
+
+    /// Returns the [category] of the language.
+    /// Returns `None` if the language is unknown.
+    ///
+    /// [category]: enum.Category.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Category, Language};
+    /// assert_eq!(from_extension("rs").unwrap().category(), Some(Category::Programming));
+    /// assert_eq!(from_extension("html").unwrap().category(), Some(Category::Markup));
+    /// assert_eq!(from_extension("json").unwrap().category(), Some(Category::Data));
+    /// assert_eq!(from_extension("md").unwrap().category(), Some(Category::Prose));
+    /// assert_eq!(Language("Foo", "foo").category(), None);
+    /// ```
+    #[inline]
+    pub fn category(&self) -> Option<Category> {
+        category::category(self.id())
+    }
+
+    /// Returns the [role] of files of the language within a repository.
+    /// Returns `None` if the language is unknown.
+    ///
+    /// The role is derived from the [category]:
+    ///
+    /// | Category      | Role            | Except                                      |
+    /// |---------------|-----------------|---------------------------------------------|
+    /// | `Programming` | `Source`        | HCL and Terraform are `Configuration`       |
+    /// | `Markup`      | `Source`        | TeX and BibTeX are `Documentation`          |
+    /// | `Data`        | `Data`          | TOML, INI, YAML, JSON with Comments, JSON5, |
+    /// |               |                 | CSON, Hjson, and Git Config are             |
+    /// |               |                 | `Configuration`,                            |
+    /// |               |                 | and SVG is an `Asset`                       |
+    /// | `Prose`       | `Documentation` |                                             |
+    /// | `Archive`     | `Other`         |                                             |
+    /// | `Other`       | `Other`         |                                             |
+    ///
+    /// [role]: enum.Role.html
+    /// [category]: #method.category
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_path, Language, Role};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert_eq!(from_path("foo.rs").unwrap().role(), Some(Role::Source));
+    /// assert_eq!(from_path("README.md").unwrap().role(), Some(Role::Documentation));
+    /// assert_eq!(from_path("Cargo.toml").unwrap().role(), Some(Role::Configuration));
+    /// assert_eq!(from_path("foo.json").unwrap().role(), Some(Role::Data));
+    /// assert_eq!(from_path("logo.svg").unwrap().role(), Some(Role::Asset));
+    /// assert_eq!(from_path(".DS_Store").unwrap().role(), Some(Role::Other));
+    /// assert_eq!(Language("Foo", "foo").role(), None);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn role(&self) -> Option<Role> {
+        category::role(self)
+    }
+}
+
+impl Language<'static> {
+    /// Finds a built-in language by its ID, e.g. `cpp`.
+    /// Returns `None` if the ID is unknown.
+    ///
+    /// The ID is matched exactly, i.e. the case is not ignored.
+    /// [Aliases] of IDs are also accepted, e.g. `c++`.
+    ///
+    /// [Aliases]: fn.canonical_id.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::Language;
+    /// assert_eq!(Language::from_id("cpp"), Some(Language("C++", "cpp")));
+    /// assert_eq!(Language::from_id("c++"), Some(Language("C++", "cpp")));
+    /// assert_eq!(Language::from_id("rust"), Some(Language("Rust", "rust")));
+    ///
+    /// assert_eq!(Language::from_id("Rust"), None);
+    /// assert_eq!(Language::from_id("unknown"), None);
+    /// ```
+    pub fn from_id(id: &str) -> Option<Self> {
+        languages::from_id(canonical_id(id)?)
+    }
+
+    /// Finds a built-in language by its name, e.g. `C++`, ignoring case.
+    /// Returns `None` if the name is unknown.
+    ///
+    /// *[See also `from_name_or_alias`][from_name_or_alias].*
+    ///
+    /// [from_name_or_alias]: fn.from_name_or_alias.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::Language;
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// assert_eq!(Language::from_name("C++"), Some(Language("C++", "cpp")));
+    /// assert_eq!(Language::from_name("rust"), Some(Language("Rust", "rust")));
+    /// assert_eq!(Language::from_name("Objective-C"), Some(Language("Objective-C", "objective-c")));
+    ///
+    /// assert_eq!(Language::from_name("cpp"), None);
+    /// assert_eq!(Language::from_name("unknown"), None);
+    /// # }
+    /// ```
+    #[inline]
+    pub fn from_name(name: &str) -> Option<Self> {
+        languages::from_name(name)
+    }
 }
 
 impl<'a> Deref for Language<'a> {
@@ -159,10 +1066,16 @@ impl<'a> Deref for Language<'a> {
     }
 }
 
+#[cfg(feature = "std")]
 /// Identifies a language from a [path extension].
 /// The casing of the extension does not affect the result.
 /// Returns `None` if the language was not identified.
 ///
+/// Well-known filenames, e.g. `.DS_Store` and `desktop.ini`, followed
+/// by multi-part extensions, e.g. `tar.gz`, are checked before the extension.
+/// If the extension is unknown, then well-known filenames followed by a
+/// qualifier are checked last, e.g. `Dockerfile.prod`.
+///
 /// Note that `from_path` does not check if the path exists,
 /// nor does it attempt to load the file.
 ///
@@ -175,6 +1088,8 @@ impl<'a> Deref for Language<'a> {
 ///
 /// ```
 /// # use detect_lang::{from_path, Language};
+/// # #[cfg(feature = "full")]
+/// # {
 /// assert_eq!(from_path("foo.rs"), Some(Language("Rust", "rust")));
 /// assert_eq!(from_path("foo.md"), Some(Language("Markdown", "markdown")));
 /// assert_eq!(from_path("foo.cpp"), Some(Language("C++", "cpp")));
@@ -182,6 +1097,13 @@ impl<'a> Deref for Language<'a> {
 ///
 /// // The case is ignored
 /// assert_eq!(from_path("foo.jSoN"), Some(Language("JSON", "json")));
+///
+/// // Well-known filenames
+/// assert_eq!(from_path("some/dir/Makefile"), Some(Language("Make", "make")));
+/// assert_eq!(from_path("Dockerfile"), Some(Language("Dockerfile", "dockerfile")));
+/// assert_eq!(from_path("Dockerfile.prod"), Some(Language("Dockerfile", "dockerfile")));
+/// assert_eq!(from_path("Makefile.linux"), Some(Language("Make", "make")));
+/// # }
 /// ```
 ///
 /// # Unsupported Language
@@ -195,11 +1117,334 @@ impl<'a> Deref for Language<'a> {
 /// [languages.rs]: https://github.com/vallentin/detect-lang/blob/master/src/languages.rs
 #[inline]
 pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Language<'static>> {
-    if let Some(Some(ext)) = path.as_ref().extension().map(OsStr::to_str) {
-        from_extension(ext)
-    } else {
-        None
+    DEFAULT_DETECTOR.from_path(path)
+}
+
+#[cfg(feature = "std")]
+/// Identifies the language of each path, the same as [`from_path`].
+/// The result is in the same order as `paths`.
+///
+/// Each distinct extension is only lowercased and resolved once,
+/// which is faster than calling [`from_path`] for each path when
+/// many paths share the same extensions, e.g. from a file index.
+///
+/// [`from_path`]: fn.from_path.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{detect_paths, Language};
+/// # use std::path::PathBuf;
+/// # #[cfg(feature = "full")]
+/// # {
+/// let paths = vec![
+///     PathBuf::from("src/main.rs"),
+///     PathBuf::from("src/lib.rs"),
+///     PathBuf::from("Makefile"),
+///     PathBuf::from("foo.unknown"),
+/// ];
+/// assert_eq!(
+///     detect_paths(paths),
+///     [
+///         Some(Language("Rust", "rust")),
+///         Some(Language("Rust", "rust")),
+///         Some(Language("Make", "make")),
+///         None,
+///     ]
+/// );
+/// # }
+/// ```
+#[inline]
+pub fn detect_paths<I>(paths: I) -> Vec<Option<Language<'static>>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<Path>,
+{
+    DEFAULT_DETECTOR.detect_paths(paths)
+}
+
+#[cfg(feature = "std")]
+/// Returns the [ID] of the language identified by [`from_path`], or otherwise
+/// the lowercase extension of the path, e.g. `xyz` for `foo.xyz`.
+/// Returns `None` if the language was not identified and the path has
+/// no extension.
+///
+/// Note that the fallback is the raw extension, and not necessarily
+/// the ID of any language.
+///
+/// [ID]: struct.Language.html#method.id
+/// [`from_path`]: fn.from_path.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::id_or_extension;
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(id_or_extension("foo.rs").as_deref(), Some("rust"));
+/// assert_eq!(id_or_extension("foo.hpp").as_deref(), Some("cpp"));
+/// assert_eq!(id_or_extension("Makefile").as_deref(), Some("make"));
+///
+/// // Unknown extensions
+/// assert_eq!(id_or_extension("foo.xyz").as_deref(), Some("xyz"));
+/// assert_eq!(id_or_extension("foo.XyZ").as_deref(), Some("xyz"));
+///
+/// // No extension
+/// assert_eq!(id_or_extension("foo"), None);
+/// # }
+/// ```
+pub fn id_or_extension<P: AsRef<Path>>(path: P) -> Option<String> {
+    let path = path.as_ref();
+    match from_path(path) {
+        Some(lang) => Some(lang.id().to_owned()),
+        None => path
+            .extension()
+            .map(|ext| ext.to_string_lossy().to_ascii_lowercase()),
+    }
+}
+
+#[cfg(feature = "std")]
+/// Identifies a language from a path and the content of the file.
+/// Returns `None` if the language was not identified.
+///
+/// The path is checked first, as by [`from_path`], except that the
+/// content is used to disambiguate extensions mapped to multiple languages,
+/// e.g. `h` for C, C++, and Objective-C. If the path does not identify
+/// a language, then the content is checked as by [`from_content`].
+///
+/// If the content does not disambiguate the extension, then the default
+/// language is used. Use [`LanguageDetectorBuilder::prefer`] to prefer
+/// another language instead.
+///
+/// *[See also `detect_explained`][detect_explained].*
+///
+/// [`from_path`]: fn.from_path.html
+/// [`from_content`]: fn.from_content.html
+/// [`LanguageDetectorBuilder::prefer`]: struct.LanguageDetectorBuilder.html#method.prefer
+/// [detect_explained]: fn.detect_explained.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_path_with_content, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// let content = b"#import <Foundation/Foundation.h>\n";
+/// assert_eq!(from_path_with_content("foo.h", content), Some(Language("Objective-C", "objective-c")));
+///
+/// let content = b"namespace foo {\nclass Bar;\n}\n";
+/// assert_eq!(from_path_with_content("foo.h", content), Some(Language("C++", "cpp")));
+///
+/// let content = b"int foo(void);\n";
+/// assert_eq!(from_path_with_content("foo.h", content), Some(Language("C", "c")));
+///
+/// let content = b"#!/bin/sh\n";
+/// assert_eq!(from_path_with_content("foo.rs", content), Some(Language("Rust", "rust")));
+/// assert_eq!(from_path_with_content("foo", content), Some(Language("Shell", "shell")));
+/// # }
+/// ```
+#[inline]
+pub fn from_path_with_content<P: AsRef<Path>>(
+    path: P,
+    content: &[u8],
+) -> Option<Language<'static>> {
+    detect_explained(path, Some(content)).map(|(lang, _)| lang)
+}
+
+#[cfg(feature = "std")]
+/// Backup suffixes removed by [`from_path_ignoring_backup`].
+///
+/// [`from_path_ignoring_backup`]: fn.from_path_ignoring_backup.html
+const BACKUP_SUFFIXES: [&str; 5] = [".bak", ".orig", ".swp", ".tmp", ".old"];
+
+#[cfg(feature = "std")]
+/// Identifies a language from a path, while ignoring backup markers
+/// left by editors and tools, e.g. `main.rs~` or `foo.py.bak`.
+/// The casing of the extension does not affect the result.
+/// Returns `None` if the language was not identified.
+///
+/// The following are stripped from the end of the filename in order,
+/// before applying [`from_path`]:
+///
+/// 1. Any trailing `~`
+/// 2. Any of the suffixes `.bak`, `.orig`, `.swp`, `.tmp`, and `.old`,
+///    repeatedly, e.g. `foo.py.orig.bak` becomes `foo.py`
+///
+/// [`from_path`]: fn.from_path.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_path_ignoring_backup, Language};
+/// assert_eq!(from_path_ignoring_backup("main.rs~"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_path_ignoring_backup("foo.py.bak"), Some(Language("Python", "python")));
+/// assert_eq!(from_path_ignoring_backup("index.html.orig"), Some(Language("HTML", "html")));
+/// assert_eq!(from_path_ignoring_backup(".config.toml.swp"), Some(Language("TOML", "toml")));
+/// assert_eq!(from_path_ignoring_backup("foo.json.tmp"), Some(Language("JSON", "json")));
+/// assert_eq!(from_path_ignoring_backup("foo.c.OLD"), Some(Language("C", "c")));
+/// assert_eq!(from_path_ignoring_backup("foo.py.orig.bak~"), Some(Language("Python", "python")));
+///
+/// // Without backup markers it is the same as `from_path`
+/// assert_eq!(from_path_ignoring_backup("foo.rs"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_path_ignoring_backup("foo.bak"), None);
+/// ```
+pub fn from_path_ignoring_backup<P: AsRef<Path>>(path: P) -> Option<Language<'static>> {
+    let path = path.as_ref();
+    let filename = match path.file_name().map(OsStr::to_str) {
+        Some(Some(filename)) => filename,
+        _ => return from_path(path),
+    };
+
+    let mut filename = filename.trim_end_matches('~');
+    while let Some(suffix) = BACKUP_SUFFIXES.iter().find(|suffix| {
+        filename.len() > suffix.len()
+            && filename.is_char_boundary(filename.len() - suffix.len())
+            && filename[filename.len() - suffix.len()..].eq_ignore_ascii_case(suffix)
+    }) {
+        filename = &filename[..filename.len() - suffix.len()];
     }
+
+    from_path(filename)
+}
+
+#[cfg(feature = "std")]
+/// Identifies a language from a request path, as used by bundlers such as
+/// webpack and Vite, e.g. `./style.css?inline` or `/icon.svg?raw`.
+/// The casing of the extension does not affect the result.
+/// Returns `None` if the language was not identified.
+///
+/// The following are stripped in order, before applying [`from_path`]:
+///
+/// 1. Everything from the first `?` or `#`, i.e. the query and fragment
+/// 2. A single leading `./` or `/`
+///
+/// [`from_path`]: fn.from_path.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_request_path, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(from_request_path("style.css?inline"), Some(Language("CSS", "css")));
+/// assert_eq!(from_request_path("icon.svg?raw"), Some(Language("SVG", "svg")));
+/// assert_eq!(from_request_path("mod.js?v=3"), Some(Language("JavaScript", "javascript")));
+/// assert_eq!(from_request_path("./mod.ts?v=1"), Some(Language("TypeScript", "typescript")));
+/// assert_eq!(from_request_path("/src/main.rs"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_request_path("/src/App.vue.css#section"), Some(Language("CSS", "css")));
+/// assert_eq!(from_request_path("main.rs#L10?foo=bar"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_request_path("/Dockerfile?raw"), Some(Language("Dockerfile", "dockerfile")));
+///
+/// assert_eq!(from_request_path("foo?bar.rs"), None);
+/// assert_eq!(from_request_path("?inline"), None);
+/// # }
+/// ```
+pub fn from_request_path(path: &str) -> Option<Language<'static>> {
+    let path = match path.find(['?', '#']) {
+        Some(i) => &path[..i],
+        None => path,
+    };
+    let path = path
+        .strip_prefix("./")
+        .or_else(|| path.strip_prefix('/'))
+        .unwrap_or(path);
+    from_path(path)
+}
+
+#[cfg(feature = "std")]
+/// Identifies a language from a path, while ignoring a numeric suffix left
+/// by log rotation and similar tools, e.g. `main.rs.1` or `foo.py.2024-01-01`.
+/// The casing of the extension does not affect the result.
+/// Returns `None` if the language was not identified.
+///
+/// If the final extension is only ASCII digits (`^\d+$`) or a date
+/// (`YYYY-MM-DD`), then it is stripped once before applying [`from_path`].
+/// Otherwise, it is the same as [`from_path`].
+///
+/// [`from_path`]: fn.from_path.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_path_stripping_numeric_suffix, Language};
+/// assert_eq!(from_path_stripping_numeric_suffix("main.rs.1"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_path_stripping_numeric_suffix("app.rs.1234567"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_path_stripping_numeric_suffix("file.py.2024-01-01"), Some(Language("Python", "python")));
+/// assert_eq!(from_path_stripping_numeric_suffix("main.rs"), Some(Language("Rust", "rust")));
+///
+/// // Only stripped once
+/// assert_eq!(from_path_stripping_numeric_suffix("main.rs.1.2"), None);
+///
+/// // Non-numeric suffixes are not stripped
+/// assert_eq!(from_path_stripping_numeric_suffix("main.rs.v1"), None);
+/// ```
+pub fn from_path_stripping_numeric_suffix<P: AsRef<Path>>(path: P) -> Option<Language<'static>> {
+    let path = path.as_ref();
+    match (path.extension().map(OsStr::to_str), path.file_stem()) {
+        (Some(Some(ext)), Some(stem)) if is_numeric(ext) || is_date(ext) => from_path(stem),
+        _ => from_path(path),
+    }
+}
+
+#[cfg(feature = "std")]
+fn is_numeric(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(feature = "std")]
+fn is_date(s: &str) -> bool {
+    let s = s.as_bytes();
+    s.len() == 10
+        && s.iter().enumerate().all(|(i, &b)| {
+            if i == 4 || i == 7 {
+                b == b'-'
+            } else {
+                b.is_ascii_digit()
+            }
+        })
+}
+
+/// Identifies all languages from the dot-separated extensions of a filename,
+/// e.g. `component.test.tsx.snap` is TSX and Jest Snapshot.
+/// The casing of the extensions does not affect the result.
+///
+/// The languages are returned in the order of the extensions, without duplicates.
+/// The base name (everything before the first `.`, ignoring a leading `.`)
+/// is never considered.
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{languages_in_filename, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(
+///     languages_in_filename("component.test.tsx.snap"),
+///     [Language("TSX", "tsx"), Language("Jest Snapshot", "jest-snapshot")],
+/// );
+/// assert_eq!(
+///     languages_in_filename("foo.yml.YAML.json"),
+///     [Language("YAML", "yaml"), Language("JSON", "json")],
+/// );
+/// assert_eq!(languages_in_filename(".eslintrc.json"), [Language("JSON", "json")]);
+///
+/// // The base name is ignored
+/// assert_eq!(languages_in_filename("rs"), []);
+/// assert_eq!(languages_in_filename(".rs"), []);
+/// # }
+/// ```
+pub fn languages_in_filename(name: &str) -> Vec<Language<'static>> {
+    let name = name.strip_prefix('.').unwrap_or(name);
+
+    let mut langs = Vec::new();
+    for ext in name.split('.').skip(1) {
+        if let Some(lang) = from_extension(ext) {
+            if !langs.contains(&lang) {
+                langs.push(lang);
+            }
+        }
+    }
+    langs
 }
 
 /// Identifies a language from a file extension.
@@ -239,8 +1484,8 @@ pub fn from_path<P: AsRef<Path>>(path: P) -> Option<Language<'static>> {
 /// [languages.rs]: https://github.com/vallentin/detect-lang/blob/master/src/languages.rs
 #[inline]
 pub fn from_extension<S: AsRef<str>>(extension: S) -> Option<Language<'static>> {
-    let ext = extension.as_ref().to_ascii_lowercase();
-    from_lowercase_extension(ext)
+    let (mut buf, mut heap) = ([0; MAX_STACK_EXTENSION_LEN], String::new());
+    from_lowercase_extension(to_ascii_lowercase(extension.as_ref(), &mut buf, &mut heap))
 }
 
 /// Identifies a language from a lowercase file extension.
@@ -279,8 +1524,60 @@ pub fn from_extension<S: AsRef<str>>(extension: S) -> Option<Language<'static>>
 /// [languages.rs]: https://github.com/vallentin/detect-lang/blob/master/src/languages.rs
 #[inline]
 pub fn from_lowercase_extension<S: AsRef<str>>(extension: S) -> Option<Language<'static>> {
+    from_lowercase_extension_bytes(extension.as_ref().as_bytes())
+}
+
+/// Identifies a language from a lowercase file extension given as bytes,
+/// e.g. from a raw directory listing, avoiding UTF-8 validation.
+/// Returns `None` if the language was not identified.
+///
+/// The extension must already be lowercase ASCII. All known extensions
+/// are ASCII, so non-ASCII bytes never match.
+///
+/// *[See also `from_lowercase_extension`][from_lowercase_extension].*
+///
+/// [from_lowercase_extension]: fn.from_lowercase_extension.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_lowercase_extension_bytes, Language};
+/// assert_eq!(from_lowercase_extension_bytes(b"rs"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_lowercase_extension_bytes(b"json"), Some(Language("JSON", "json")));
+/// assert_eq!(from_lowercase_extension_bytes(b"unknown"), None);
+///
+/// // Not lowercase
+/// assert_eq!(from_lowercase_extension_bytes(b"RS"), None);
+///
+/// // Not ASCII (nor valid UTF-8)
+/// assert_eq!(from_lowercase_extension_bytes(b"r\xFFs"), None);
+/// assert_eq!(from_lowercase_extension_bytes("rś".as_bytes()), None);
+/// ```
+pub fn from_lowercase_extension_bytes(extension: &[u8]) -> Option<Language<'static>> {
     LANGUAGES
-        .binary_search_by_key(&extension.as_ref(), |&(ext, _)| ext)
+        .binary_search_by(|&(ext, _)| ext.as_bytes().cmp(extension))
         .ok()
         .map(|i| LANGUAGES[i].1)
 }
+
+/// Extensions up to this length are lowercased on the stack instead of
+/// allocating, which covers every built-in extension.
+pub(crate) const MAX_STACK_EXTENSION_LEN: usize = 32;
+
+/// Lowercases `s` into `buf`, falling back to `heap` if it does not fit.
+pub(crate) fn to_ascii_lowercase<'b>(
+    s: &str,
+    buf: &'b mut [u8; MAX_STACK_EXTENSION_LEN],
+    heap: &'b mut String,
+) -> &'b str {
+    if let Some(buf) = buf.get_mut(..s.len()) {
+        buf.copy_from_slice(s.as_bytes());
+        buf.make_ascii_lowercase();
+        // Only ASCII bytes were changed, so the bytes remain valid UTF-8
+        if let Ok(s) = core::str::from_utf8(buf) {
+            return s;
+        }
+    }
+    *heap = s.to_ascii_lowercase();
+    heap
+}
@@ -0,0 +1,105 @@
+// The array is sorted by the language ID
+//
+// IDs are the `language_id` of the corresponding language in
+// GitHub Linguist's `languages.yml`
+pub(crate) static LINGUIST_IDS: [(&str, u32); 61] = [
+    ("astro", 578209015),
+    ("batch", 29),
+    ("c", 41),
+    ("clojure", 62),
+    ("cmake", 47),
+    ("common-lisp", 66),
+    ("cpp", 43),
+    ("csharp", 42),
+    ("css", 50),
+    ("csv", 51),
+    ("d", 79),
+    ("dart", 87),
+    ("dockerfile", 89),
+    ("emacs-lisp", 102),
+    ("glsl", 124),
+    ("go", 132),
+    ("groovy", 142),
+    ("hcl", 144),
+    ("hlsl", 145),
+    ("html", 146),
+    ("ini", 163),
+    ("java", 181),
+    ("javascript", 183),
+    ("json", 174),
+    ("julia", 184),
+    ("jupyter-notebook", 185),
+    ("kotlin", 189),
+    ("lua", 213),
+    ("make", 220),
+    ("markdown", 222),
+    ("matlab", 225),
+    ("nix", 252),
+    ("objective-c", 257),
+    ("pascal", 281),
+    ("perl", 282),
+    ("php", 272),
+    ("powershell", 293),
+    ("prolog", 297),
+    ("puppet", 299),
+    ("python", 303),
+    ("r", 307),
+    ("racket", 316),
+    ("raku", 283),
+    ("rebol", 319),
+    ("restructuredtext", 419),
+    ("ruby", 326),
+    ("rust", 327),
+    ("scala", 333),
+    ("shell", 346),
+    ("svelte", 928734530),
+    ("svg", 337),
+    ("swift", 362),
+    ("tex", 369),
+    ("text", 372),
+    ("toml", 365),
+    ("typescript", 378),
+    ("verilog", 387),
+    ("vim-script", 388),
+    ("vue", 391),
+    ("xml", 399),
+    ("yaml", 407),
+];
+
+pub(crate) fn linguist_id(id: &str) -> Option<u32> {
+    LINGUIST_IDS
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .ok()
+        .map(|i| LINGUIST_IDS[i].1)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in LINGUIST_IDS.iter().zip(LINGUIST_IDS.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Linguist IDs out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_linguist_ids() {
+    for (i, &(id, linguist_id)) in LINGUIST_IDS.iter().enumerate() {
+        assert!(
+            crate::category::category(id).is_some(),
+            "Linguist ID for unknown language {:?}",
+            id
+        );
+        assert!(
+            LINGUIST_IDS[i + 1..]
+                .iter()
+                .all(|&(_, other)| other != linguist_id),
+            "Duplicate Linguist ID {} for {:?}",
+            linguist_id,
+            id
+        );
+    }
+}
@@ -0,0 +1,86 @@
+// The array is sorted by the language ID
+//
+// Identifiers are the ones listed by the Language Server Protocol
+// specification for `TextDocumentItem`
+pub(crate) static LSP_IDS: [(&str, &str); 50] = [
+    ("batch", "bat"),
+    ("bibtex", "bibtex"),
+    ("c", "c"),
+    ("clojure", "clojure"),
+    ("cpp", "cpp"),
+    ("csharp", "csharp"),
+    ("css", "css"),
+    ("d", "d"),
+    ("dart", "dart"),
+    ("dockerfile", "dockerfile"),
+    ("go", "go"),
+    ("groovy", "groovy"),
+    ("handlebars", "handlebars"),
+    ("html", "html"),
+    ("ini", "ini"),
+    ("java", "java"),
+    ("javascript", "javascript"),
+    ("json", "json"),
+    ("less", "less"),
+    ("lua", "lua"),
+    ("make", "makefile"),
+    ("markdown", "markdown"),
+    ("mysql", "sql"),
+    ("objective-c", "objective-c"),
+    ("pascal", "pascal"),
+    ("perl", "perl"),
+    ("php", "php"),
+    ("plsql", "sql"),
+    ("postgresql", "sql"),
+    ("powershell", "powershell"),
+    ("python", "python"),
+    ("r", "r"),
+    ("raku", "perl6"),
+    ("ruby", "ruby"),
+    ("rust", "rust"),
+    ("sass", "sass"),
+    ("scala", "scala"),
+    ("scss", "scss"),
+    ("shaderlab", "shaderlab"),
+    ("shell", "shellscript"),
+    ("sql", "sql"),
+    ("swift", "swift"),
+    ("tex", "latex"),
+    ("tsql", "sql"),
+    ("tsx", "typescriptreact"),
+    ("typescript", "typescript"),
+    ("typescript-declaration", "typescript"),
+    ("vba", "vb"),
+    ("xml", "xml"),
+    ("yaml", "yaml"),
+];
+
+pub(crate) fn lsp_id(id: &str) -> Option<&'static str> {
+    LSP_IDS
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .ok()
+        .map(|i| LSP_IDS[i].1)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in LSP_IDS.iter().zip(LSP_IDS.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "LSP IDs out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_lsp_ids() {
+    for &(id, _) in LSP_IDS.iter() {
+        assert!(
+            crate::category::category(id).is_some(),
+            "LSP ID for unknown language {:?}",
+            id
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use crate::Language;
+
+// The array is sorted by the signature
+//
+// Signatures are matched at the beginning of the content, and no
+// signature is a prefix of another
+pub(crate) static SIGNATURES: &[(&[u8], Language)] = &[
+    #[cfg(feature = "full")]
+    (b"\x1F\x8B", Language("Gzip", "gzip")),
+    #[cfg(feature = "full")]
+    (b"%PDF-", Language("PDF", "pdf")),
+    #[cfg(feature = "full")]
+    (b"(\xB5/\xFD", Language("Zstandard", "zstd")),
+    #[cfg(feature = "full")]
+    (b"7z\xBC\xAF\x27\x1C", Language("7-Zip", "7z")),
+    #[cfg(feature = "full")]
+    (b"PK\x03\x04", Language("ZIP", "zip")),
+    #[cfg(feature = "full")]
+    (b"PK\x05\x06", Language("ZIP", "zip")),
+    #[cfg(feature = "full")]
+    (b"Rar!\x1A\x07", Language("RAR", "rar")),
+    #[cfg(feature = "full")]
+    (b"SQLite format 3\x00", Language("SQLite", "sqlite")),
+    #[cfg(feature = "full")]
+    (b"\x7FELF", Language("ELF", "elf")),
+    #[cfg(feature = "full")]
+    (b"\x89PNG\r\n\x1A\n", Language("PNG", "png")),
+    #[cfg(feature = "full")]
+    (b"\xCE\xFA\xED\xFE", Language("Mach-O", "mach-o")),
+    #[cfg(feature = "full")]
+    (b"\xCF\xFA\xED\xFE", Language("Mach-O", "mach-o")),
+    #[cfg(feature = "full")]
+    (b"\xFD7zXZ\x00", Language("XZ", "xz")),
+    #[cfg(feature = "full")]
+    (b"\xFE\xED\xFA\xCE", Language("Mach-O", "mach-o")),
+    #[cfg(feature = "full")]
+    (b"\xFE\xED\xFA\xCF", Language("Mach-O", "mach-o")),
+];
+
+// Portable Executables start with `MZ`, which is too short to be a
+// signature by itself, so the `PE\0\0` signature at the offset given
+// by `e_lfanew` must also match
+#[cfg(feature = "full")]
+pub(crate) const PORTABLE_EXECUTABLE: Language<'static> = Language("Portable Executable", "pe");
+
+#[cfg(feature = "full")]
+fn is_portable_executable(content: &[u8]) -> bool {
+    const E_LFANEW: usize = 0x3C;
+    if !content.starts_with(b"MZ") || content.len() < E_LFANEW + 4 {
+        return false;
+    }
+    let mut offset = [0; 4];
+    offset.copy_from_slice(&content[E_LFANEW..E_LFANEW + 4]);
+    let offset = u32::from_le_bytes(offset) as usize;
+    content
+        .get(offset..)
+        .is_some_and(|header| header.starts_with(b"PE\0\0"))
+}
+
+/// Identifies a binary format from the magic number at the beginning of
+/// `content`, e.g. `\x7fELF` for ELF executables and `%PDF-` for PDF.
+/// Returns `None` if no signature matched.
+///
+/// Formats are identified regardless of their extension, e.g. an
+/// extension-less executable. Formats based on other formats are
+/// identified as the underlying format, e.g. `.jar` and `.xlsx` files
+/// are ZIP archives.
+///
+/// [`from_content`] checks the signatures before anything else, if
+/// the content [is binary].
+///
+/// [`from_content`]: fn.from_content.html
+/// [is binary]: fn.is_binary.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_magic_bytes, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(from_magic_bytes(b"\x7fELF\x02\x01\x01\x00"), Some(Language("ELF", "elf")));
+/// assert_eq!(from_magic_bytes(b"\x89PNG\r\n\x1a\n\x00\x00\x00\rIHDR"), Some(Language("PNG", "png")));
+/// assert_eq!(from_magic_bytes(b"%PDF-1.7\n"), Some(Language("PDF", "pdf")));
+/// assert_eq!(from_magic_bytes(b"PK\x03\x04\x14\x00"), Some(Language("ZIP", "zip")));
+/// assert_eq!(from_magic_bytes(b"SQLite format 3\x00"), Some(Language("SQLite", "sqlite")));
+///
+/// assert_eq!(from_magic_bytes(b"fn main() {}\n"), None);
+///
+/// // `MZ` must be followed by a PE header
+/// assert_eq!(from_magic_bytes(b"MZ is a company\n"), None);
+/// # }
+/// ```
+pub fn from_magic_bytes(content: &[u8]) -> Option<Language<'static>> {
+    #[cfg(feature = "full")]
+    if is_portable_executable(content) {
+        return Some(PORTABLE_EXECUTABLE);
+    }
+
+    SIGNATURES
+        .iter()
+        .find(|&&(signature, _)| content.starts_with(signature))
+        .map(|&(_, lang)| lang)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in SIGNATURES.iter().zip(SIGNATURES.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Signatures out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+        assert!(
+            !b.0.starts_with(a.0),
+            "Signature {:?} is a prefix of {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_signatures() {
+    for &(signature, lang) in SIGNATURES {
+        assert!(lang.is_binary(), "{:?} is not binary", lang);
+        assert_eq!(from_magic_bytes(signature), Some(lang));
+        assert_eq!(from_magic_bytes(&signature[..signature.len() - 1]), None);
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_portable_executable() {
+    use alloc::vec;
+
+    let mut exe = vec![0; 0x80];
+    exe[..2].copy_from_slice(b"MZ");
+    exe[0x3C] = 0x40;
+    exe[0x40..0x44].copy_from_slice(b"PE\0\0");
+    assert_eq!(from_magic_bytes(&exe), Some(PORTABLE_EXECUTABLE));
+    assert_eq!(crate::from_content(&exe), Some(PORTABLE_EXECUTABLE));
+
+    // The header is out of bounds
+    exe[0x3C] = 0xFF;
+    assert_eq!(from_magic_bytes(&exe), None);
+
+    exe[0x3C] = 0x40;
+    exe[0x40] = b'X';
+    assert_eq!(from_magic_bytes(&exe), None);
+    assert_eq!(from_magic_bytes(&exe[..0x3E]), None);
+
+    // Text starting with `MZ` is not binary
+    assert_eq!(crate::from_content(b"MZ-Tools settings\nfoo = bar\n"), None);
+    #[cfg(feature = "std")]
+    assert_eq!(
+        crate::from_path_with_content("NOTES", b"MZ is a company\n"),
+        None
+    );
+}
@@ -0,0 +1,138 @@
+use crate::{languages, Language};
+
+// The array is sorted by the language ID
+//
+// MIME types are lowercase, and are the registered type if any,
+// otherwise the de facto `text/x-*` type
+pub(crate) static MIME_TYPES: [(&str, &str); 53] = [
+    ("7z", "application/x-7z-compressed"),
+    ("batch", "text/x-msdos-batch"),
+    ("bibtex", "text/x-bibtex"),
+    ("bzip2", "application/x-bzip2"),
+    ("c", "text/x-c"),
+    ("clojure", "text/x-clojure"),
+    ("cpp", "text/x-c++"),
+    ("csharp", "text/x-csharp"),
+    ("css", "text/css"),
+    ("csv", "text/csv"),
+    ("dart", "text/x-dart"),
+    ("dockerfile", "text/x-dockerfile"),
+    ("excel", "application/vnd.ms-excel"),
+    ("go", "text/x-go"),
+    ("graphql", "application/graphql"),
+    ("gzip", "application/gzip"),
+    ("html", "text/html"),
+    ("java", "text/x-java"),
+    ("javascript", "text/javascript"),
+    ("json", "application/json"),
+    ("json-lines", "application/jsonl"),
+    ("jupyter-notebook", "application/x-ipynb+json"),
+    ("kotlin", "text/x-kotlin"),
+    ("lua", "text/x-lua"),
+    ("make", "text/x-makefile"),
+    ("markdown", "text/markdown"),
+    ("objective-c", "text/x-objective-c"),
+    ("ods", "application/vnd.oasis.opendocument.spreadsheet"),
+    ("pascal", "text/x-pascal"),
+    ("perl", "text/x-perl"),
+    ("php", "application/x-httpd-php"),
+    ("python", "text/x-python"),
+    ("rar", "application/vnd.rar"),
+    ("restructuredtext", "text/x-rst"),
+    ("ruby", "text/x-ruby"),
+    ("rust", "text/x-rust"),
+    ("scala", "text/x-scala"),
+    ("shell", "text/x-shellscript"),
+    ("sql", "application/sql"),
+    ("svg", "image/svg+xml"),
+    ("swift", "text/x-swift"),
+    ("tar", "application/x-tar"),
+    ("tex", "text/x-tex"),
+    ("text", "text/plain"),
+    ("toml", "application/toml"),
+    ("tsv", "text/tab-separated-values"),
+    ("typescript", "application/typescript"),
+    ("xhtml", "application/xhtml+xml"),
+    ("xml", "application/xml"),
+    ("xz", "application/x-xz"),
+    ("yaml", "application/yaml"),
+    ("zip", "application/zip"),
+    ("zstd", "application/zstd"),
+];
+
+pub(crate) fn mime(id: &str) -> Option<&'static str> {
+    MIME_TYPES
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .ok()
+        .map(|i| MIME_TYPES[i].1)
+}
+
+/// Identifies a language from a MIME type, e.g. the `Content-Type` of
+/// an HTTP response, such as `text/x-python` or `application/json`.
+/// Returns `None` if the language was not identified.
+///
+/// Parameters are ignored, e.g. `text/x-c; charset=utf-8`, and the
+/// case of the MIME type does not affect the result.
+///
+/// Both the MIME types returned by [`Language::mime`] and the ones
+/// recognized by [`from_file_mime`] are supported.
+///
+/// [`Language::mime`]: struct.Language.html#method.mime
+/// [`from_file_mime`]: fn.from_file_mime.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_mime, Language};
+/// assert_eq!(from_mime("text/x-python"), Some(Language("Python", "python")));
+/// assert_eq!(from_mime("application/json; charset=utf-8"), Some(Language("JSON", "json")));
+/// assert_eq!(from_mime("text/x-rust"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_mime("application/javascript"), Some(Language("JavaScript", "javascript")));
+///
+/// assert_eq!(from_mime("application/octet-stream"), None);
+///
+/// let lang = from_mime("text/x-c++").unwrap();
+/// assert_eq!(from_mime(lang.mime().unwrap()), Some(lang));
+/// ```
+pub fn from_mime(mime: &str) -> Option<Language<'static>> {
+    let essence = mime.split(';').next()?.trim();
+    let known = MIME_TYPES
+        .iter()
+        .find(|&&(_, known)| known.eq_ignore_ascii_case(essence));
+    match known {
+        Some(&(id, _)) => languages::from_id(id),
+        None => crate::from_file_mime(mime),
+    }
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in MIME_TYPES.iter().zip(MIME_TYPES.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "MIME types out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_mime_types() {
+    for &(id, mime) in MIME_TYPES.iter() {
+        let lang = languages::from_id(id);
+        assert!(lang.is_some(), "Unknown language {:?} for {:?}", id, mime);
+        assert_eq!(
+            mime,
+            mime.to_ascii_lowercase(),
+            "{:?} is not lowercase",
+            mime
+        );
+        assert_eq!(from_mime(mime), lang, "{:?}", mime);
+    }
+
+    assert_eq!(from_mime("TEXT/PLAIN"), Some(Language("Text", "text")));
+    assert_eq!(from_mime("text/xml"), Some(Language("XML", "xml")));
+    assert_eq!(from_mime(""), None);
+}
@@ -0,0 +1,177 @@
+use crate::{from_lowercase_extension, from_name_or_alias, languages, Language};
+
+/// The number of lines at the top and bottom of the content checked
+/// for modelines, which is the default of Vim's `modelines` option.
+const MODELINE_LINES: usize = 5;
+
+// The array is sorted by the mode
+//
+// Vim filetypes and Emacs modes which are neither a name, an alias,
+// nor an extension, see `from_name_or_alias`
+pub(crate) static MODES: [(&str, &str); 2] = [("js2", "javascript"), ("lisp", "common-lisp")];
+
+/// Identifies a language from a Vim or Emacs modeline, e.g.
+/// `# vim: ft=python` or `# -*- mode: ruby -*-`.
+/// Returns `None` if the language was not identified.
+///
+/// Either the content of a file or a single line can be given. As in
+/// Vim, the first and last 5 lines are checked for modelines.
+///
+/// The following forms are supported:
+///
+/// - Vim: `vim: ft=python`, `vim: set filetype=python :`, and `vi:`, `vim:`, or `ex:`
+///   followed by `ft`, `filetype`, `syn`, or `syntax`
+/// - Emacs: `-*- ruby -*-` and `-*- mode: ruby; coding: utf-8 -*-`
+///
+/// The filetype or mode is resolved as by [`from_name_or_alias`], e.g.
+/// `cpp`, `c++`, or `shell-script`, as an extension, e.g. `py`, or as a
+/// known Vim filetype or Emacs mode, e.g. `emacs-lisp`, ignoring case.
+///
+/// [`from_name_or_alias`]: fn.from_name_or_alias.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_modeline, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(from_modeline("# vim: ft=python"), Some(Language("Python", "python")));
+/// assert_eq!(from_modeline("/* vim: set filetype=cpp : */"), Some(Language("C++", "cpp")));
+/// assert_eq!(from_modeline("# -*- mode: ruby -*-"), Some(Language("Ruby", "ruby")));
+/// assert_eq!(from_modeline("# -*- shell-script -*-"), Some(Language("Shell", "shell")));
+///
+/// let content = "#!/bin/false\nfoo\nbar\n\n# vim: ts=4 sw=4 ft=sh\n";
+/// assert_eq!(from_modeline(content), Some(Language("Shell", "shell")));
+///
+/// assert_eq!(from_modeline("# vim: ts=4"), None);
+/// assert_eq!(from_modeline("mode: ruby"), None);
+/// # }
+/// ```
+pub fn from_modeline(content: &str) -> Option<Language<'static>> {
+    let head = content.lines().take(MODELINE_LINES);
+    let line_count = content.lines().count();
+    let tail = content.lines().skip(
+        line_count
+            .saturating_sub(MODELINE_LINES)
+            .max(MODELINE_LINES),
+    );
+
+    head.chain(tail)
+        .find_map(|line| emacs_mode(line).or_else(|| vim_filetype(line)))
+        .and_then(from_mode)
+}
+
+fn vim_filetype(line: &str) -> Option<&str> {
+    let options = ["vim:", "vi:", "ex:"].iter().find_map(|marker| {
+        line.match_indices(marker)
+            // The marker must be at the start or follow whitespace
+            .find(|&(i, _)| i == 0 || line[..i].ends_with(char::is_whitespace))
+            .map(|(i, _)| &line[i + marker.len()..])
+    })?;
+    let options = options.trim_start();
+    let options = options.strip_prefix("set ").unwrap_or(options);
+
+    options
+        .split(|c: char| c == ':' || c.is_whitespace())
+        .find_map(|option| {
+            let (key, value) = option.split_once('=')?;
+            match key {
+                "ft" | "filetype" | "syn" | "syntax" if !value.is_empty() => Some(value),
+                _ => None,
+            }
+        })
+}
+
+fn emacs_mode(line: &str) -> Option<&str> {
+    let (_, rest) = line.split_once("-*-")?;
+    let (vars, _) = rest.split_once("-*-")?;
+    let vars = vars.trim();
+
+    if !vars.contains(':') {
+        return Some(vars).filter(|mode| !mode.is_empty());
+    }
+
+    vars.split(';').find_map(|var| {
+        let (key, value) = var.split_once(':')?;
+        if key.trim().eq_ignore_ascii_case("mode") {
+            Some(value.trim()).filter(|mode| !mode.is_empty())
+        } else {
+            None
+        }
+    })
+}
+
+fn from_mode(mode: &str) -> Option<Language<'static>> {
+    let mode = mode.to_ascii_lowercase();
+    let mode = mode.strip_suffix("-mode").unwrap_or(&mode);
+
+    if let Ok(i) = MODES.binary_search_by_key(&mode, |&(mode, _)| mode) {
+        return languages::from_id(MODES[i].1);
+    }
+
+    from_name_or_alias(mode).or_else(|| from_lowercase_extension(mode))
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in MODES.iter().zip(MODES.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Modes out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_modelines() {
+    use alloc::string::String;
+
+    let cases: &[(&str, Option<&str>)] = &[
+        ("// vim: ft=rust", Some("rust")),
+        ("// vim:ft=rust", Some("rust")),
+        ("# vi: set ts=2 syntax=yaml:", Some("yaml")),
+        ("# ex: filetype=make", Some("make")),
+        ("\" vim: set ft=vim :", Some("vim-script")),
+        ("# vim: ft=zsh", Some("shell")),
+        ("# vim: ft=bash", Some("shell")),
+        ("\" vim: ft=viml", Some("vim-script")),
+        ("# -*- mode: makefile -*-", Some("make")),
+        ("# -*- shell-script -*-", Some("shell")),
+        ("# -*- mode: Python -*-", Some("python")),
+        ("# vim: ft=", None),
+        ("# foovim: ft=python", None),
+        (";; -*- mode: lisp; coding: utf-8 -*-", Some("common-lisp")),
+        (";; -*- Mode: Emacs-Lisp -*-", Some("emacs-lisp")),
+        (";; -*- mode: elisp -*-", Some("emacs-lisp")),
+        ("# -*- coding: utf-8 -*-", None),
+        ("// -*- C++ -*-", Some("cpp")),
+        ("// -*- js2-mode -*-", Some("javascript")),
+        ("# -*- -*-", None),
+        ("# -*- mode: unknown -*-", None),
+    ];
+    for &(content, id) in cases {
+        assert_eq!(
+            from_modeline(content).map(|lang| lang.id()),
+            id,
+            "{:?}",
+            content
+        );
+    }
+
+    // Only the first and last lines are checked
+    let mut content = String::from("# vim: ft=python\n");
+    content.push_str(&"\n".repeat(20));
+    assert_eq!(from_modeline(&content), Some(Language("Python", "python")));
+
+    let mut content = "\n".repeat(20);
+    content.push_str("# vim: ft=python\n\n\n\n\n");
+    assert_eq!(from_modeline(&content), Some(Language("Python", "python")));
+
+    let mut content = "\n".repeat(10);
+    content.push_str("# vim: ft=python\n");
+    content.push_str(&"\n".repeat(10));
+    assert_eq!(from_modeline(&content), None);
+}
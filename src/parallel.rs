@@ -0,0 +1,224 @@
+use std::io;
+use std::path::Path;
+
+use rayon::prelude::*;
+
+use crate::stats::add_path;
+use crate::{Language, LanguageStats};
+
+/// Same as [`scan_dir`], except directories are read and files are
+/// identified across threads, using the global [rayon] thread pool.
+///
+/// With the `ignore` feature, the parallel walker of [ignore] is used
+/// instead, which skips ignored files the same as `scan_dir`.
+///
+/// *This function requires the `rayon` feature.*
+///
+/// [`scan_dir`]: fn.scan_dir.html
+/// [rayon]: https://docs.rs/rayon
+/// [ignore]: https://docs.rs/ignore
+///
+/// # Errors
+///
+/// Returns an error if `root`, any directory within it, or the
+/// metadata of any file cannot be read.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> std::io::Result<()> {
+/// use detect_lang::scan_dir_par;
+///
+/// for (lang, percentage) in scan_dir_par(".")?.percentages() {
+///     println!("{}: {:.1}%", lang.name(), percentage);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn scan_dir_par<P: AsRef<Path>>(root: P) -> io::Result<LanguageStats> {
+    let root = root.as_ref();
+    scan(root, root)
+}
+
+#[cfg(not(feature = "ignore"))]
+fn scan(root: &Path, dir: &Path) -> io::Result<LanguageStats> {
+    use crate::stats::is_skipped_dir;
+
+    let mut stats = LanguageStats::new();
+    let mut dirs = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        // `DirEntry::file_type` does not follow symbolic links
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            if !is_skipped_dir(&entry.file_name()) {
+                dirs.push(entry.path());
+            }
+        } else if file_type.is_file() {
+            add_path(&mut stats, root, &entry.path(), entry.metadata()?.len());
+        }
+    }
+
+    dirs.par_iter()
+        .map(|dir| scan(root, dir))
+        .try_fold(LanguageStats::new, |mut acc, stats| {
+            acc.merge(stats?);
+            Ok(acc)
+        })
+        .try_reduce(LanguageStats::new, |mut a, b| {
+            a.merge(b);
+            Ok(a)
+        })
+        .map(|dirs| {
+            stats.merge(dirs);
+            stats
+        })
+}
+
+#[cfg(feature = "ignore")]
+fn scan(root: &Path, dir: &Path) -> io::Result<LanguageStats> {
+    use std::sync::Mutex;
+
+    use ignore::{DirEntry, Error, ParallelVisitor, ParallelVisitorBuilder, WalkState};
+
+    use crate::stats::to_io_error;
+
+    struct Shared {
+        stats: Mutex<LanguageStats>,
+        error: Mutex<Option<io::Error>>,
+    }
+
+    struct Builder<'s> {
+        root: &'s Path,
+        shared: &'s Shared,
+    }
+
+    impl<'s> ParallelVisitorBuilder<'s> for Builder<'s> {
+        fn build(&mut self) -> Box<dyn ParallelVisitor + 's> {
+            Box::new(Visitor {
+                root: self.root,
+                shared: self.shared,
+                stats: LanguageStats::new(),
+            })
+        }
+    }
+
+    /// Counts the files visited by a single thread, which are merged
+    /// into the shared statistics when the thread finishes.
+    struct Visitor<'s> {
+        root: &'s Path,
+        shared: &'s Shared,
+        stats: LanguageStats,
+    }
+
+    impl Visitor<'_> {
+        fn try_visit(&mut self, entry: Result<DirEntry, Error>) -> Result<(), Error> {
+            let entry = entry?;
+            if entry
+                .file_type()
+                .is_some_and(|file_type| file_type.is_file())
+            {
+                let bytes = entry.metadata()?.len();
+                add_path(&mut self.stats, self.root, entry.path(), bytes);
+            }
+            Ok(())
+        }
+    }
+
+    impl ParallelVisitor for Visitor<'_> {
+        fn visit(&mut self, entry: Result<DirEntry, Error>) -> WalkState {
+            match self.try_visit(entry) {
+                Ok(()) => WalkState::Continue,
+                Err(err) => {
+                    let mut error = self.shared.error.lock().unwrap();
+                    error.get_or_insert_with(|| to_io_error(err));
+                    WalkState::Quit
+                }
+            }
+        }
+    }
+
+    impl Drop for Visitor<'_> {
+        fn drop(&mut self) {
+            let stats = std::mem::take(&mut self.stats);
+            if let Ok(mut shared) = self.shared.stats.lock() {
+                shared.merge(stats);
+            }
+        }
+    }
+
+    let shared = Shared {
+        stats: Mutex::new(LanguageStats::new()),
+        error: Mutex::new(None),
+    };
+    crate::stats::walker(dir)
+        .build_parallel()
+        .visit(&mut Builder {
+            root,
+            shared: &shared,
+        });
+
+    match shared.error.into_inner().unwrap() {
+        Some(err) => Err(err),
+        None => Ok(shared.stats.into_inner().unwrap()),
+    }
+}
+
+/// Identifies the language of each path using [`from_path`], across
+/// threads using the global [rayon] thread pool. The result is in the
+/// same order as `paths`.
+///
+/// *This function requires the `rayon` feature.*
+///
+/// [`from_path`]: fn.from_path.html
+/// [rayon]: https://docs.rs/rayon
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_paths_par, Language};
+/// let langs = from_paths_par(&["foo.rs", "foo.unknown", "README.md"]);
+/// assert_eq!(
+///     langs,
+///     [
+///         Some(Language("Rust", "rust")),
+///         None,
+///         Some(Language("Markdown", "markdown")),
+///     ]
+/// );
+/// ```
+pub fn from_paths_par<P: AsRef<Path> + Sync>(paths: &[P]) -> Vec<Option<Language<'static>>> {
+    paths.par_iter().map(crate::from_path).collect()
+}
+
+#[test]
+fn scan_temp_dir_par() {
+    use crate::test_util::TempDir;
+    use std::fs;
+
+    let root = TempDir::new("parallel");
+    for i in 0..10 {
+        let dir = root.join(format!("src/{}/nested", i));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("lib.rs"), [b'a'; 10]).unwrap();
+        fs::write(dir.join("foo.py"), [b'a'; 5]).unwrap();
+    }
+    fs::create_dir_all(root.join("vendor")).unwrap();
+    fs::write(root.join("vendor/foo.py"), [b'a'; 100]).unwrap();
+    fs::write(root.join("foo.unknown"), [b'a'; 100]).unwrap();
+
+    assert_eq!(
+        scan_dir_par(&root).unwrap(),
+        crate::scan_dir(&root).unwrap()
+    );
+    assert_eq!(
+        scan_dir_par(&root).unwrap().percentages(),
+        [
+            (Language("Rust", "rust"), 100.0 / 150.0 * 100.0),
+            (Language("Python", "python"), 50.0 / 150.0 * 100.0),
+        ]
+    );
+
+    assert!(scan_dir_par(root.join("missing")).is_err());
+}
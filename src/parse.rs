@@ -0,0 +1,145 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{from_name_or_alias, Language};
+
+/// Error returned when [parsing] an unknown language.
+///
+/// [parsing]: struct.Language.html#impl-FromStr-for-Language%3C'static%3E
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ParseLanguageError {
+    name: String,
+}
+
+impl ParseLanguageError {
+    /// Returns the name that was not identified as a language.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for ParseLanguageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown language {:?}", self.name)
+    }
+}
+
+impl Error for ParseLanguageError {}
+
+/// Parses a language from its ID, name, or an alias, ignoring case,
+/// as by [`from_name_or_alias`].
+///
+/// [`from_name_or_alias`]: fn.from_name_or_alias.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::Language;
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!("rust".parse(), Ok(Language("Rust", "rust")));
+/// assert_eq!("C++".parse(), Ok(Language("C++", "cpp")));
+/// assert_eq!("golang".parse(), Ok(Language("Go", "go")));
+///
+/// let err = "unknown".parse::<Language>().unwrap_err();
+/// assert_eq!(err.name(), "unknown");
+/// # }
+/// ```
+impl FromStr for Language<'static> {
+    type Err = ParseLanguageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        from_name_or_alias(s).ok_or_else(|| ParseLanguageError { name: s.to_owned() })
+    }
+}
+
+#[test]
+fn check_parse() {
+    use crate::from_extension;
+
+    for ext in ["rs", "cpp", "md", "json"] {
+        let lang = from_extension(ext).unwrap();
+        assert_eq!(lang.id().parse(), Ok(lang));
+        assert_eq!(lang.name().parse(), Ok(lang));
+    }
+    assert_eq!(
+        "".parse::<Language>(),
+        Err(ParseLanguageError {
+            name: String::new()
+        })
+    );
+}
+
+/// Serializes the language as its [ID], e.g. `"rust"`.
+///
+/// *This implementation requires the `serde` feature.*
+///
+/// [ID]: struct.Language.html#method.id
+#[cfg(feature = "serde")]
+impl serde::Serialize for Language<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.id())
+    }
+}
+
+/// Deserializes a built-in language from its [ID], e.g. `"rust"`.
+/// Unknown IDs result in an error.
+///
+/// *This implementation requires the `serde` feature.*
+///
+/// [ID]: struct.Language.html#method.from_id
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::Language;
+/// let lang = Language("Rust", "rust");
+/// let json = serde_json::to_string(&lang).unwrap();
+/// assert_eq!(json, r#""rust""#);
+/// assert_eq!(serde_json::from_str::<Language>(&json).unwrap(), lang);
+///
+/// assert!(serde_json::from_str::<Language>(r#""unknown""#).is_err());
+/// ```
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Language<'static> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IdVisitor;
+
+        impl serde::de::Visitor<'_> for IdVisitor {
+            type Value = Language<'static>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a language id")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, id: &str) -> Result<Self::Value, E> {
+                Language::from_id(id)
+                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(id), &self))
+            }
+        }
+
+        deserializer.deserialize_str(IdVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn check_serde() {
+    use crate::from_path;
+
+    let langs = vec![from_path("foo.rs").unwrap(), from_path("foo.cpp").unwrap()];
+    let json = serde_json::to_string(&langs).unwrap();
+    assert_eq!(json, r#"["rust","cpp"]"#);
+    assert_eq!(serde_json::from_str::<Vec<Language>>(&json).unwrap(), langs);
+
+    // ID aliases are accepted
+    assert_eq!(
+        serde_json::from_str::<Language>(r#""c++""#).unwrap(),
+        Language("C++", "cpp")
+    );
+    assert!(serde_json::from_str::<Language>("1").is_err());
+}
@@ -0,0 +1,118 @@
+use crate::category::Category;
+use crate::{candidates, from_lowercase_extension, Language};
+
+/// How to resolve an extension which is [ambiguous] between multiple languages,
+/// based on the [category] of each language.
+///
+/// [ambiguous]: fn.ambiguous_extensions.html
+/// [category]: enum.Category.html
+#[non_exhaustive]
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
+pub enum ResolutionPolicy {
+    /// Prefer programming languages, e.g. for code statistics.
+    PreferProgramming,
+    /// Prefer data and configuration formats, e.g. for file viewers.
+    PreferData,
+    /// Use the same language as [`from_extension`].
+    ///
+    /// [`from_extension`]: fn.from_extension.html
+    FirstInTable,
+}
+
+impl Default for ResolutionPolicy {
+    #[inline]
+    fn default() -> Self {
+        Self::FirstInTable
+    }
+}
+
+/// Identifies a language from a file extension, resolving ambiguous
+/// extensions using `policy`. Returns `None` if the language was not identified.
+///
+/// If none of the candidates match the preferred category, then the
+/// first candidate is returned, i.e. the same language as [`from_extension`].
+/// Extensions which are not ambiguous always return the same language
+/// as [`from_extension`], regardless of the policy.
+///
+/// The policy only applies to the built-in languages. Overrides, e.g.
+/// from a [`Registry`] or [`GitattributesOverrides`], take precedence
+/// and should be checked before falling back to this function.
+///
+/// [`from_extension`]: fn.from_extension.html
+/// [`Registry`]: struct.Registry.html
+/// [`GitattributesOverrides`]: struct.GitattributesOverrides.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_extension_with_policy, Language, ResolutionPolicy};
+/// # #[cfg(feature = "full")]
+/// # {
+/// use ResolutionPolicy::*;
+///
+/// // `.ts` is both TypeScript and Qt Linguist translations (XML)
+/// assert_eq!(from_extension_with_policy("ts", PreferProgramming), Some(Language("TypeScript", "typescript")));
+/// assert_eq!(from_extension_with_policy("ts", PreferData), Some(Language("XML", "xml")));
+/// assert_eq!(from_extension_with_policy("ts", FirstInTable), Some(Language("TypeScript", "typescript")));
+///
+/// // Unambiguous extensions are unaffected
+/// assert_eq!(from_extension_with_policy("json", PreferProgramming), Some(Language("JSON", "json")));
+/// # }
+/// ```
+pub fn from_extension_with_policy<S: AsRef<str>>(
+    extension: S,
+    policy: ResolutionPolicy,
+) -> Option<Language<'static>> {
+    let ext = extension.as_ref().to_ascii_lowercase();
+
+    let langs = match candidates::find(&ext) {
+        Some(langs) => langs,
+        None => return from_lowercase_extension(ext),
+    };
+
+    let preferred = match policy {
+        ResolutionPolicy::PreferProgramming => Category::Programming,
+        ResolutionPolicy::PreferData => Category::Data,
+        ResolutionPolicy::FirstInTable => return langs.first().copied(),
+    };
+
+    langs
+        .iter()
+        .find(|lang| lang.category() == Some(preferred))
+        .or_else(|| langs.first())
+        .copied()
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_policies() {
+    use ResolutionPolicy::*;
+
+    let policies = [PreferProgramming, PreferData, FirstInTable];
+    for &policy in policies.iter() {
+        // Candidates of the same category resolve to the first
+        assert_eq!(
+            from_extension_with_policy("M", policy),
+            Some(Language("Objective-C", "objective-c"))
+        );
+        assert_eq!(
+            from_extension_with_policy("pp", policy),
+            Some(Language("Puppet", "puppet"))
+        );
+
+        assert_eq!(
+            from_extension_with_policy("rs", policy),
+            Some(Language("Rust", "rust"))
+        );
+        assert_eq!(from_extension_with_policy("unknown", policy), None);
+    }
+
+    assert_eq!(
+        from_extension_with_policy("TS", PreferData),
+        Some(Language("XML", "xml"))
+    );
+    assert_eq!(
+        from_extension_with_policy("TS", PreferProgramming),
+        Some(Language("TypeScript", "typescript"))
+    );
+}
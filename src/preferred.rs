@@ -0,0 +1,81 @@
+use crate::languages::LANGUAGES;
+
+// The array is sorted by the language ID
+//
+// Languages with a single extension are not included,
+// as that extension is used.
+pub(crate) static PREFERRED_EXTENSIONS: [(&str, &str); 30] = [
+    ("asciidoc", "adoc"),
+    ("c", "c"),
+    ("cpp", "cpp"),
+    ("d", "d"),
+    ("excel", "xlsx"),
+    ("glsl", "glsl"),
+    ("godot-resource", "tres"),
+    ("graphql", "graphql"),
+    ("groovy", "groovy"),
+    ("handlebars", "hbs"),
+    ("hcl", "hcl"),
+    ("jinja", "jinja"),
+    ("json-lines", "jsonl"),
+    ("kotlin", "kt"),
+    ("make", "mk"),
+    ("perl", "pl"),
+    ("php", "php"),
+    ("plsql", "pls"),
+    ("powershell", "ps1"),
+    ("python", "py"),
+    ("raku", "raku"),
+    ("rebol", "reb"),
+    ("scala", "scala"),
+    ("tar-gzip", "tar.gz"),
+    ("terraform", "tf"),
+    ("tex", "tex"),
+    ("vba", "bas"),
+    ("verilog", "v"),
+    ("xslt", "xsl"),
+    ("yaml", "yaml"),
+];
+
+pub(crate) fn preferred_extension(id: &str) -> Option<&'static str> {
+    if let Ok(i) = PREFERRED_EXTENSIONS.binary_search_by_key(&id, |&(id, _)| id) {
+        return Some(PREFERRED_EXTENSIONS[i].1);
+    }
+
+    LANGUAGES
+        .iter()
+        .find(|(_, lang)| lang.id() == id)
+        .map(|&(ext, _)| ext)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in PREFERRED_EXTENSIONS
+        .iter()
+        .zip(PREFERRED_EXTENSIONS.iter().skip(1))
+    {
+        assert!(
+            a.0 < b.0,
+            "Preferred extensions out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_multiple_extensions() {
+    for &(_, lang) in LANGUAGES.iter() {
+        let count = LANGUAGES
+            .iter()
+            .filter(|(_, l)| l.id() == lang.id())
+            .count();
+        if count > 1 {
+            assert!(
+                PREFERRED_EXTENSIONS.iter().any(|&(id, _)| id == lang.id()),
+                "Missing preferred extension for {:?}",
+                lang,
+            );
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::Language;
+
+macro_rules! m {
+    ($filename:literal, $name:literal, $id:literal) => {
+        ($filename, Language($name, $id))
+    };
+}
+
+// The array is sorted by the filename
+//
+// Languages not included in the `common` feature are only
+// included when the `full` feature is enabled.
+pub(crate) static MANIFESTS: &[(&str, Language)] = &[
+    m!("Cargo.toml", "Rust", "rust"),
+    #[cfg(feature = "full")]
+    m!("Gemfile", "Ruby", "ruby"),
+    #[cfg(feature = "full")]
+    m!("Package.swift", "Swift", "swift"),
+    m!("Pipfile", "Python", "python"),
+    #[cfg(feature = "full")]
+    m!("build.gradle", "Java", "java"),
+    #[cfg(feature = "full")]
+    m!("build.gradle.kts", "Kotlin", "kotlin"),
+    #[cfg(feature = "full")]
+    m!("build.sbt", "Scala", "scala"),
+    #[cfg(feature = "full")]
+    m!("composer.json", "PHP", "php"),
+    #[cfg(feature = "full")]
+    m!("cpanfile", "Perl", "perl"),
+    #[cfg(feature = "full")]
+    m!("deps.edn", "Clojure", "clojure"),
+    #[cfg(feature = "full")]
+    m!("dub.json", "D", "d"),
+    #[cfg(feature = "full")]
+    m!("dub.sdl", "D", "d"),
+    #[cfg(feature = "full")]
+    m!("go.mod", "Go", "go"),
+    m!("package.json", "JavaScript", "javascript"),
+    #[cfg(feature = "full")]
+    m!("pom.xml", "Java", "java"),
+    #[cfg(feature = "full")]
+    m!("project.clj", "Clojure", "clojure"),
+    #[cfg(feature = "full")]
+    m!("pubspec.yaml", "Dart", "dart"),
+    m!("pyproject.toml", "Python", "python"),
+    m!("requirements.txt", "Python", "python"),
+    m!("setup.py", "Python", "python"),
+    m!("tsconfig.json", "TypeScript", "typescript"),
+    #[cfg(feature = "full")]
+    m!("v.mod", "V", "v"),
+];
+
+/// Identifies the language of a project from the filename of its
+/// manifest, e.g. `Cargo.toml` for Rust and `go.mod` for Go.
+/// Returns `None` if the filename is not a known manifest.
+///
+/// The filename is case-sensitive.
+///
+/// *[See also `project_languages`][project_languages].*
+///
+/// [project_languages]: fn.project_languages.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_manifest, Language};
+/// assert_eq!(from_manifest("Cargo.toml"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_manifest("package.json"), Some(Language("JavaScript", "javascript")));
+/// assert_eq!(from_manifest("pyproject.toml"), Some(Language("Python", "python")));
+///
+/// // Unlike `from_path`, other files are not manifests
+/// assert_eq!(from_manifest("Cargo.lock"), None);
+/// assert_eq!(from_manifest("main.rs"), None);
+/// ```
+pub fn from_manifest(filename: &str) -> Option<Language<'static>> {
+    MANIFESTS
+        .binary_search_by_key(&filename, |&(filename, _)| filename)
+        .ok()
+        .map(|i| MANIFESTS[i].1)
+}
+
+/// Identifies the primary languages of the project in the directory
+/// `dir`, from the [manifests] directly within it, e.g. `Cargo.toml`
+/// and `package.json`. The result is empty if no manifests were found.
+///
+/// The result is sorted by the number of manifests of each language,
+/// such that the language with the most manifests is first, and then
+/// by language, e.g. a directory containing `pyproject.toml`,
+/// `requirements.txt`, and `Cargo.toml` results in Python and then Rust.
+///
+/// Subdirectories are not checked.
+///
+/// [manifests]: fn.from_manifest.html
+///
+/// # Errors
+///
+/// Returns an error if `dir` cannot be read.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> std::io::Result<()> {
+/// use detect_lang::project_languages;
+///
+/// for lang in project_languages(".")? {
+///     println!("{}", lang.name());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn project_languages<P: AsRef<Path>>(dir: P) -> io::Result<Vec<Language<'static>>> {
+    let mut counts = BTreeMap::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            continue;
+        }
+        if let Some(lang) = entry.file_name().to_str().and_then(from_manifest) {
+            *counts.entry(lang).or_insert(0usize) += 1;
+        }
+    }
+
+    // The sort is stable, so languages with the same number of
+    // manifests remain sorted by language
+    let mut counts = counts.into_iter().collect::<Vec<_>>();
+    counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+    Ok(counts.into_iter().map(|(lang, _)| lang).collect())
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in MANIFESTS.iter().zip(MANIFESTS.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Manifests out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_manifests() {
+    for &(filename, lang) in MANIFESTS.iter() {
+        assert!(
+            crate::category::category(lang.id()).is_some(),
+            "Missing category for {:?} of {:?}",
+            lang,
+            filename
+        );
+        assert_eq!(crate::languages::from_id(lang.id()), Some(lang));
+    }
+}
+
+#[test]
+fn check_project_dir() {
+    use crate::test_util::TempDir;
+
+    let root = TempDir::new("project");
+    fs::create_dir_all(root.join("web")).unwrap();
+    fs::create_dir_all(root.join("go.mod")).unwrap();
+    fs::write(root.join("Cargo.toml"), "").unwrap();
+    fs::write(root.join("Cargo.lock"), "").unwrap();
+    fs::write(root.join("package.json"), "").unwrap();
+    fs::write(root.join("requirements.txt"), "").unwrap();
+    fs::write(root.join("setup.py"), "").unwrap();
+    fs::write(root.join("web/package.json"), "").unwrap();
+
+    assert_eq!(
+        project_languages(&root).unwrap(),
+        [
+            Language("Python", "python"),
+            Language("JavaScript", "javascript"),
+            Language("Rust", "rust"),
+        ]
+    );
+    assert_eq!(
+        project_languages(root.join("web")).unwrap(),
+        [Language("JavaScript", "javascript")]
+    );
+    assert!(project_languages(root.join("missing")).is_err());
+}
@@ -0,0 +1,108 @@
+// The array is sorted by the language ID
+//
+// Lexers are given by a short name accepted by `get_lexer_by_name`
+pub(crate) static PYGMENTS_LEXERS: [(&str, &str); 73] = [
+    ("batch", "batch"),
+    ("bibtex", "bibtex"),
+    ("bicep", "bicep"),
+    ("c", "c"),
+    ("clojure", "clojure"),
+    ("cmake", "cmake"),
+    ("common-lisp", "common-lisp"),
+    ("coq", "coq"),
+    ("cpp", "cpp"),
+    ("csharp", "csharp"),
+    ("css", "css"),
+    ("d", "d"),
+    ("dart", "dart"),
+    ("dockerfile", "docker"),
+    ("gdscript", "gdscript"),
+    ("glsl", "glsl"),
+    ("go", "go"),
+    ("graphql", "graphql"),
+    ("groovy", "groovy"),
+    ("haml", "haml"),
+    ("handlebars", "handlebars"),
+    ("hcl", "terraform"),
+    ("hlsl", "hlsl"),
+    ("html", "html"),
+    ("html-erb", "rhtml"),
+    ("ini", "ini"),
+    ("java", "java"),
+    ("javascript", "javascript"),
+    ("jinja", "jinja"),
+    ("json", "json"),
+    ("kotlin", "kotlin"),
+    ("less", "less"),
+    ("lua", "lua"),
+    ("make", "make"),
+    ("markdown", "markdown"),
+    ("matlab", "matlab"),
+    ("mysql", "mysql"),
+    ("nix", "nixos"),
+    ("objective-c", "objective-c"),
+    ("pascal", "delphi"),
+    ("perl", "perl"),
+    ("php", "php"),
+    ("postgresql", "postgresql"),
+    ("powershell", "powershell"),
+    ("prolog", "prolog"),
+    ("puppet", "puppet"),
+    ("python", "python"),
+    ("r", "splus"),
+    ("racket", "racket"),
+    ("raku", "perl6"),
+    ("rebol", "rebol"),
+    ("restructuredtext", "rst"),
+    ("ruby", "ruby"),
+    ("rust", "rust"),
+    ("sass", "sass"),
+    ("scala", "scala"),
+    ("scss", "scss"),
+    ("shell", "bash"),
+    ("sparql", "sparql"),
+    ("sql", "sql"),
+    ("swift", "swift"),
+    ("terraform", "terraform"),
+    ("tex", "tex"),
+    ("text", "text"),
+    ("toml", "toml"),
+    ("tsql", "tsql"),
+    ("tsx", "tsx"),
+    ("typescript", "typescript"),
+    ("typescript-declaration", "typescript"),
+    ("verilog", "verilog"),
+    ("vim-script", "vim"),
+    ("xml", "xml"),
+    ("yaml", "yaml"),
+];
+
+pub(crate) fn pygments_lexer(id: &str) -> Option<&'static str> {
+    PYGMENTS_LEXERS
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .ok()
+        .map(|i| PYGMENTS_LEXERS[i].1)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in PYGMENTS_LEXERS.iter().zip(PYGMENTS_LEXERS.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Pygments lexers out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_pygments_lexers() {
+    for &(id, _) in PYGMENTS_LEXERS.iter() {
+        assert!(
+            crate::category::category(id).is_some(),
+            "Pygments lexers for unknown language {:?}",
+            id
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use std::io::{self, Read};
+use std::path::Path;
+
+use crate::{Language, MAX_CONTENT_LEN};
+
+/// The maximum number of bytes read by [`from_reader`], the same as
+/// [`MAX_CONTENT_LEN`].
+///
+/// [`from_reader`]: fn.from_reader.html
+/// [`MAX_CONTENT_LEN`]: constant.MAX_CONTENT_LEN.html
+pub const MAX_SNIFF_LEN: usize = MAX_CONTENT_LEN;
+
+/// Identifies a language from the beginning of the content of a file,
+/// e.g. a `#!` line, without reading the whole file.
+/// Returns `Ok(None)` if the language was not identified.
+///
+/// At most [`MAX_SNIFF_LEN`] bytes are read, such that the rest of the
+/// content remains in the reader. The path of the file is optional, and
+/// if given, the language is identified the same as by
+/// [`from_path_with_content`].
+///
+/// If the `jupyter` feature is enabled and the path has the `.ipynb`
+/// extension, then the whole content is read instead, as the
+/// [metadata of notebooks] is commonly stored at the end of the file.
+///
+/// [`MAX_SNIFF_LEN`]: constant.MAX_SNIFF_LEN.html
+/// [`from_path_with_content`]: fn.from_path_with_content.html
+/// [metadata of notebooks]: fn.from_notebook.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_reader, Language};
+/// # use std::path::Path;
+/// # fn main() -> std::io::Result<()> {
+/// # #[cfg(feature = "full")]
+/// # {
+/// let content = b"#!/usr/bin/env python3\nprint('Hello World')\n";
+/// assert_eq!(from_reader(&mut &content[..], None)?, Some(Language("Python", "python")));
+///
+/// // The path is checked before the content
+/// let path = Path::new("foo.rs");
+/// assert_eq!(from_reader(&mut &content[..], Some(path))?, Some(Language("Rust", "rust")));
+///
+/// // Ambiguous extensions are resolved using the content
+/// let path = Path::new("foo.h");
+/// let mut content = &b"#import <Foundation/Foundation.h>\n"[..];
+/// assert_eq!(from_reader(&mut content, Some(path))?, Some(Language("Objective-C", "objective-c")));
+///
+/// assert_eq!(from_reader(&mut &b"Hello World\n"[..], None)?, None);
+/// # }
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_reader<R: Read>(
+    reader: &mut R,
+    path_hint: Option<&Path>,
+) -> io::Result<Option<Language<'static>>> {
+    let mut content = Vec::new();
+    reader
+        .take(sniff_len(path_hint))
+        .read_to_end(&mut content)?;
+
+    let path = path_hint.unwrap_or_else(|| Path::new(""));
+    Ok(crate::from_path_with_content(path, &content))
+}
+
+/// Returns the number of bytes to read of the file at `path`, which is
+/// [`MAX_SNIFF_LEN`], except for whole notebooks if the `jupyter`
+/// feature is enabled.
+///
+/// [`MAX_SNIFF_LEN`]: constant.MAX_SNIFF_LEN.html
+pub(crate) fn sniff_len(path: Option<&Path>) -> u64 {
+    let notebook = path
+        .and_then(Path::extension)
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("ipynb"));
+    if cfg!(feature = "jupyter") && notebook {
+        u64::MAX
+    } else {
+        MAX_SNIFF_LEN as u64
+    }
+}
+
+#[test]
+fn check_bounded() {
+    let mut reader = io::repeat(b'a');
+    assert_eq!(from_reader(&mut reader, None).unwrap(), None);
+
+    let mut reader = b"#!/bin/sh\n".chain(io::repeat(b' '));
+    assert_eq!(
+        from_reader(&mut reader, None).unwrap(),
+        Some(Language("Shell", "shell"))
+    );
+
+    // The rest of the content remains in the reader
+    let content = [b'a'; MAX_SNIFF_LEN + 10];
+    let mut reader = &content[..];
+    from_reader(&mut reader, Some(Path::new("foo.txt"))).unwrap();
+    assert_eq!(reader.len(), 10);
+}
+
+#[cfg(feature = "jupyter")]
+#[test]
+fn check_notebook() {
+    let mut content = String::from(r#"{"cells": [{"source": ""#);
+    content.push_str(&"x".repeat(MAX_SNIFF_LEN));
+    content.push_str(r#""}], "metadata": {"kernelspec": {"language": "julia"}}}"#);
+
+    let path = Path::new("foo.ipynb");
+    let mut reader = content.as_bytes();
+    let lang = from_reader(&mut reader, Some(path)).unwrap();
+    assert_eq!(
+        lang,
+        crate::from_path_with_content(path, content.as_bytes())
+    );
+    #[cfg(feature = "full")]
+    assert_eq!(lang, Some(Language("Julia", "julia")));
+    assert!(reader.is_empty());
+}
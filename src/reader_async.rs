@@ -0,0 +1,108 @@
+use std::io;
+use std::path::Path;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::Language;
+
+/// Same as [`from_reader`], except the content is read asynchronously,
+/// e.g. from a streamed upload. At most [`MAX_SNIFF_LEN`] bytes are read,
+/// such that the rest of the content remains in the reader, except for
+/// notebooks as by [`from_reader`].
+/// Returns `Ok(None)` if the language was not identified.
+///
+/// *This function requires the `tokio` feature.*
+///
+/// [`from_reader`]: fn.from_reader.html
+/// [`MAX_SNIFF_LEN`]: constant.MAX_SNIFF_LEN.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_async_reader, Language};
+/// # async fn f() -> std::io::Result<()> {
+/// let mut content = &b"#!/usr/bin/env python3\nprint('Hello World')\n"[..];
+/// assert_eq!(
+///     from_async_reader(&mut content, None).await?,
+///     Some(Language("Python", "python")),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub async fn from_async_reader<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    path_hint: Option<&Path>,
+) -> io::Result<Option<Language<'static>>> {
+    let mut content = Vec::new();
+    reader
+        .take(crate::reader::sniff_len(path_hint))
+        .read_to_end(&mut content)
+        .await?;
+
+    let path = path_hint.unwrap_or_else(|| Path::new(""));
+    Ok(crate::from_path_with_content(path, &content))
+}
+
+/// Identifies the language of the file at `path`, using both the path
+/// and the beginning of the content of the file, the same as
+/// [`from_path_with_content`]. The file is read asynchronously, and
+/// at most [`MAX_SNIFF_LEN`] bytes are read, except for notebooks as
+/// by [`from_async_reader`].
+/// Returns `Ok(None)` if the language was not identified.
+///
+/// *This function requires the `tokio` feature.*
+///
+/// [`from_path_with_content`]: fn.from_path_with_content.html
+/// [`MAX_SNIFF_LEN`]: constant.MAX_SNIFF_LEN.html
+/// [`from_async_reader`]: fn.from_async_reader.html
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be opened or read.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn f() -> std::io::Result<()> {
+/// use detect_lang::detect_path_async;
+///
+/// if let Some(lang) = detect_path_async("scripts/build").await? {
+///     println!("{}", lang.name());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn detect_path_async<P: AsRef<Path>>(path: P) -> io::Result<Option<Language<'static>>> {
+    let path = path.as_ref();
+    let mut file = tokio::fs::File::open(path).await?;
+    from_async_reader(&mut file, Some(path)).await
+}
+
+#[tokio::test]
+async fn detect_temp_file_async() {
+    use crate::test_util::TempDir;
+    use std::fs;
+
+    let root = TempDir::new("reader-async");
+    fs::write(root.join("build"), "#!/bin/sh\necho hello\n").unwrap();
+    fs::write(root.join("foo.rs"), "#!/bin/sh\n").unwrap();
+    fs::write(root.join("foo"), "").unwrap();
+
+    let detect = |name| detect_path_async(root.join(name));
+    assert_eq!(
+        detect("build").await.unwrap(),
+        Some(Language("Shell", "shell"))
+    );
+    assert_eq!(
+        detect("foo.rs").await.unwrap(),
+        Some(Language("Rust", "rust"))
+    );
+    assert_eq!(detect("foo").await.unwrap(), None);
+    assert!(detect("missing").await.is_err());
+
+    // The rest of the content remains in the reader
+    let content = [b'a'; crate::MAX_SNIFF_LEN + 10];
+    let mut reader = &content[..];
+    from_async_reader(&mut reader, None).await.unwrap();
+    assert_eq!(reader.len(), 10);
+}
@@ -0,0 +1,402 @@
+use std::path::Path;
+
+#[cfg(feature = "serde")]
+use std::collections::BTreeMap;
+#[cfg(feature = "serde")]
+use std::fmt;
+#[cfg(feature = "serde")]
+use std::io::Read;
+
+use crate::{DetectionSource, Language, LanguageDetector, LanguageValidationError};
+
+/// A set of custom extension mappings layered over the built-in languages.
+///
+/// A registry is a thin wrapper around a [`LanguageDetector`], where
+/// [`insert`] is the same as [`register_extension`]. Use
+/// [`from_detector`] to layer the custom mappings over a customized
+/// detector, and [`into_detector`] to use e.g. mappings loaded with
+/// [`load_from_json`] with the rest of the detector's methods.
+///
+/// Custom mappings take precedence over the built-in ones, which makes
+/// it possible to both add new extensions and override existing ones.
+///
+/// [`LanguageDetector`]: struct.LanguageDetector.html
+/// [`register_extension`]: struct.LanguageDetector.html#method.register_extension
+/// [`insert`]: #method.insert
+/// [`from_detector`]: #method.from_detector
+/// [`into_detector`]: #method.into_detector
+/// [`load_from_json`]: #method.load_from_json
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{Language, Registry};
+/// let mut registry = Registry::new();
+/// registry.insert("kicad_sch", Language("KiCad", "kicad")).unwrap();
+/// registry.insert("pl", Language("Prolog", "prolog")).unwrap();
+///
+/// assert_eq!(registry.from_extension("kicad_sch"), Some(Language("KiCad", "kicad")));
+/// assert_eq!(registry.from_path("foo.pl"), Some(Language("Prolog", "prolog")));
+///
+/// // Built-in languages are still available
+/// assert_eq!(registry.from_path("foo.rs"), Some(Language("Rust", "rust")));
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct Registry {
+    detector: LanguageDetector,
+}
+
+impl Registry {
+    /// Creates a registry containing only the built-in languages.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry layered over `detector`, such that custom
+    /// mappings are added to the detector's customizations.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{Language, LanguageDetector, Registry};
+    /// let detector = LanguageDetector::builder().disable("python").build();
+    ///
+    /// let mut registry = Registry::from_detector(detector);
+    /// registry.insert("kicad_sch", Language("KiCad", "kicad")).unwrap();
+    ///
+    /// assert_eq!(registry.from_path("foo.kicad_sch"), Some(Language("KiCad", "kicad")));
+    /// assert_eq!(registry.from_path("foo.py"), None);
+    /// ```
+    #[inline]
+    pub fn from_detector(detector: LanguageDetector) -> Self {
+        Self { detector }
+    }
+
+    /// Returns the detector containing the custom mappings.
+    #[inline]
+    pub fn detector(&self) -> &LanguageDetector {
+        &self.detector
+    }
+
+    /// Converts the registry into the detector containing the
+    /// custom mappings.
+    #[inline]
+    pub fn into_detector(self) -> LanguageDetector {
+        self.detector
+    }
+
+    /// Maps `extension` to `language`, replacing any previous custom mapping.
+    /// The casing of the extension does not affect the result.
+    ///
+    /// Returns an error and leaves the registry unchanged, if the
+    /// language is [invalid].
+    ///
+    /// *[See also `LanguageDetector::register_extension`][register_extension].*
+    ///
+    /// [invalid]: struct.Language.html#method.validate
+    /// [register_extension]: struct.LanguageDetector.html#method.register_extension
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{Language, LanguageValidationError, Registry};
+    /// # #[cfg(feature = "full")]
+    /// # {
+    /// let mut registry = Registry::new();
+    /// assert_eq!(registry.insert("kicad_sch", Language("KiCad", "kicad")), Ok(()));
+    ///
+    /// assert_eq!(
+    ///     registry.insert("pl", Language("Prolog", "Prolog")),
+    ///     Err(LanguageValidationError::InvalidId { id: "Prolog".to_owned() }),
+    /// );
+    /// assert_eq!(registry.from_extension("pl"), Some(Language("Perl", "perl")));
+    /// # }
+    /// ```
+    #[inline]
+    pub fn insert<S: AsRef<str>>(
+        &mut self,
+        extension: S,
+        language: Language<'_>,
+    ) -> Result<(), LanguageValidationError> {
+        self.detector.register_extension(extension, language)
+    }
+
+    /// Identifies a language from a path, checking the custom
+    /// mappings before the built-in languages.
+    /// Returns `None` if the language was not identified.
+    ///
+    /// *[See also `LanguageDetector::from_path`][from_path].*
+    ///
+    /// [from_path]: struct.LanguageDetector.html#method.from_path
+    #[inline]
+    pub fn from_path<P: AsRef<Path>>(&self, path: P) -> Option<Language<'_>> {
+        self.detector.from_path(path)
+    }
+
+    /// Identifies a language from a file extension, checking the custom
+    /// mappings before the built-in languages.
+    /// The casing of the extension does not affect the result.
+    /// Returns `None` if the language was not identified.
+    ///
+    /// *[See also `LanguageDetector::from_extension`][from_extension].*
+    ///
+    /// [from_extension]: struct.LanguageDetector.html#method.from_extension
+    #[inline]
+    pub fn from_extension<S: AsRef<str>>(&self, extension: S) -> Option<Language<'_>> {
+        self.detector.from_extension(extension)
+    }
+
+    /// Identifies a language from a path and optionally the content of the file,
+    /// along with how the language was identified, checking the custom
+    /// mappings before the built-in languages.
+    /// Returns `None` if the language was not identified.
+    ///
+    /// *[See also `LanguageDetector::detect_explained`][detect_explained].*
+    ///
+    /// [detect_explained]: struct.LanguageDetector.html#method.detect_explained
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{DetectionSource, Language, Registry};
+    /// let mut registry = Registry::new();
+    /// registry.insert("pl", Language("Prolog", "prolog")).unwrap();
+    ///
+    /// assert_eq!(
+    ///     registry.detect_explained("foo.pl", None),
+    ///     Some((Language("Prolog", "prolog"), DetectionSource::UserOverride)),
+    /// );
+    /// assert_eq!(
+    ///     registry.detect_explained("foo.rs", None),
+    ///     Some((Language("Rust", "rust"), DetectionSource::BuiltinExtension)),
+    /// );
+    /// ```
+    #[inline]
+    pub fn detect_explained<P: AsRef<Path>>(
+        &self,
+        path: P,
+        content: Option<&[u8]>,
+    ) -> Option<(Language<'_>, DetectionSource)> {
+        self.detector.detect_explained(path, content)
+    }
+}
+
+impl From<LanguageDetector> for Registry {
+    #[inline]
+    fn from(detector: LanguageDetector) -> Self {
+        Self::from_detector(detector)
+    }
+}
+
+impl From<Registry> for LanguageDetector {
+    #[inline]
+    fn from(registry: Registry) -> Self {
+        registry.into_detector()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct Entry {
+    extension: String,
+    name: String,
+    id: String,
+}
+
+#[cfg(feature = "serde")]
+impl Registry {
+    /// Loads custom extension mappings from JSON, which are merged over
+    /// the built-in languages. Use [`into_detector`] to get the
+    /// resulting [`LanguageDetector`].
+    ///
+    /// The JSON must be an array of objects, each containing an
+    /// `extension`, `name`, and `id`. Every language must be [valid],
+    /// i.e. the `id` must be a lowercase [URL slug], and each extension
+    /// may only occur once.
+    ///
+    /// *This function requires the `serde` feature.*
+    ///
+    /// [`into_detector`]: #method.into_detector
+    /// [`LanguageDetector`]: struct.LanguageDetector.html
+    /// [valid]: struct.Language.html#method.validate
+    /// [URL slug]: https://en.wikipedia.org/wiki/Clean_URL#Slug
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{Language, LoadError, Registry};
+    /// let json = r#"[
+    ///     { "extension": "kicad_sch", "name": "KiCad", "id": "kicad" },
+    ///     { "extension": "pl", "name": "Prolog", "id": "prolog" }
+    /// ]"#;
+    ///
+    /// let registry = Registry::load_from_json(json.as_bytes()).unwrap();
+    /// assert_eq!(registry.from_extension("kicad_sch"), Some(Language("KiCad", "kicad")));
+    /// assert_eq!(registry.from_extension("pl"), Some(Language("Prolog", "prolog")));
+    /// assert_eq!(registry.from_extension("rs"), Some(Language("Rust", "rust")));
+    ///
+    /// let detector = registry.into_detector();
+    /// assert_eq!(detector.from_path("foo.kicad_sch"), Some(Language("KiCad", "kicad")));
+    ///
+    /// let json = r#"[{ "extension": "foo", "name": "Foo", "id": "Foo Bar" }]"#;
+    /// assert!(matches!(
+    ///     Registry::load_from_json(json.as_bytes()),
+    ///     Err(LoadError::InvalidId { .. }),
+    /// ));
+    /// ```
+    pub fn load_from_json<R: Read>(reader: R) -> Result<Registry, LoadError> {
+        let entries: Vec<Entry> = serde_json::from_reader(reader).map_err(LoadError::Json)?;
+
+        // The ID given for each lowercase extension so far
+        let mut ids = BTreeMap::<String, String>::new();
+        let mut registry = Registry::new();
+        for Entry {
+            extension,
+            name,
+            id,
+        } in entries
+        {
+            let ext = extension.to_ascii_lowercase();
+            if let Some(prev_id) = ids.get(&ext) {
+                return Err(if *prev_id == id {
+                    LoadError::DuplicateExtension { extension }
+                } else {
+                    LoadError::ConflictingExtension {
+                        extension,
+                        first: prev_id.clone(),
+                        second: id,
+                    }
+                });
+            }
+
+            registry
+                .insert(&extension, Language(&name, &id))
+                .map_err(|err| match err {
+                    LanguageValidationError::InvalidId { id } => {
+                        LoadError::InvalidId { extension, id }
+                    }
+                    error => LoadError::InvalidLanguage { extension, error },
+                })?;
+            ids.insert(ext, id);
+        }
+
+        Ok(registry)
+    }
+}
+
+/// Errors returned by [`Registry::load_from_json`].
+///
+/// *This type requires the `serde` feature.*
+///
+/// [`Registry::load_from_json`]: struct.Registry.html#method.load_from_json
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub enum LoadError {
+    /// The input could not be read or was not valid JSON.
+    Json(serde_json::Error),
+    /// The `id` of a language is not a lowercase URL slug.
+    InvalidId {
+        /// The extension the language was given for.
+        extension: String,
+        /// The invalid language ID.
+        id: String,
+    },
+    /// The name or ID of a language is empty.
+    InvalidLanguage {
+        /// The extension the language was given for.
+        extension: String,
+        /// Why the language is invalid.
+        error: LanguageValidationError,
+    },
+    /// The same extension was given more than once for the same language.
+    DuplicateExtension {
+        /// The duplicated extension.
+        extension: String,
+    },
+    /// The same extension was given for different languages.
+    ConflictingExtension {
+        /// The conflicting extension.
+        extension: String,
+        /// The language ID given first.
+        first: String,
+        /// The language ID given second.
+        second: String,
+    },
+}
+
+#[cfg(feature = "serde")]
+impl fmt::Display for LoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Json(err) => write!(f, "invalid json: {}", err),
+            Self::InvalidId { extension, id } => write!(
+                f,
+                "invalid id {:?} for extension {:?}, expected a lowercase slug",
+                id, extension
+            ),
+            Self::InvalidLanguage { extension, error } => {
+                write!(f, "{} for extension {:?}", error, extension)
+            }
+            Self::DuplicateExtension { extension } => {
+                write!(f, "duplicate extension {:?}", extension)
+            }
+            Self::ConflictingExtension {
+                extension,
+                first,
+                second,
+            } => write!(
+                f,
+                "extension {:?} is mapped to both {:?} and {:?}",
+                extension, first, second
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl std::error::Error for LoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(err) => Some(err),
+            Self::InvalidLanguage { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn check_load_errors() {
+    let json = r#"[
+        { "extension": "foo", "name": "Foo", "id": "foo" },
+        { "extension": "FOO", "name": "Foo", "id": "foo" }
+    ]"#;
+    match Registry::load_from_json(json.as_bytes()) {
+        Err(LoadError::DuplicateExtension { extension }) => assert_eq!(extension, "FOO"),
+        result => panic!("expected DuplicateExtension, got {:?}", result),
+    }
+
+    let json = r#"[
+        { "extension": "foo", "name": "Foo", "id": "foo" },
+        { "extension": "foo", "name": "Bar", "id": "bar" }
+    ]"#;
+    match Registry::load_from_json(json.as_bytes()) {
+        Err(LoadError::ConflictingExtension {
+            extension,
+            first,
+            second,
+        }) => {
+            assert_eq!(extension, "foo");
+            assert_eq!(first, "foo");
+            assert_eq!(second, "bar");
+        }
+        result => panic!("expected ConflictingExtension, got {:?}", result),
+    }
+
+    let json = r#"[
+        { "extension": "foo", "name": "Foo", "id": "foo" },
+        { "extension": "bar", "name": "Bar", "id": "bar" }
+    ]"#;
+    assert!(Registry::load_from_json(json.as_bytes()).is_ok());
+}
@@ -0,0 +1,181 @@
+use core::str;
+
+use crate::Language;
+
+macro_rules! i {
+    ($interpreter:literal, $name:literal, $id:literal) => {
+        ($interpreter, Language($name, $id))
+    };
+}
+
+// The array is sorted by the interpreter name
+//
+// Interpreters of languages not included in the `common` feature are
+// only included when the `full` feature is enabled.
+pub(crate) static INTERPRETERS: &[(&str, Language)] = &[
+    i!("bash", "Shell", "shell"),
+    i!("dash", "Shell", "shell"),
+    i!("deno", "TypeScript", "typescript"),
+    #[cfg(feature = "full")]
+    i!("groovy", "Groovy", "groovy"),
+    i!("ksh", "Shell", "shell"),
+    #[cfg(feature = "full")]
+    i!("lua", "Lua", "lua"),
+    i!("node", "JavaScript", "javascript"),
+    #[cfg(feature = "full")]
+    i!("nu", "Nushell", "nushell"),
+    #[cfg(feature = "full")]
+    i!("perl", "Perl", "perl"),
+    #[cfg(feature = "full")]
+    i!("php", "PHP", "php"),
+    #[cfg(feature = "full")]
+    i!("pwsh", "PowerShell", "powershell"),
+    i!("python", "Python", "python"),
+    #[cfg(feature = "full")]
+    i!("ruby", "Ruby", "ruby"),
+    i!("sh", "Shell", "shell"),
+    i!("ts-node", "TypeScript", "typescript"),
+    i!("zsh", "Shell", "shell"),
+];
+
+/// Identifies a language from the interpreter of a `#!` line,
+/// e.g. `#!/bin/bash` or `#!/usr/bin/env python3`.
+/// Returns `None` if the language was not identified.
+///
+/// Either the first line or the whole content of a file can be given,
+/// in which case only the first line is inspected. Options given to
+/// `env` are skipped, e.g. `#!/usr/bin/env -S python3 -u`, and
+/// versions are ignored, e.g. `python3.8`.
+///
+/// *[See also `from_shebang_bytes`][from_shebang_bytes].*
+///
+/// [from_shebang_bytes]: fn.from_shebang_bytes.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_shebang, Language};
+/// assert_eq!(from_shebang("#!/bin/bash"), Some(Language("Shell", "shell")));
+/// assert_eq!(from_shebang("#!/usr/bin/env python3"), Some(Language("Python", "python")));
+/// assert_eq!(from_shebang("#!/usr/bin/env -S node --harmony"), Some(Language("JavaScript", "javascript")));
+/// assert_eq!(from_shebang("#!/usr/bin/python3.8 -u\nprint('Hello World')\n"), Some(Language("Python", "python")));
+///
+/// assert_eq!(from_shebang("#!/usr/bin/unknown"), None);
+/// assert_eq!(from_shebang("print('Hello World')"), None);
+/// assert_eq!(from_shebang("\n#!/bin/sh"), None);
+/// ```
+pub fn from_shebang(content: &str) -> Option<Language<'static>> {
+    let line = content.lines().next()?;
+    let line = line.strip_prefix("#!")?;
+    let mut args = line.split_whitespace();
+
+    let mut interpreter = basename(args.next()?);
+    if interpreter == "env" {
+        // Skip `env` options, e.g. `#!/usr/bin/env -S python3 -u`
+        interpreter = basename(args.find(|arg| !arg.starts_with('-'))?);
+    }
+
+    from_interpreter(interpreter)
+}
+
+/// Identifies a language from the interpreter of a `#!` line, given
+/// as bytes. Returns `None` if the language was not identified, or if
+/// the first line is not valid UTF-8.
+///
+/// *[See also `from_shebang`][from_shebang].*
+///
+/// [from_shebang]: fn.from_shebang.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_shebang_bytes, Language};
+/// assert_eq!(from_shebang_bytes(b"#!/bin/sh\n\xFF\xFE"), Some(Language("Shell", "shell")));
+/// assert_eq!(from_shebang_bytes(b"#!/bin/\xFF"), None);
+/// ```
+pub fn from_shebang_bytes(content: &[u8]) -> Option<Language<'static>> {
+    let line = match content.iter().position(|&b| b == b'\n') {
+        Some(end) => &content[..end],
+        None => content,
+    };
+    from_shebang(str::from_utf8(line).ok()?)
+}
+
+/// Identifies a language from the name or path of an interpreter,
+/// e.g. `python3` or `/usr/bin/node`.
+/// Returns `None` if the language was not identified.
+///
+/// Versions are ignored, e.g. `python3.8`. This is useful when the
+/// interpreter is known from elsewhere than a `#!` line, e.g. the
+/// command line of a process.
+///
+/// *[See also `from_shebang`][from_shebang].*
+///
+/// [from_shebang]: fn.from_shebang.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_interpreter, Language};
+/// assert_eq!(from_interpreter("python3"), Some(Language("Python", "python")));
+/// assert_eq!(from_interpreter("/usr/bin/node"), Some(Language("JavaScript", "javascript")));
+/// assert_eq!(from_interpreter("bash"), Some(Language("Shell", "shell")));
+/// assert_eq!(from_interpreter("deno"), Some(Language("TypeScript", "typescript")));
+///
+/// assert_eq!(from_interpreter("unknown"), None);
+/// assert_eq!(from_interpreter(""), None);
+/// ```
+pub fn from_interpreter(interpreter: &str) -> Option<Language<'static>> {
+    let interpreter = basename(interpreter);
+    let find = |interpreter: &str| {
+        INTERPRETERS
+            .binary_search_by_key(&interpreter, |&(name, _)| name)
+            .ok()
+            .map(|i| INTERPRETERS[i].1)
+    };
+
+    // Fallback to ignoring versions, e.g. `python3.8`
+    find(interpreter)
+        .or_else(|| find(interpreter.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.')))
+}
+
+fn basename(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_interpreters() {
+    assert_eq!(from_interpreter("php"), Some(Language("PHP", "php")));
+    assert_eq!(from_interpreter("php8.2"), Some(Language("PHP", "php")));
+    assert_eq!(from_interpreter("perl5"), Some(Language("Perl", "perl")));
+    assert_eq!(
+        from_shebang("#!/usr/bin/env php"),
+        Some(Language("PHP", "php"))
+    );
+    assert_eq!(from_interpreter("env"), None);
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in INTERPRETERS.iter().zip(INTERPRETERS.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "Interpreters out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_languages() {
+    for &(interpreter, lang) in INTERPRETERS {
+        assert_eq!(
+            crate::languages::from_id(lang.id()),
+            Some(lang),
+            "Missing language for {:?}",
+            interpreter
+        );
+    }
+}
@@ -0,0 +1,587 @@
+use std::collections::BTreeMap;
+use std::ffi::OsStr;
+use std::io;
+use std::ops::AddAssign;
+use std::path::Path;
+
+use crate::{is_generated, is_vendored, Category, Language};
+
+/// The number of files and their total size in bytes.
+///
+/// *[See also `LanguageStats`][LanguageStats].*
+///
+/// [LanguageStats]: struct.LanguageStats.html
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug)]
+pub struct FileStats {
+    /// The number of files.
+    pub files: u64,
+    /// The total size of the files in bytes.
+    pub bytes: u64,
+}
+
+impl AddAssign for FileStats {
+    #[inline]
+    fn add_assign(&mut self, other: Self) {
+        self.files += other.files;
+        self.bytes += other.bytes;
+    }
+}
+
+/// The number of files and bytes of each language, e.g. as returned
+/// by [`scan_dir`].
+///
+/// Files are also tracked by whether they are [vendored] or [generated],
+/// such that they can be excluded from the [`percentages`].
+///
+/// [`scan_dir`]: fn.scan_dir.html
+/// [vendored]: fn.is_vendored.html
+/// [generated]: fn.is_generated.html
+/// [`percentages`]: #method.percentages
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{FileStats, Language, LanguageStats};
+/// let mut stats = LanguageStats::new();
+/// stats.add_file("src/main.rs", 30);
+/// stats.add_file("src/lib.rs", 10);
+/// stats.add_file("README.md", 5);
+/// stats.add_file("foo.unknown", 100);
+///
+/// let rust = Language("Rust", "rust");
+/// assert_eq!(stats.get(rust), FileStats { files: 2, bytes: 40 });
+/// assert_eq!(stats.total(), FileStats { files: 3, bytes: 45 });
+/// assert_eq!(stats.unidentified(), FileStats { files: 1, bytes: 100 });
+/// assert_eq!(stats.len(), 2);
+/// ```
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct LanguageStats {
+    languages: BTreeMap<Language<'static>, Buckets>,
+    unidentified: FileStats,
+}
+
+/// The files of a language, split by whether they are vendored and
+/// whether they are generated.
+#[derive(PartialEq, Eq, Clone, Copy, Default, Debug)]
+struct Buckets([FileStats; 4]);
+
+const VENDORED: usize = 0b01;
+const GENERATED: usize = 0b10;
+
+impl Buckets {
+    fn total(&self, options: &PercentageOptions) -> FileStats {
+        let mut total = FileStats::default();
+        for (flags, &stats) in self.0.iter().enumerate() {
+            let excluded = (options.exclude_vendored && flags & VENDORED != 0)
+                || (options.exclude_generated && flags & GENERATED != 0);
+            if !excluded {
+                total += stats;
+            }
+        }
+        total
+    }
+}
+
+impl LanguageStats {
+    /// Creates empty statistics.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Identifies the language of `path` using [`from_path`], and adds
+    /// a file of `bytes` bytes to the language. If the language was not
+    /// identified, then the file is added to the [unidentified] files.
+    ///
+    /// `path` should be relative to the repository root, for checking
+    /// whether the file is [vendored] or [generated].
+    ///
+    /// [`from_path`]: fn.from_path.html
+    /// [unidentified]: #method.unidentified
+    /// [vendored]: fn.is_vendored.html
+    /// [generated]: fn.is_generated.html
+    pub fn add_file<P: AsRef<Path>>(&mut self, path: P, bytes: u64) {
+        let path = path.as_ref();
+        let mut flags = 0;
+        if is_vendored(path) {
+            flags |= VENDORED;
+        }
+        if is_generated(path) {
+            flags |= GENERATED;
+        }
+        self.insert(crate::from_path(path), bytes, flags);
+    }
+
+    /// Adds a file of `bytes` bytes to `lang`, or to the [unidentified]
+    /// files if `lang` is `None`. The file is neither vendored nor generated.
+    ///
+    /// [unidentified]: #method.unidentified
+    #[inline]
+    pub fn add(&mut self, lang: Option<Language<'static>>, bytes: u64) {
+        self.insert(lang, bytes, 0);
+    }
+
+    /// Adds all files of `other`, e.g. from scanning another directory.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{FileStats, Language, LanguageStats};
+    /// let mut a = LanguageStats::new();
+    /// a.add_file("foo.rs", 10);
+    /// let mut b = LanguageStats::new();
+    /// b.add_file("bar.rs", 20);
+    ///
+    /// a.merge(b);
+    /// assert_eq!(a.get(Language("Rust", "rust")), FileStats { files: 2, bytes: 30 });
+    /// ```
+    pub fn merge(&mut self, other: LanguageStats) {
+        for (lang, other) in other.languages {
+            let buckets = self.languages.entry(lang).or_default();
+            for (stats, other) in buckets.0.iter_mut().zip(other.0) {
+                *stats += other;
+            }
+        }
+        self.unidentified += other.unidentified;
+    }
+
+    fn insert(&mut self, lang: Option<Language<'static>>, bytes: u64, flags: usize) {
+        let stats = match lang {
+            Some(lang) => &mut self.languages.entry(lang).or_default().0[flags],
+            None => &mut self.unidentified,
+        };
+        *stats += FileStats { files: 1, bytes };
+    }
+
+    /// Returns the statistics of `lang`, which are zero if no files
+    /// of `lang` were added.
+    #[inline]
+    pub fn get(&self, lang: Language<'_>) -> FileStats {
+        self.languages
+            .get(&lang)
+            .map(|buckets| buckets.total(&PercentageOptions::new()))
+            .unwrap_or_default()
+    }
+
+    /// Returns the statistics of each language, sorted by language.
+    /// Languages without any files are not included.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Language<'static>, FileStats)> + '_ {
+        let options = PercentageOptions::new();
+        self.languages
+            .iter()
+            .map(move |(&lang, buckets)| (lang, buckets.total(&options)))
+    }
+
+    /// Returns the combined statistics of all files where the
+    /// language was identified.
+    pub fn total(&self) -> FileStats {
+        let mut total = FileStats::default();
+        for (_, stats) in self.iter() {
+            total += stats;
+        }
+        total
+    }
+
+    /// Returns the share of bytes of each language in percent, similar
+    /// to the language bar of GitHub repositories. The result is sorted
+    /// by percentage in descending order, followed by language.
+    ///
+    /// Like GitHub, [vendored] and [generated] files, and data and prose
+    /// languages, e.g. JSON and Markdown, are excluded. Use
+    /// [`percentages_with`] to choose what is excluded.
+    ///
+    /// [vendored]: fn.is_vendored.html
+    /// [generated]: fn.is_generated.html
+    /// [`percentages_with`]: #method.percentages_with
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{Language, LanguageStats};
+    /// let mut stats = LanguageStats::new();
+    /// stats.add_file("src/main.rs", 300);
+    /// stats.add_file("build.py", 100);
+    /// stats.add_file("README.md", 1000);
+    /// stats.add_file("node_modules/foo/index.js", 1000);
+    ///
+    /// assert_eq!(
+    ///     stats.percentages(),
+    ///     [(Language("Rust", "rust"), 75.0), (Language("Python", "python"), 25.0)],
+    /// );
+    /// ```
+    #[inline]
+    pub fn percentages(&self) -> Vec<(Language<'static>, f64)> {
+        self.percentages_with(&PercentageOptions::github())
+    }
+
+    /// Returns the share of bytes of each language in percent, excluding
+    /// files as given by `options`. The result is sorted by percentage in
+    /// descending order, followed by language.
+    ///
+    /// [Binary] files and [system files] are always excluded. Languages
+    /// without any bytes are not included, and the result is empty if
+    /// no bytes remain.
+    ///
+    /// [Binary]: struct.Language.html#method.is_binary
+    /// [system files]: struct.Language.html#method.is_system_file
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{Language, LanguageStats, PercentageOptions};
+    /// let mut stats = LanguageStats::new();
+    /// stats.add_file("src/main.rs", 300);
+    /// stats.add_file("README.md", 100);
+    /// stats.add_file("Cargo.lock", 1000);
+    ///
+    /// let options = PercentageOptions::new().exclude_generated(true);
+    /// assert_eq!(
+    ///     stats.percentages_with(&options),
+    ///     [(Language("Rust", "rust"), 75.0), (Language("Markdown", "markdown"), 25.0)],
+    /// );
+    /// ```
+    pub fn percentages_with(&self, options: &PercentageOptions) -> Vec<(Language<'static>, f64)> {
+        let included = self.bytes_with(options);
+        let total = included.iter().map(|&(_, bytes)| bytes).sum::<u64>() as f64;
+        included
+            .into_iter()
+            .map(|(lang, bytes)| (lang, bytes as f64 / total * 100.0))
+            .collect()
+    }
+
+    /// Returns the bytes of each language, excluding files as given by
+    /// `options`, as by [`percentages_with`]. The result is sorted by
+    /// bytes in descending order, followed by language.
+    ///
+    /// [`percentages_with`]: #method.percentages_with
+    pub(crate) fn bytes_with(&self, options: &PercentageOptions) -> Vec<(Language<'static>, u64)> {
+        let mut included = self
+            .languages
+            .iter()
+            .filter(|(lang, _)| options.includes(lang))
+            .map(|(&lang, buckets)| (lang, buckets.total(options).bytes))
+            .filter(|&(_, bytes)| bytes > 0)
+            .collect::<Vec<_>>();
+        included.sort_unstable_by(|(a, a_bytes), (b, b_bytes)| b_bytes.cmp(a_bytes).then(a.cmp(b)));
+        included
+    }
+
+    /// Returns the statistics of files where the language was not
+    /// identified.
+    #[inline]
+    pub fn unidentified(&self) -> FileStats {
+        self.unidentified
+    }
+
+    /// Returns the number of languages.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.languages.len()
+    }
+
+    /// Returns `true` if no files of any language were added.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.languages.is_empty()
+    }
+}
+
+/// What to exclude from [`LanguageStats::percentages_with`].
+///
+/// [`LanguageStats::percentages_with`]: struct.LanguageStats.html#method.percentages_with
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::PercentageOptions;
+/// let options = PercentageOptions::new()
+///     .exclude_vendored(true)
+///     .exclude_data(true);
+/// # let _ = options;
+/// ```
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default, Debug)]
+pub struct PercentageOptions {
+    exclude_vendored: bool,
+    exclude_generated: bool,
+    exclude_data: bool,
+    exclude_prose: bool,
+}
+
+impl PercentageOptions {
+    /// Creates options which exclude nothing, besides binary files and
+    /// system files.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates options similar to the language bar of GitHub repositories,
+    /// which exclude vendored and generated files, and data and prose languages.
+    #[inline]
+    pub fn github() -> Self {
+        Self {
+            exclude_vendored: true,
+            exclude_generated: true,
+            exclude_data: true,
+            exclude_prose: true,
+        }
+    }
+
+    /// Excludes [vendored] files, e.g. inside `node_modules/`.
+    ///
+    /// [vendored]: fn.is_vendored.html
+    #[inline]
+    pub fn exclude_vendored(mut self, exclude: bool) -> Self {
+        self.exclude_vendored = exclude;
+        self
+    }
+
+    /// Excludes [generated] files, e.g. `Cargo.lock`.
+    ///
+    /// [generated]: fn.is_generated.html
+    #[inline]
+    pub fn exclude_generated(mut self, exclude: bool) -> Self {
+        self.exclude_generated = exclude;
+        self
+    }
+
+    /// Excludes [data] languages, e.g. JSON and YAML.
+    ///
+    /// [data]: enum.Category.html#variant.Data
+    #[inline]
+    pub fn exclude_data(mut self, exclude: bool) -> Self {
+        self.exclude_data = exclude;
+        self
+    }
+
+    /// Excludes [prose] languages, e.g. Markdown and plain text.
+    ///
+    /// [prose]: enum.Category.html#variant.Prose
+    #[inline]
+    pub fn exclude_prose(mut self, exclude: bool) -> Self {
+        self.exclude_prose = exclude;
+        self
+    }
+
+    fn includes(&self, lang: &Language<'_>) -> bool {
+        if lang.is_binary() || lang.is_system_file() {
+            return false;
+        }
+        match lang.category() {
+            Some(Category::Data) => !self.exclude_data,
+            Some(Category::Prose) => !self.exclude_prose,
+            Some(Category::Archive) | Some(Category::Other) => false,
+            _ => true,
+        }
+    }
+}
+
+/// Recursively walks the directory `root`, identifying each file using
+/// [`from_path`], and counting the files and bytes of each language.
+///
+/// Symbolic links are not followed, and are skipped. `.git`
+/// directories are skipped. Files are checked for whether they are
+/// vendored or generated relative to `root`.
+///
+/// With the `ignore` feature, files ignored by `.gitignore` and `.ignore`
+/// files, and by global git excludes, are skipped, e.g. build artifacts.
+///
+/// *[See also `breakdown_by_bytes`][breakdown_by_bytes].*
+///
+/// [`from_path`]: fn.from_path.html
+/// [breakdown_by_bytes]: fn.breakdown_by_bytes.html
+///
+/// # Errors
+///
+/// Returns an error if `root`, any directory within it, or the
+/// metadata of any file cannot be read.
+///
+/// # Example
+///
+/// ```no_run
+/// # fn main() -> std::io::Result<()> {
+/// use detect_lang::scan_dir;
+///
+/// let stats = scan_dir(".")?;
+/// for (lang, stats) in stats.iter() {
+///     println!("{}: {} files, {} bytes", lang.name(), stats.files, stats.bytes);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub fn scan_dir<P: AsRef<Path>>(root: P) -> io::Result<LanguageStats> {
+    scan(root.as_ref())
+}
+
+#[cfg(not(feature = "ignore"))]
+fn scan(root: &Path) -> io::Result<LanguageStats> {
+    let mut stats = LanguageStats::new();
+
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            // `DirEntry::file_type` does not follow symbolic links
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                if !is_skipped_dir(&entry.file_name()) {
+                    pending.push(entry.path());
+                }
+            } else if file_type.is_file() {
+                add_path(&mut stats, root, &entry.path(), entry.metadata()?.len());
+            }
+        }
+    }
+
+    Ok(stats)
+}
+
+#[cfg(feature = "ignore")]
+fn scan(root: &Path) -> io::Result<LanguageStats> {
+    let mut stats = LanguageStats::new();
+    for entry in walker(root).build() {
+        let entry = entry.map_err(to_io_error)?;
+        if entry
+            .file_type()
+            .is_some_and(|file_type| file_type.is_file())
+        {
+            let bytes = entry.metadata().map_err(to_io_error)?.len();
+            add_path(&mut stats, root, entry.path(), bytes);
+        }
+    }
+    Ok(stats)
+}
+
+/// Returns `true` if the directory named `name` is skipped when walking
+/// a directory, i.e. `.git`.
+#[inline]
+pub(crate) fn is_skipped_dir(name: &OsStr) -> bool {
+    name == ".git"
+}
+
+/// Walks `root` while skipping files ignored by `.gitignore`, `.ignore`,
+/// and global git excludes, but not hidden files.
+#[cfg(feature = "ignore")]
+pub(crate) fn walker(root: &Path) -> ignore::WalkBuilder {
+    let mut walker = ignore::WalkBuilder::new(root);
+    walker
+        .hidden(false)
+        // Honor `.gitignore` files in folders which are not git repositories
+        .require_git(false)
+        .filter_entry(|entry| !is_skipped_dir(entry.file_name()));
+    walker
+}
+
+/// Converts `err` to an `io::Error`, preserving the kind of the
+/// underlying `io::Error` if any, along with the path of `err`.
+#[cfg(feature = "ignore")]
+pub(crate) fn to_io_error(err: ignore::Error) -> io::Error {
+    let kind = err.io_error().map_or(io::ErrorKind::Other, io::Error::kind);
+    io::Error::new(kind, err)
+}
+
+pub(crate) fn add_path(stats: &mut LanguageStats, root: &Path, path: &Path, bytes: u64) {
+    // Vendored and generated files are relative to the root
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    stats.add_file(relative, bytes);
+}
+
+#[test]
+fn scan_temp_dir() {
+    use crate::test_util::TempDir;
+    use std::fs;
+
+    let root = TempDir::new("stats");
+    fs::create_dir_all(root.join("src/nested")).unwrap();
+    fs::create_dir_all(root.join(".git")).unwrap();
+    fs::write(root.join("README.md"), [b'a'; 10]).unwrap();
+    fs::write(root.join("src/main.rs"), [b'a'; 30]).unwrap();
+    fs::write(root.join("src/nested/lib.rs"), [b'a'; 5]).unwrap();
+    fs::write(root.join("src/nested/foo.py"), [b'a'; 20]).unwrap();
+    fs::write(root.join("src/nested/foo.unknown"), [b'a'; 100]).unwrap();
+    fs::write(root.join(".git/config"), [b'a'; 100]).unwrap();
+    fs::create_dir_all(root.join("node_modules/foo")).unwrap();
+    fs::write(root.join("node_modules/foo/index.js"), [b'a'; 100]).unwrap();
+
+    let file_stats = |files, bytes| FileStats { files, bytes };
+    let stats = scan_dir(&root).unwrap();
+    assert_eq!(
+        stats.iter().collect::<Vec<_>>(),
+        [
+            (Language("JavaScript", "javascript"), file_stats(1, 100)),
+            (Language("Markdown", "markdown"), file_stats(1, 10)),
+            (Language("Python", "python"), file_stats(1, 20)),
+            (Language("Rust", "rust"), file_stats(2, 35)),
+        ]
+    );
+    assert_eq!(stats.total(), file_stats(5, 165));
+    assert_eq!(stats.unidentified(), file_stats(1, 100));
+    assert_eq!(stats.get(Language("C", "c")), FileStats::default());
+
+    let rust = 35.0 / 55.0 * 100.0;
+    let python = 20.0 / 55.0 * 100.0;
+    assert_eq!(
+        stats.percentages(),
+        [
+            (Language("Rust", "rust"), rust),
+            (Language("Python", "python"), python),
+        ]
+    );
+
+    assert!(scan_dir(root.join("missing")).is_err());
+}
+
+#[test]
+fn check_percentages() {
+    let mut stats = LanguageStats::new();
+    assert!(stats.percentages().is_empty());
+
+    stats.add_file("README.md", 100);
+    stats.add_file("data/foo.json", 100);
+    stats.add_file("foo.zip", 100);
+    stats.add_file("foo.unknown", 100);
+    assert!(stats.percentages().is_empty());
+
+    let options = PercentageOptions::github().exclude_prose(false);
+    assert_eq!(
+        stats.percentages_with(&options),
+        [(Language("Markdown", "markdown"), 100.0)]
+    );
+    assert_eq!(
+        stats.percentages_with(&PercentageOptions::new()),
+        [
+            (Language("JSON", "json"), 50.0),
+            (Language("Markdown", "markdown"), 50.0),
+        ]
+    );
+}
+
+#[cfg(feature = "ignore")]
+#[test]
+fn scan_ignored() {
+    use crate::test_util::TempDir;
+    use std::fs;
+
+    let root = TempDir::new("ignore");
+    fs::create_dir_all(root.join("target/debug")).unwrap();
+    fs::create_dir_all(root.join("src")).unwrap();
+    fs::write(root.join(".gitignore"), "/target\n*.log\n").unwrap();
+    fs::write(root.join(".ignore"), "generated.rs\n").unwrap();
+    fs::write(root.join("src/main.rs"), [b'a'; 10]).unwrap();
+    fs::write(root.join("src/generated.rs"), [b'a'; 10]).unwrap();
+    fs::write(root.join("src/.hidden.py"), [b'a'; 10]).unwrap();
+    fs::write(root.join("target/debug/build.rs"), [b'a'; 10]).unwrap();
+    fs::write(root.join("foo.log"), [b'a'; 10]).unwrap();
+
+    let stats = scan_dir(&root).unwrap();
+    assert_eq!(stats.get(Language("Rust", "rust")).files, 1);
+    assert_eq!(stats.get(Language("Python", "python")).files, 1);
+    #[cfg(feature = "rayon")]
+    assert_eq!(crate::scan_dir_par(&root).unwrap(), stats);
+
+    assert!(scan_dir(root.join("missing")).is_err());
+    #[cfg(feature = "rayon")]
+    assert!(crate::scan_dir_par(root.join("missing")).is_err());
+}
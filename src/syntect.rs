@@ -0,0 +1,144 @@
+use syntect::parsing::{SyntaxReference, SyntaxSet};
+
+use crate::Language;
+
+// The array is sorted by the language ID
+//
+// Names are the ones of the syntaxes included in
+// `SyntaxSet::load_defaults_newlines`
+static SYNTECT_NAMES: [(&str, &str); 38] = [
+    ("batch", "Batch File"),
+    ("bibtex", "BibTeX"),
+    ("c", "C"),
+    ("clojure", "Clojure"),
+    ("common-lisp", "Lisp"),
+    ("cpp", "C++"),
+    ("csharp", "C#"),
+    ("css", "CSS"),
+    ("d", "D"),
+    ("go", "Go"),
+    ("groovy", "Groovy"),
+    ("haml", "Ruby Haml"),
+    ("html", "HTML"),
+    ("html-erb", "HTML (Rails)"),
+    ("java", "Java"),
+    ("javascript", "JavaScript"),
+    ("json", "JSON"),
+    ("lua", "Lua"),
+    ("make", "Makefile"),
+    ("markdown", "Markdown"),
+    ("matlab", "MATLAB"),
+    ("objective-c", "Objective-C"),
+    ("pascal", "Pascal"),
+    ("perl", "Perl"),
+    ("php", "PHP"),
+    ("python", "Python"),
+    ("r", "R"),
+    ("restructuredtext", "reStructuredText"),
+    ("ruby", "Ruby"),
+    ("rust", "Rust"),
+    ("scala", "Scala"),
+    ("shell", "Bourne Again Shell (bash)"),
+    ("sql", "SQL"),
+    ("tex", "LaTeX"),
+    ("text", "Plain Text"),
+    ("textile", "Textile"),
+    ("xml", "XML"),
+    ("yaml", "YAML"),
+];
+
+impl Language<'_> {
+    /// Returns the name of the [syntect] syntax of the language, as
+    /// included in the default syntaxes, e.g. `Bourne Again Shell (bash)`
+    /// for Shell. Returns `None` if the language is unknown or has no
+    /// default syntax.
+    ///
+    /// *[See also `find_syntax`][find_syntax].*
+    ///
+    /// *This function requires the `syntect` feature.*
+    ///
+    /// [syntect]: https://docs.rs/syntect
+    /// [find_syntax]: #method.find_syntax
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// assert_eq!(from_extension("rs").unwrap().syntect_name(), Some("Rust"));
+    /// assert_eq!(from_extension("sh").unwrap().syntect_name(), Some("Bourne Again Shell (bash)"));
+    /// assert_eq!(Language("Foo", "foo").syntect_name(), None);
+    /// ```
+    pub fn syntect_name(&self) -> Option<&'static str> {
+        SYNTECT_NAMES
+            .binary_search_by_key(&self.id(), |&(id, _)| id)
+            .ok()
+            .map(|i| SYNTECT_NAMES[i].1)
+    }
+
+    /// Finds the syntax of the language in a [syntect] `SyntaxSet`.
+    /// Returns `None` if the set has no syntax for the language.
+    ///
+    /// The syntax is found by its [name], otherwise by the
+    /// [extensions] of the language, which also finds syntaxes
+    /// that are not included in the default syntaxes.
+    ///
+    /// *This function requires the `syntect` feature.*
+    ///
+    /// [syntect]: https://docs.rs/syntect
+    /// [name]: #method.syntect_name
+    /// [extensions]: #method.extensions
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::from_path;
+    /// use syntect::parsing::SyntaxSet;
+    ///
+    /// let syntaxes = SyntaxSet::load_defaults_newlines();
+    ///
+    /// let lang = from_path("foo.rs").unwrap();
+    /// assert_eq!(lang.find_syntax(&syntaxes).unwrap().name, "Rust");
+    ///
+    /// let lang = from_path("foo.hpp").unwrap();
+    /// assert_eq!(lang.find_syntax(&syntaxes).unwrap().name, "C++");
+    /// ```
+    pub fn find_syntax<'s>(&self, syntaxes: &'s SyntaxSet) -> Option<&'s SyntaxReference> {
+        self.syntect_name()
+            .and_then(|name| syntaxes.find_syntax_by_name(name))
+            .or_else(|| {
+                self.extensions()
+                    .iter()
+                    .find_map(|ext| syntaxes.find_syntax_by_extension(ext))
+            })
+    }
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in SYNTECT_NAMES.iter().zip(SYNTECT_NAMES.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "syntect names out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_syntect_names() {
+    let syntaxes = SyntaxSet::load_defaults_newlines();
+    for &(id, name) in SYNTECT_NAMES.iter() {
+        assert!(
+            crate::category::category(id).is_some(),
+            "syntect name for unknown language {:?}",
+            id
+        );
+        assert!(
+            syntaxes.find_syntax_by_name(name).is_some(),
+            "Unknown syntax {:?} for {:?}",
+            name,
+            id
+        );
+    }
+}
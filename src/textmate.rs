@@ -0,0 +1,207 @@
+use crate::{languages, Language};
+
+// The array is sorted by the language ID
+pub(crate) static TEXTMATE_SCOPES: [(&str, &str); 105] = [
+    ("apex", "source.apex"),
+    ("asciidoc", "text.asciidoc"),
+    ("astro", "source.astro"),
+    ("batch", "source.batchfile"),
+    ("bibtex", "text.bibtex"),
+    ("bicep", "source.bicep"),
+    ("blade", "text.html.php.blade"),
+    ("c", "source.c"),
+    ("clojure", "source.clojure"),
+    ("cmake", "source.cmake"),
+    ("common-lisp", "source.lisp"),
+    ("coq", "source.coq"),
+    ("cpp", "source.cpp"),
+    ("csharp", "source.cs"),
+    ("cson", "source.coffee"),
+    ("css", "source.css"),
+    ("csv", "text.csv"),
+    ("cue", "source.cue"),
+    ("d", "source.d"),
+    ("dart", "source.dart"),
+    ("dhall", "source.dhall"),
+    ("dockerfile", "source.dockerfile"),
+    ("dtrace", "source.dtrace"),
+    ("emacs-lisp", "source.emacs.lisp"),
+    ("gdscript", "source.gdscript"),
+    ("gemfile-lock", "source.gemfile-lock"),
+    ("git-config", "source.git-config"),
+    ("glsl", "source.glsl"),
+    ("gml", "source.gml"),
+    ("go", "source.go"),
+    ("godot-resource", "source.gdresource"),
+    ("graphql", "source.graphql"),
+    ("groovy", "source.groovy"),
+    ("haml", "text.haml"),
+    ("handlebars", "text.html.handlebars"),
+    ("hcl", "source.hcl"),
+    ("hiveql", "source.hql"),
+    ("hjson", "source.hjson"),
+    ("hlsl", "source.hlsl"),
+    ("html", "text.html.basic"),
+    ("html-erb", "text.html.erb"),
+    ("ini", "source.ini"),
+    ("java", "source.java"),
+    ("javascript", "source.js"),
+    ("jest-snapshot", "source.jest.snap"),
+    ("jinja", "source.jinja"),
+    ("json", "source.json"),
+    ("json-lines", "source.json"),
+    ("json5", "source.json5"),
+    ("jsonc", "source.json.comments"),
+    ("kotlin", "source.kotlin"),
+    ("less", "source.css.less"),
+    ("lua", "source.lua"),
+    ("make", "source.makefile"),
+    ("markdown", "text.html.markdown"),
+    ("matlab", "source.matlab"),
+    ("mercury", "source.mercury"),
+    ("mysql", "source.sql"),
+    ("nix", "source.nix"),
+    ("nushell", "source.nushell"),
+    ("objective-c", "source.objc"),
+    ("pascal", "source.pascal"),
+    ("perl", "source.perl"),
+    ("php", "source.php"),
+    ("plsql", "source.plsql.oracle"),
+    ("pod", "text.pod"),
+    ("postgresql", "source.sql"),
+    ("powershell", "source.powershell"),
+    ("prolog", "source.prolog"),
+    ("puppet", "source.puppet"),
+    ("python", "source.python"),
+    ("r", "source.r"),
+    ("racket", "source.racket"),
+    ("raku", "source.raku"),
+    ("rebol", "source.rebol"),
+    ("restructuredtext", "text.restructuredtext"),
+    ("ruby", "source.ruby"),
+    ("rust", "source.rust"),
+    ("sass", "source.sass"),
+    ("scala", "source.scala"),
+    ("scss", "source.css.scss"),
+    ("shaderlab", "source.shaderlab"),
+    ("shell", "source.shell"),
+    ("sparql", "source.sparql"),
+    ("sql", "source.sql"),
+    ("svelte", "source.svelte"),
+    ("svg", "text.xml.svg"),
+    ("swift", "source.swift"),
+    ("terraform", "source.hcl.terraform"),
+    ("tex", "text.tex"),
+    ("text", "text.plain"),
+    ("textile", "text.html.textile"),
+    ("toml", "source.toml"),
+    ("tsql", "source.tsql"),
+    ("tsx", "source.tsx"),
+    ("typescript", "source.ts"),
+    ("typescript-declaration", "source.ts"),
+    ("v", "source.v"),
+    ("vba", "source.vbnet"),
+    ("verilog", "source.verilog"),
+    ("vim-script", "source.viml"),
+    ("vue", "source.vue"),
+    ("xhtml", "text.xml"),
+    ("xml", "text.xml"),
+    ("yaml", "source.yaml"),
+];
+
+pub(crate) fn textmate_scope(id: &str) -> Option<&'static str> {
+    TEXTMATE_SCOPES
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .ok()
+        .map(|i| TEXTMATE_SCOPES[i].1)
+}
+
+/// Identifies a language from a [TextMate scope], e.g. `source.rust`
+/// or `text.html.markdown`.
+/// Returns `None` if the language was not identified.
+///
+/// The longest known scope that is a prefix of `scope` is used, such that
+/// embedded scopes, e.g. `source.js.embedded.html`, resolve to the leading
+/// language. Otherwise, the component following `source.` or `text.` is
+/// resolved as a language [ID] or an extension, e.g. `source.rs`.
+///
+/// *[See also `Language::textmate_scope`][textmate_scope].*
+///
+/// [TextMate scope]: https://macromates.com/manual/en/language_grammars#naming_conventions
+/// [ID]: struct.Language.html#method.id
+/// [textmate_scope]: struct.Language.html#method.textmate_scope
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_textmate_scope, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// assert_eq!(from_textmate_scope("source.rust"), Some(Language("Rust", "rust")));
+/// assert_eq!(from_textmate_scope("source.cpp"), Some(Language("C++", "cpp")));
+/// assert_eq!(from_textmate_scope("text.html.markdown"), Some(Language("Markdown", "markdown")));
+/// assert_eq!(from_textmate_scope("text.html.basic"), Some(Language("HTML", "html")));
+/// assert_eq!(from_textmate_scope("source.css.scss"), Some(Language("SCSS", "scss")));
+/// assert_eq!(from_textmate_scope("source.js.embedded.html"), Some(Language("JavaScript", "javascript")));
+/// assert_eq!(from_textmate_scope("source.rs"), Some(Language("Rust", "rust")));
+///
+/// assert_eq!(from_textmate_scope("source.unknown"), None);
+/// assert_eq!(from_textmate_scope("meta.embedded"), None);
+/// assert_eq!(from_textmate_scope("source.rustc"), None);
+/// # }
+/// ```
+pub fn from_textmate_scope(scope: &str) -> Option<Language<'static>> {
+    let known = TEXTMATE_SCOPES
+        .iter()
+        .filter(|&&(_, known)| {
+            scope.starts_with(known)
+                && matches!(scope.as_bytes().get(known.len()), None | Some(b'.'))
+        })
+        .max_by_key(|&&(_, known)| known.len());
+    if let Some(&(id, _)) = known {
+        return languages::from_id(id);
+    }
+
+    let rest = scope
+        .strip_prefix("source.")
+        .or_else(|| scope.strip_prefix("text."))?;
+    let name = rest.split('.').next()?;
+    languages::from_id(name).or_else(|| crate::from_lowercase_extension(name))
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in TEXTMATE_SCOPES.iter().zip(TEXTMATE_SCOPES.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "TextMate scopes out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_textmate_scopes() {
+    for &(id, scope) in TEXTMATE_SCOPES.iter() {
+        assert!(
+            languages::from_id(id).is_some(),
+            "Unknown language {:?} for {:?}",
+            id,
+            scope
+        );
+        let lang = from_textmate_scope(scope);
+        assert!(lang.is_some(), "{:?}", scope);
+        assert_eq!(lang.unwrap().textmate_scope(), Some(scope));
+    }
+
+    assert_eq!(
+        from_textmate_scope("source.yaml"),
+        Some(Language("YAML", "yaml"))
+    );
+    assert_eq!(
+        from_textmate_scope("text.xml.svg"),
+        Some(Language("SVG", "svg"))
+    );
+}
@@ -0,0 +1,178 @@
+use tokei::LanguageType;
+use tokei::LanguageType::*;
+
+use crate::{languages, Language};
+
+// The array is sorted by the language ID
+//
+// Languages map one-to-one, other tokei languages are in `TOKEI_ALIASES`
+static TOKEI_LANGUAGES: [(&str, LanguageType); 65] = [
+    ("asciidoc", AsciiDoc),
+    ("batch", Batch),
+    ("c", C),
+    ("clojure", Clojure),
+    ("cmake", CMake),
+    ("common-lisp", Lisp),
+    ("coq", Coq),
+    ("cpp", Cpp),
+    ("csharp", CSharp),
+    ("css", Css),
+    ("d", D),
+    ("dart", Dart),
+    ("dhall", Dhall),
+    ("dockerfile", Dockerfile),
+    ("gdscript", GdScript),
+    ("glsl", Glsl),
+    ("go", Go),
+    ("graphql", Graphql),
+    ("groovy", Groovy),
+    ("handlebars", Handlebars),
+    ("hcl", Hcl),
+    ("hlsl", Hlsl),
+    ("html", Html),
+    ("html-erb", RubyHtml),
+    ("ini", Ini),
+    ("java", Java),
+    ("javascript", JavaScript),
+    ("json", Json),
+    ("kotlin", Kotlin),
+    ("less", Less),
+    ("lua", Lua),
+    ("make", Makefile),
+    ("markdown", Markdown),
+    ("nix", Nix),
+    ("objective-c", ObjectiveC),
+    ("org", Org),
+    ("pascal", Pascal),
+    ("perl", Perl),
+    ("php", Php),
+    ("powershell", PowerShell),
+    ("prolog", Prolog),
+    ("python", Python),
+    ("r", R),
+    ("racket", Racket),
+    ("raku", Perl6),
+    ("restructuredtext", ReStructuredText),
+    ("ruby", Ruby),
+    ("rust", Rust),
+    ("sass", Sass),
+    ("scala", Scala),
+    ("shell", Sh),
+    ("sql", Sql),
+    ("svelte", Svelte),
+    ("svg", Svg),
+    ("swift", Swift),
+    ("tex", Tex),
+    ("text", Text),
+    ("toml", Toml),
+    ("tsx", Tsx),
+    ("typescript", TypeScript),
+    ("verilog", Verilog),
+    ("vim-script", VimScript),
+    ("vue", Vue),
+    ("xml", Xml),
+    ("yaml", Yaml),
+];
+
+// tokei languages, which are variants of the languages above
+static TOKEI_ALIASES: [(LanguageType, &str); 5] = [
+    (Bash, "shell"),
+    (CHeader, "c"),
+    (CppHeader, "cpp"),
+    (Jsx, "javascript"),
+    (Zsh, "shell"),
+];
+
+impl Language<'_> {
+    /// Returns the [tokei] language type of the language, e.g. for
+    /// counting lines of code of detected files.
+    /// Returns `None` if the language is unknown or not in tokei.
+    ///
+    /// *[See also `from_tokei`][from_tokei].*
+    ///
+    /// *This function requires the `tokei` feature.*
+    ///
+    /// [tokei]: https://docs.rs/tokei
+    /// [from_tokei]: fn.from_tokei.html
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use detect_lang::{from_extension, Language};
+    /// use tokei::LanguageType;
+    ///
+    /// assert_eq!(from_extension("rs").unwrap().tokei_type(), Some(LanguageType::Rust));
+    /// assert_eq!(from_extension("sh").unwrap().tokei_type(), Some(LanguageType::Sh));
+    /// assert_eq!(Language("Foo", "foo").tokei_type(), None);
+    /// ```
+    pub fn tokei_type(&self) -> Option<LanguageType> {
+        TOKEI_LANGUAGES
+            .binary_search_by_key(&self.id(), |&(id, _)| id)
+            .ok()
+            .map(|i| TOKEI_LANGUAGES[i].1)
+    }
+}
+
+/// Identifies a language from a [tokei] language type.
+/// Returns `None` if the language was not identified.
+///
+/// Variants of languages in tokei are identified as the language,
+/// e.g. `CHeader` as C and `Bash` as Shell.
+///
+/// *[See also `Language::tokei_type`][tokei_type].*
+///
+/// *This function requires the `tokei` feature.*
+///
+/// [tokei]: https://docs.rs/tokei
+/// [tokei_type]: struct.Language.html#method.tokei_type
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_tokei, Language};
+/// use tokei::LanguageType;
+///
+/// assert_eq!(from_tokei(LanguageType::Rust), Some(Language("Rust", "rust")));
+/// assert_eq!(from_tokei(LanguageType::CHeader), Some(Language("C", "c")));
+/// assert_eq!(from_tokei(LanguageType::Bash), Some(Language("Shell", "shell")));
+/// assert_eq!(from_tokei(LanguageType::Zig), None);
+/// ```
+pub fn from_tokei(lang: LanguageType) -> Option<Language<'static>> {
+    let id = TOKEI_LANGUAGES
+        .iter()
+        .find(|&&(_, other)| other == lang)
+        .map(|&(id, _)| id)
+        .or_else(|| {
+            TOKEI_ALIASES
+                .iter()
+                .find(|&&(other, _)| other == lang)
+                .map(|&(_, id)| id)
+        })?;
+    languages::from_id(id)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in TOKEI_LANGUAGES.iter().zip(TOKEI_LANGUAGES.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "tokei languages out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_tokei() {
+    for &(id, lang) in TOKEI_LANGUAGES.iter() {
+        let found = from_tokei(lang);
+        assert!(found.is_some(), "Unknown language {:?} for {:?}", id, lang);
+        assert_eq!(found.unwrap().id(), id, "{:?} is not one-to-one", lang);
+        assert_eq!(found.unwrap().tokei_type(), Some(lang));
+    }
+    for &(lang, id) in TOKEI_ALIASES.iter() {
+        assert_eq!(from_tokei(lang).map(|lang| lang.id()), Some(id));
+    }
+}
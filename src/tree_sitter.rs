@@ -0,0 +1,120 @@
+// The array is sorted by the language ID
+//
+// Grammars are given by the name of their parser, as used by e.g.
+// `tree-sitter-<grammar>` crates and nvim-treesitter
+pub(crate) static TREE_SITTER_GRAMMARS: [(&str, &str); 74] = [
+    ("astro", "astro"),
+    ("bibtex", "bibtex"),
+    ("bicep", "bicep"),
+    ("c", "c"),
+    ("clojure", "clojure"),
+    ("cmake", "cmake"),
+    ("common-lisp", "commonlisp"),
+    ("cpp", "cpp"),
+    ("csharp", "c_sharp"),
+    ("css", "css"),
+    ("csv", "csv"),
+    ("cue", "cue"),
+    ("d", "d"),
+    ("dart", "dart"),
+    ("dhall", "dhall"),
+    ("dockerfile", "dockerfile"),
+    ("gdscript", "gdscript"),
+    ("git-config", "git_config"),
+    ("glsl", "glsl"),
+    ("go", "go"),
+    ("godot-resource", "godot_resource"),
+    ("graphql", "graphql"),
+    ("groovy", "groovy"),
+    ("hcl", "hcl"),
+    ("hlsl", "hlsl"),
+    ("html", "html"),
+    ("ini", "ini"),
+    ("java", "java"),
+    ("javascript", "javascript"),
+    ("jinja", "jinja"),
+    ("json", "json"),
+    ("json5", "json5"),
+    ("jsonc", "jsonc"),
+    ("kotlin", "kotlin"),
+    ("lua", "lua"),
+    ("make", "make"),
+    ("markdown", "markdown"),
+    ("matlab", "matlab"),
+    ("nix", "nix"),
+    ("nushell", "nu"),
+    ("objective-c", "objc"),
+    ("pascal", "pascal"),
+    ("perl", "perl"),
+    ("php", "php"),
+    ("powershell", "powershell"),
+    ("prolog", "prolog"),
+    ("psv", "psv"),
+    ("puppet", "puppet"),
+    ("python", "python"),
+    ("r", "r"),
+    ("racket", "racket"),
+    ("restructuredtext", "rst"),
+    ("ruby", "ruby"),
+    ("rust", "rust"),
+    ("scala", "scala"),
+    ("scss", "scss"),
+    ("shell", "bash"),
+    ("sparql", "sparql"),
+    ("sql", "sql"),
+    ("svelte", "svelte"),
+    ("swift", "swift"),
+    ("terraform", "terraform"),
+    ("tex", "latex"),
+    ("toml", "toml"),
+    ("tsv", "tsv"),
+    ("tsx", "tsx"),
+    ("typescript", "typescript"),
+    ("typescript-declaration", "typescript"),
+    ("v", "v"),
+    ("verilog", "verilog"),
+    ("vim-script", "vim"),
+    ("vue", "vue"),
+    ("xml", "xml"),
+    ("yaml", "yaml"),
+];
+
+pub(crate) fn tree_sitter_grammar(id: &str) -> Option<&'static str> {
+    TREE_SITTER_GRAMMARS
+        .binary_search_by_key(&id, |&(id, _)| id)
+        .ok()
+        .map(|i| TREE_SITTER_GRAMMARS[i].1)
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in TREE_SITTER_GRAMMARS
+        .iter()
+        .zip(TREE_SITTER_GRAMMARS.iter().skip(1))
+    {
+        assert!(
+            a.0 < b.0,
+            "Tree-sitter grammars out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_tree_sitter_grammars() {
+    for &(id, grammar) in TREE_SITTER_GRAMMARS.iter() {
+        assert!(
+            crate::category::category(id).is_some(),
+            "Tree-sitter grammar for unknown language {:?}",
+            id
+        );
+        assert!(
+            grammar
+                .bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_'),
+            "Invalid tree-sitter grammar {:?}",
+            grammar
+        );
+    }
+}
@@ -0,0 +1,87 @@
+use alloc::borrow::ToOwned;
+use alloc::string::String;
+use core::error::Error;
+use core::fmt;
+
+use crate::Language;
+
+/// Errors returned by [`Language::validate`].
+///
+/// [`Language::validate`]: struct.Language.html#method.validate
+#[non_exhaustive]
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum LanguageValidationError {
+    /// The name is empty or only whitespace.
+    EmptyName,
+    /// The ID is empty.
+    EmptyId,
+    /// The ID is not a lowercase URL slug, i.e. it contains characters
+    /// other than lowercase ASCII letters, ASCII digits, and `-`.
+    InvalidId {
+        /// The invalid language ID.
+        id: String,
+    },
+}
+
+impl fmt::Display for LanguageValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyName => write!(f, "empty language name"),
+            Self::EmptyId => write!(f, "empty language id"),
+            Self::InvalidId { id } => {
+                write!(f, "invalid language id {:?}, expected a lowercase slug", id)
+            }
+        }
+    }
+}
+
+impl Error for LanguageValidationError {}
+
+pub(crate) fn validate(lang: &Language<'_>) -> Result<(), LanguageValidationError> {
+    let Language(name, id) = *lang;
+    if name.trim().is_empty() {
+        Err(LanguageValidationError::EmptyName)
+    } else if id.is_empty() {
+        Err(LanguageValidationError::EmptyId)
+    } else if !is_slug(id) {
+        Err(LanguageValidationError::InvalidId { id: id.to_owned() })
+    } else {
+        Ok(())
+    }
+}
+
+fn is_slug(id: &str) -> bool {
+    id.bytes()
+        .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+#[test]
+fn check_validate() {
+    use LanguageValidationError::*;
+
+    assert_eq!(validate(&Language("Rust", "rust")), Ok(()));
+    assert_eq!(validate(&Language("C++", "cpp")), Ok(()));
+    assert_eq!(validate(&Language("Objective-C", "objective-c")), Ok(()));
+    assert_eq!(validate(&Language("Python 3", "python3")), Ok(()));
+
+    assert_eq!(validate(&Language("", "foo")), Err(EmptyName));
+    assert_eq!(validate(&Language("  ", "foo")), Err(EmptyName));
+    assert_eq!(validate(&Language("Foo", "")), Err(EmptyId));
+    assert_eq!(validate(&Language("", "")), Err(EmptyName));
+
+    for &id in &["Foo", "foo bar", "c++", "foo_bar", " foo", "föo", "foo/bar"] {
+        assert_eq!(
+            validate(&Language("Foo", id)),
+            Err(InvalidId { id: id.to_owned() }),
+            "{:?}",
+            id
+        );
+    }
+}
+
+#[test]
+fn check_languages() {
+    for lang in crate::languages::all() {
+        assert_eq!(validate(&lang), Ok(()), "{:?}", lang);
+    }
+}
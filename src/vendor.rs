@@ -0,0 +1,309 @@
+use std::path::Path;
+
+use crate::glob::{glob_match, normalize};
+use crate::Language;
+
+// Patterns are matched against `/` separated paths relative to the
+// repository root, similar to Linguist's `vendor.yml`, `generated.rb`,
+// and `documentation.yml`
+
+pub(crate) static VENDORED: &[&str] = &[
+    "**/.yarn/**",
+    "**/Carthage/**",
+    "**/Godeps/**",
+    "**/Pods/**",
+    "**/__pypackages__/**",
+    "**/bower_components/**",
+    "**/node_modules/**",
+    "**/site-packages/**",
+    "**/third-party/**",
+    "**/third_party/**",
+    "**/thirdparty/**",
+    "**/vendor/**",
+    "**/vendored/**",
+    "**/vendors/**",
+    "**/virtualenv/**",
+    "**/.venv/**",
+    "**/venv/**",
+    "**/jquery.js",
+    "**/jquery-*.js",
+];
+
+pub(crate) static GENERATED: &[&str] = &[
+    "**/__generated__/**",
+    // Lockfiles
+    "**/Cargo.lock",
+    "**/Gemfile.lock",
+    "**/Pipfile.lock",
+    "**/Podfile.lock",
+    "**/bun.lockb",
+    "**/composer.lock",
+    "**/flake.lock",
+    "**/go.sum",
+    "**/gradle.lockfile",
+    "**/mix.lock",
+    "**/npm-shrinkwrap.json",
+    "**/package-lock.json",
+    "**/packages.lock.json",
+    "**/pnpm-lock.yaml",
+    "**/poetry.lock",
+    "**/uv.lock",
+    "**/yarn.lock",
+    // Minified and bundled sources
+    "**/*.min.js",
+    "**/*.min.css",
+    "**/*.bundle.js",
+    "**/*.js.map",
+    "**/*.css.map",
+    // Protocol Buffers
+    "**/*.pb.go",
+    "**/*.pb.cc",
+    "**/*.pb.h",
+    "**/*_pb2.py",
+    "**/*_pb2_grpc.py",
+    "**/*_pb.js",
+    "**/*_pb.d.ts",
+    // Visual Studio designer files
+    "**/*.designer.cs",
+    "**/*.Designer.cs",
+    "**/*.designer.vb",
+    "**/*.Designer.vb",
+];
+
+pub(crate) static DOCUMENTATION: &[&str] = &[
+    "Doc/**",
+    "Docs/**",
+    "doc/**",
+    "docs/**",
+    "**/Documentation/**",
+    "**/documentation/**",
+    "**/Examples/**",
+    "**/examples/**",
+    "**/CHANGELOG",
+    "**/CHANGELOG.*",
+    "**/CHANGES",
+    "**/CHANGES.*",
+    "**/CONTRIBUTING",
+    "**/CONTRIBUTING.*",
+    "**/COPYING",
+    "**/COPYING.*",
+    "**/INSTALL",
+    "**/INSTALL.*",
+    "**/LICENCE",
+    "**/LICENCE.*",
+    "**/LICENSE",
+    "**/LICENSE.*",
+    "**/README",
+    "**/README.*",
+];
+
+pub(crate) static TESTS: &[&str] = &[
+    "**/__tests__/**",
+    "**/spec/**",
+    "**/test/**",
+    "**/tests/**",
+    "**/testdata/**",
+    // Go, Python, Ruby, and Rust
+    "**/*_test.go",
+    "**/test_*.py",
+    "**/*_test.py",
+    "**/*_spec.rb",
+    "**/*_test.rb",
+    "**/*_test.rs",
+    // JavaScript and TypeScript
+    "**/*.spec.js",
+    "**/*.spec.jsx",
+    "**/*.spec.ts",
+    "**/*.spec.tsx",
+    "**/*.test.js",
+    "**/*.test.jsx",
+    "**/*.test.ts",
+    "**/*.test.tsx",
+    // Java, Kotlin, and C#
+    "**/*Test.java",
+    "**/*Tests.java",
+    "**/*Test.kt",
+    "**/*Tests.cs",
+];
+
+fn matches_any(patterns: &[&str], path: &Path) -> bool {
+    match normalize(path) {
+        Some(path) => patterns.iter().any(|pattern| glob_match(pattern, &path)),
+        None => false,
+    }
+}
+
+/// Returns `true` if `path`, relative to the repository root, is a
+/// vendored dependency, e.g. inside `node_modules/` or `third_party/`.
+///
+/// Language statistics commonly exclude vendored files,
+/// as done by [GitHub Linguist].
+///
+/// *[See also `is_generated` and `is_documentation`][is_generated].*
+///
+/// [GitHub Linguist]: https://github.com/github/linguist/blob/master/lib/linguist/vendor.yml
+/// [is_generated]: fn.is_generated.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::is_vendored;
+/// assert!(is_vendored("node_modules/foo/index.js"));
+/// assert!(is_vendored("web/node_modules/foo/index.js"));
+/// assert!(is_vendored("third_party/zlib/zlib.h"));
+/// assert!(is_vendored("vendor/github.com/foo/bar.go"));
+///
+/// assert!(!is_vendored("src/main.rs"));
+/// assert!(!is_vendored("src/vendor.rs"));
+/// ```
+pub fn is_vendored<P: AsRef<Path>>(path: P) -> bool {
+    matches_any(VENDORED, path.as_ref())
+}
+
+/// Returns `true` if `path`, relative to the repository root, is
+/// commonly machine-generated, e.g. lockfiles like `Cargo.lock`,
+/// minified bundles like `app.min.js`, and Protocol Buffers sources
+/// like `foo.pb.go`.
+///
+/// Only the path is checked, not the content of the file.
+///
+/// *[See also `is_vendored`][is_vendored].*
+///
+/// [is_vendored]: fn.is_vendored.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::is_generated;
+/// assert!(is_generated("Cargo.lock"));
+/// assert!(is_generated("web/package-lock.json"));
+/// assert!(is_generated("dist/app.min.js"));
+/// assert!(is_generated("static/style.min.css"));
+/// assert!(is_generated("api/foo.pb.go"));
+/// assert!(is_generated("api/foo_pb2.py"));
+///
+/// assert!(!is_generated("src/app.js"));
+/// ```
+pub fn is_generated<P: AsRef<Path>>(path: P) -> bool {
+    matches_any(GENERATED, path.as_ref())
+}
+
+/// Identifies the language of `path` as by [`from_path`], along with
+/// whether the file is machine-generated as by [`is_generated`].
+///
+/// The language may be identified regardless of whether the file
+/// is generated, and vice versa.
+///
+/// [`from_path`]: fn.from_path.html
+/// [`is_generated`]: fn.is_generated.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{detect_generated, Language};
+/// assert_eq!(detect_generated("Cargo.lock"), (Some(Language("TOML", "toml")), true));
+/// assert_eq!(detect_generated("package-lock.json"), (Some(Language("JSON", "json")), true));
+/// assert_eq!(detect_generated("dist/app.min.js"), (Some(Language("JavaScript", "javascript")), true));
+/// assert_eq!(detect_generated("Cargo.toml"), (Some(Language("TOML", "toml")), false));
+/// assert_eq!(detect_generated("foo.unknown"), (None, false));
+/// ```
+pub fn detect_generated<P: AsRef<Path>>(path: P) -> (Option<Language<'static>>, bool) {
+    let path = path.as_ref();
+    (crate::from_path(path), is_generated(path))
+}
+
+/// Returns `true` if `path`, relative to the repository root, is
+/// documentation, e.g. inside `docs/` or named `README.md`.
+///
+/// *[See also `is_vendored`][is_vendored].*
+///
+/// [is_vendored]: fn.is_vendored.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::is_documentation;
+/// assert!(is_documentation("docs/index.md"));
+/// assert!(is_documentation("docs/api/conf.py"));
+/// assert!(is_documentation("README.md"));
+/// assert!(is_documentation("src/foo/LICENSE"));
+/// assert!(is_documentation("examples/hello.rs"));
+///
+/// assert!(!is_documentation("src/docs.rs"));
+/// assert!(!is_documentation("src/docs/mod.rs"));
+/// ```
+pub fn is_documentation<P: AsRef<Path>>(path: P) -> bool {
+    matches_any(DOCUMENTATION, path.as_ref())
+}
+
+/// Returns `true` if `path`, relative to the repository root, is test
+/// code by common conventions, e.g. `foo_test.go`, `foo.spec.ts`,
+/// `test_foo.py`, and files inside `tests/` or `__tests__/`.
+///
+/// *[See also `is_vendored`][is_vendored].*
+///
+/// [is_vendored]: fn.is_vendored.html
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::is_test_path;
+/// assert!(is_test_path("pkg/foo_test.go"));
+/// assert!(is_test_path("src/app.spec.ts"));
+/// assert!(is_test_path("test_foo.py"));
+/// assert!(is_test_path("tests/integration.rs"));
+/// assert!(is_test_path("src/__tests__/app.js"));
+///
+/// assert!(!is_test_path("src/main.rs"));
+/// assert!(!is_test_path("src/testing.py"));
+/// ```
+pub fn is_test_path<P: AsRef<Path>>(path: P) -> bool {
+    matches_any(TESTS, path.as_ref())
+}
+
+#[test]
+fn check_paths() {
+    assert!(is_vendored("./node_modules/foo.js"));
+    assert!(is_vendored("Pods/Foo/Foo.m"));
+    assert!(is_vendored("static/jquery-3.6.0.js"));
+    assert!(!is_vendored("node_modules"));
+    assert!(!is_vendored("../node_modules/foo.js"));
+
+    assert!(is_generated("src/__generated__/schema.ts"));
+    assert!(is_generated("Form1.Designer.cs"));
+    assert!(!is_generated("min.js"));
+
+    assert!(is_documentation("Documentation/foo.txt"));
+    assert!(is_documentation("CHANGELOG.md"));
+    assert!(!is_documentation("READMEs.md"));
+
+    assert!(is_test_path("src/test/java/FooTest.java"));
+    assert!(is_test_path("spec/models/user_spec.rb"));
+    assert!(is_test_path("FooTests.cs"));
+    assert!(!is_test_path("src/contest.go"));
+    assert!(!is_test_path("latest.py"));
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_lockfiles() {
+    let cases: &[(&str, Option<&str>)] = &[
+        ("Cargo.lock", Some("toml")),
+        ("poetry.lock", Some("toml")),
+        ("package-lock.json", Some("json")),
+        ("Pipfile.lock", Some("json")),
+        ("composer.lock", Some("json")),
+        ("flake.lock", Some("json")),
+        ("yarn.lock", Some("yaml")),
+        ("pnpm-lock.yaml", Some("yaml")),
+        ("go.sum", Some("go-checksums")),
+        ("Gemfile.lock", Some("gemfile-lock")),
+        ("foo/bar.pb.go", Some("go")),
+        ("foo/bar_pb2.py", Some("python")),
+    ];
+    for &(path, id) in cases {
+        let (lang, generated) = detect_generated(path);
+        assert_eq!(lang.map(|lang| lang.id()), id, "{:?}", path);
+        assert!(generated, "{:?}", path);
+    }
+}
@@ -0,0 +1,149 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tokio::task::JoinSet;
+
+use crate::Language;
+
+/// The default maximum number of directories read concurrently by
+/// [`walk_and_detect_async`].
+///
+/// *This constant requires the `tokio` feature.*
+///
+/// [`walk_and_detect_async`]: fn.walk_and_detect_async.html
+pub const DEFAULT_CONCURRENCY: usize = 64;
+
+/// Recursively walks the directory `root`, identifying the language
+/// of each file using [`from_path`]. Files where the language was not
+/// identified are not included.
+///
+/// Directories are read concurrently, at most [`DEFAULT_CONCURRENCY`]
+/// at a time. Symbolic links are not followed, and are skipped.
+/// The result is sorted by path.
+///
+/// *This function requires the `tokio` feature.*
+///
+/// *[See also `walk_and_detect_async_with_concurrency`][with_concurrency].*
+///
+/// [`from_path`]: fn.from_path.html
+/// [`DEFAULT_CONCURRENCY`]: constant.DEFAULT_CONCURRENCY.html
+/// [with_concurrency]: fn.walk_and_detect_async_with_concurrency.html
+///
+/// # Errors
+///
+/// Returns an error if `root` or any directory within it cannot be read.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn f() -> std::io::Result<()> {
+/// use detect_lang::walk_and_detect_async;
+///
+/// for (path, lang) in walk_and_detect_async("src").await? {
+///     println!("{}: {}", path.display(), lang.name());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[inline]
+pub async fn walk_and_detect_async<P: AsRef<Path>>(
+    root: P,
+) -> io::Result<Vec<(PathBuf, Language<'static>)>> {
+    walk_and_detect_async_with_concurrency(root, DEFAULT_CONCURRENCY).await
+}
+
+/// Same as [`walk_and_detect_async`], except at most `max_concurrency`
+/// directories are read concurrently. A `max_concurrency` of `0` is
+/// treated as `1`.
+///
+/// *This function requires the `tokio` feature.*
+///
+/// [`walk_and_detect_async`]: fn.walk_and_detect_async.html
+pub async fn walk_and_detect_async_with_concurrency<P: AsRef<Path>>(
+    root: P,
+    max_concurrency: usize,
+) -> io::Result<Vec<(PathBuf, Language<'static>)>> {
+    let max_concurrency = max_concurrency.max(1);
+
+    let mut pending = vec![root.as_ref().to_path_buf()];
+    let mut tasks = JoinSet::new();
+    let mut found = Vec::new();
+
+    while !pending.is_empty() || !tasks.is_empty() {
+        while tasks.len() < max_concurrency {
+            match pending.pop() {
+                Some(dir) => {
+                    tasks.spawn(read_dir_async(dir));
+                }
+                None => break,
+            }
+        }
+
+        if let Some(res) = tasks.join_next().await {
+            let (dirs, files) = res.map_err(io::Error::other)??;
+            pending.extend(dirs);
+            found.extend(files);
+        }
+    }
+
+    found.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+    Ok(found)
+}
+
+type DirContents = (Vec<PathBuf>, Vec<(PathBuf, Language<'static>)>);
+
+async fn read_dir_async(dir: PathBuf) -> io::Result<DirContents> {
+    let mut dirs = Vec::new();
+    let mut files = Vec::new();
+
+    let mut entries = tokio::fs::read_dir(dir).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        // `DirEntry::file_type` does not follow symbolic links
+        let file_type = entry.file_type().await?;
+        if file_type.is_dir() {
+            dirs.push(entry.path());
+        } else if file_type.is_file() {
+            let path = entry.path();
+            if let Some(lang) = crate::from_path(&path) {
+                files.push((path, lang));
+            }
+        }
+    }
+
+    Ok((dirs, files))
+}
+
+#[tokio::test]
+async fn walk_temp_dir_async() {
+    use crate::test_util::TempDir;
+    use std::fs;
+
+    let root = TempDir::new("walk-async");
+    fs::create_dir_all(root.join("src/nested")).unwrap();
+    fs::write(root.join("Makefile"), "").unwrap();
+    fs::write(root.join("README.md"), "").unwrap();
+    fs::write(root.join("src/main.rs"), "").unwrap();
+    fs::write(root.join("src/nested/foo.py"), "").unwrap();
+    fs::write(root.join("src/nested/foo.unknown"), "").unwrap();
+
+    for max_concurrency in [0, 1, DEFAULT_CONCURRENCY] {
+        let found = walk_and_detect_async_with_concurrency(&root, max_concurrency)
+            .await
+            .unwrap();
+        let found = found
+            .iter()
+            .map(|(path, lang)| (path.strip_prefix(&root).unwrap(), lang.id()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            [
+                (Path::new("Makefile"), "make"),
+                (Path::new("README.md"), "markdown"),
+                (Path::new("src/main.rs"), "rust"),
+                (Path::new("src/nested/foo.py"), "python"),
+            ]
+        );
+    }
+
+    assert!(walk_and_detect_async(root.join("missing")).await.is_err());
+}
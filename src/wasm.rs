@@ -0,0 +1,108 @@
+//! [wasm-bindgen] bindings, for detecting languages client-side using
+//! the same detection data, e.g. in web-based editors.
+//!
+//! ```js
+//! import { fromPath, fromExtension } from "detect-lang";
+//!
+//! const lang = fromPath("src/main.rs");
+//! console.log(lang.name, lang.id); // Rust rust
+//!
+//! console.log(fromExtension("unknown")); // undefined
+//! ```
+//!
+//! *This module requires the `wasm` feature.*
+//!
+//! [wasm-bindgen]: https://docs.rs/wasm-bindgen
+
+use wasm_bindgen::prelude::*;
+
+use crate::Language;
+
+/// A language returned by the wasm-bindgen bindings, exported to
+/// JavaScript as `Language`.
+///
+/// *[See also the module documentation][wasm].*
+///
+/// [wasm]: index.html
+#[wasm_bindgen(js_name = Language)]
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct WasmLanguage(Language<'static>);
+
+#[wasm_bindgen(js_class = Language)]
+impl WasmLanguage {
+    /// The name of the language, e.g. `Rust`.
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        self.0.name().to_owned()
+    }
+
+    /// The ID of the language, e.g. `rust`.
+    #[wasm_bindgen(getter)]
+    pub fn id(&self) -> String {
+        self.0.id().to_owned()
+    }
+}
+
+impl From<Language<'static>> for WasmLanguage {
+    #[inline]
+    fn from(lang: Language<'static>) -> Self {
+        Self(lang)
+    }
+}
+
+impl From<WasmLanguage> for Language<'static> {
+    #[inline]
+    fn from(lang: WasmLanguage) -> Self {
+        lang.0
+    }
+}
+
+/// Identifies a language from a path, exported to JavaScript as `fromPath`.
+/// Returns `undefined` if the language was not identified.
+///
+/// *[See also `detect_lang::from_path`][from_path].*
+///
+/// [from_path]: ../fn.from_path.html
+#[wasm_bindgen(js_name = fromPath)]
+pub fn from_path(path: &str) -> Option<WasmLanguage> {
+    crate::from_path(path).map(WasmLanguage)
+}
+
+/// Identifies a language from a file extension, exported to JavaScript
+/// as `fromExtension`. Returns `undefined` if the language was not
+/// identified.
+///
+/// *[See also `detect_lang::from_extension`][from_extension].*
+///
+/// [from_extension]: ../fn.from_extension.html
+#[wasm_bindgen(js_name = fromExtension)]
+pub fn from_extension(extension: &str) -> Option<WasmLanguage> {
+    crate::from_extension(extension).map(WasmLanguage)
+}
+
+/// Identifies a language from a path and the content of the file, e.g.
+/// a `Uint8Array`, exported to JavaScript as `fromPathWithContent`.
+/// Returns `undefined` if the language was not identified.
+///
+/// *[See also `detect_lang::from_path_with_content`][from_path_with_content].*
+///
+/// [from_path_with_content]: ../fn.from_path_with_content.html
+#[wasm_bindgen(js_name = fromPathWithContent)]
+pub fn from_path_with_content(path: &str, content: &[u8]) -> Option<WasmLanguage> {
+    crate::from_path_with_content(path, content).map(WasmLanguage)
+}
+
+#[test]
+fn check_wasm() {
+    let lang = from_path("src/main.rs").unwrap();
+    assert_eq!((lang.name().as_str(), lang.id().as_str()), ("Rust", "rust"));
+    assert_eq!(
+        from_extension("JSON").map(Language::from),
+        Some(Language("JSON", "json"))
+    );
+    assert_eq!(
+        from_path_with_content("foo", b"#!/bin/sh\n").map(Language::from),
+        Some(Language("Shell", "shell"))
+    );
+    assert_eq!(from_path("foo.unknown"), None);
+}
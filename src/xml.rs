@@ -0,0 +1,245 @@
+use crate::content::text;
+use crate::Language;
+
+#[cfg(feature = "full")]
+macro_rules! d {
+    ($root:literal, $namespace:literal, $name:literal, $id:literal) => {
+        ($root, $namespace, Language($name, $id))
+    };
+}
+
+// The array is sorted by the root element
+//
+// If the namespace is not empty, then the attributes of the root
+// element must contain it, e.g. `xmlns="http://maven.apache.org/POM/4.0.0"`
+pub(crate) static XML_DIALECTS: &[(&str, &str, Language)] = &[
+    #[cfg(feature = "full")]
+    d!("plist", "", "XML Property List", "xml-property-list"),
+    #[cfg(feature = "full")]
+    d!(
+        "project",
+        "http://maven.apache.org/POM/",
+        "Maven POM",
+        "maven-pom"
+    ),
+    #[cfg(feature = "full")]
+    d!("svg", "", "SVG", "svg"),
+    #[cfg(feature = "full")]
+    d!("xsl:stylesheet", "", "XSLT", "xslt"),
+    #[cfg(feature = "full")]
+    d!("xsl:transform", "", "XSLT", "xslt"),
+];
+
+// Android layouts have any root element with the `android` namespace,
+// other than the root elements of the manifest and other resources,
+// e.g. drawables, animations, menus, and values
+#[cfg(feature = "full")]
+pub(crate) const ANDROID_NAMESPACE: &str = "http://schemas.android.com/apk/res/android";
+
+// The array is sorted
+#[cfg(feature = "full")]
+pub(crate) static ANDROID_RESOURCE_ROOTS: [&str; 25] = [
+    "PreferenceScreen",
+    "adaptive-icon",
+    "animated-selector",
+    "animated-vector",
+    "animation-list",
+    "appwidget-provider",
+    "bitmap",
+    "clip",
+    "font-family",
+    "inset",
+    "layer-list",
+    "level-list",
+    "manifest",
+    "menu",
+    "navigation",
+    "objectAnimator",
+    "resources",
+    "ripple",
+    "rotate",
+    "scale",
+    "selector",
+    "set",
+    "shape",
+    "transition",
+    "vector",
+];
+#[cfg(feature = "full")]
+pub(crate) const ANDROID_LAYOUT: Language<'static> = Language("Android Layout", "android-layout");
+
+/// Identifies a dialect of XML from the root element of `content`, e.g.
+/// `<svg>` for SVG and `<xsl:stylesheet>` for XSLT. Returns `None` if the
+/// content is not XML, or the dialect was not identified.
+///
+/// The identified dialects are SVG, XSLT, Maven POM, XML Property List,
+/// and Android layouts, i.e. root elements with the `android` namespace,
+/// other than `<manifest>` and the root elements of other resources, e.g.
+/// `<vector>`, `<selector>`, `<shape>`, and `<resources>`.
+///
+/// *[See also `LanguageDetectorBuilder::refine_xml`][refine_xml], which uses this
+/// for files identified as XML.*
+///
+/// [refine_xml]: struct.LanguageDetectorBuilder.html#method.refine_xml
+///
+/// # Example
+///
+/// ```
+/// # use detect_lang::{from_xml_root, Language};
+/// # #[cfg(feature = "full")]
+/// # {
+/// let content = br#"<?xml version="1.0" encoding="UTF-8"?>
+/// <!-- Generated -->
+/// <svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"/>
+/// "#;
+/// assert_eq!(from_xml_root(content), Some(Language("SVG", "svg")));
+///
+/// let content = br#"<?xml version="1.0"?>
+/// <xsl:stylesheet version="1.0" xmlns:xsl="http://www.w3.org/1999/XSL/Transform">
+/// </xsl:stylesheet>
+/// "#;
+/// assert_eq!(from_xml_root(content), Some(Language("XSLT", "xslt")));
+///
+/// let content = br#"<project xmlns="http://maven.apache.org/POM/4.0.0">
+///   <modelVersion>4.0.0</modelVersion>
+/// </project>
+/// "#;
+/// assert_eq!(from_xml_root(content), Some(Language("Maven POM", "maven-pom")));
+///
+/// // Other projects are not Maven
+/// assert_eq!(from_xml_root(b"<project name=\"foo\"/>"), None);
+/// assert_eq!(from_xml_root(b"<?xml version=\"1.0\"?>\n<foo/>\n"), None);
+/// # }
+/// ```
+pub fn from_xml_root(content: &[u8]) -> Option<Language<'static>> {
+    let (root, attributes) = root_element(text(content)?)?;
+
+    if let Ok(i) = XML_DIALECTS.binary_search_by_key(&root, |&(root, _, _)| root) {
+        let (_, namespace, lang) = XML_DIALECTS[i];
+        if attributes.contains(namespace) {
+            return Some(lang);
+        }
+    }
+
+    #[cfg(feature = "full")]
+    if attributes.contains(ANDROID_NAMESPACE)
+        && ANDROID_RESOURCE_ROOTS.binary_search(&root).is_err()
+    {
+        return Some(ANDROID_LAYOUT);
+    }
+
+    None
+}
+
+/// Returns the name and the attributes of the root element, skipping
+/// the XML declaration, comments, processing instructions, and the
+/// document type declaration.
+fn root_element(content: &str) -> Option<(&str, &str)> {
+    let mut content = content.trim_start_matches('\u{FEFF}');
+    loop {
+        content = content.trim_start();
+        if let Some(rest) = content.strip_prefix("<!--") {
+            content = &rest[rest.find("-->")? + 3..];
+        } else if let Some(rest) = content.strip_prefix("<?") {
+            content = &rest[rest.find("?>")? + 2..];
+        } else if let Some(rest) = content.strip_prefix("<!") {
+            content = &rest[rest.find('>')? + 1..];
+        } else {
+            break;
+        }
+    }
+
+    let tag = content.strip_prefix('<')?;
+    let tag = &tag[..tag.find('>').unwrap_or(tag.len())];
+    let end = tag
+        .find(|c: char| c.is_whitespace() || c == '/')
+        .unwrap_or(tag.len());
+    let (name, attributes) = tag.split_at(end);
+    if name.is_empty() {
+        None
+    } else {
+        Some((name, attributes))
+    }
+}
+
+#[test]
+fn check_order() {
+    for (a, b) in XML_DIALECTS.iter().zip(XML_DIALECTS.iter().skip(1)) {
+        assert!(
+            a.0 < b.0,
+            "XML dialects out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+
+    #[cfg(feature = "full")]
+    for (a, b) in ANDROID_RESOURCE_ROOTS
+        .iter()
+        .zip(ANDROID_RESOURCE_ROOTS.iter().skip(1))
+    {
+        assert!(
+            a < b,
+            "Android resource roots out of order - {:?} should come after {:?}",
+            a,
+            b,
+        );
+    }
+}
+
+#[test]
+fn check_root_element() {
+    assert_eq!(root_element("<foo/>"), Some(("foo", "/")));
+    assert_eq!(root_element("<foo a=\"1\">"), Some(("foo", " a=\"1\"")));
+    assert_eq!(
+        root_element("\u{FEFF}<?xml version=\"1.0\"?>\n<!DOCTYPE foo>\n<!-- <bar> -->\n<foo>"),
+        Some(("foo", ""))
+    );
+    assert_eq!(root_element("<!-- <foo>"), None);
+    assert_eq!(root_element("foo"), None);
+    assert_eq!(root_element("< foo>"), None);
+    assert_eq!(root_element(""), None);
+}
+
+#[cfg(feature = "full")]
+#[test]
+fn check_dialects() {
+    use alloc::format;
+
+    let plist = br#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict/>
+</plist>
+"#;
+    assert_eq!(
+        from_xml_root(plist),
+        Some(Language("XML Property List", "xml-property-list"))
+    );
+
+    let layout = br#"<?xml version="1.0" encoding="utf-8"?>
+<LinearLayout xmlns:android="http://schemas.android.com/apk/res/android"
+    android:layout_width="match_parent">
+</LinearLayout>
+"#;
+    assert_eq!(
+        from_xml_root(layout),
+        Some(Language("Android Layout", "android-layout"))
+    );
+
+    let manifest = br#"<manifest xmlns:android="http://schemas.android.com/apk/res/android"/>"#;
+    assert_eq!(from_xml_root(manifest), None);
+
+    for root in &["vector", "selector", "shape", "resources", "layer-list"] {
+        let content = format!(
+            r#"<{} xmlns:android="http://schemas.android.com/apk/res/android"/>"#,
+            root
+        );
+        assert_eq!(from_xml_root(content.as_bytes()), None, "{}", root);
+    }
+
+    assert_eq!(
+        from_xml_root(b"<xsl:transform version=\"1.0\"/>"),
+        Some(Language("XSLT", "xslt"))
+    );
+}